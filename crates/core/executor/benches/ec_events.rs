@@ -0,0 +1,157 @@
+//! Benchmarks of the elliptic curve event constructors, for each supported curve.
+
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use sp1_core_executor::{
+    events::{
+        create_ec_add_event, create_ec_decompress_event, create_ec_double_event,
+        decompress_for_curve, MemoryEntry, PrecompileEvent,
+    },
+    syscalls::SyscallContext,
+    Executor, ExecutorConfig, ExecutorMode, Program, SP1CoreOpts, Trace,
+};
+use sp1_curves::{
+    edwards::{ed25519::Ed25519, jubjub::Jubjub},
+    weierstrass::{
+        bls12_381::Bls12381,
+        bn254::Bn254,
+        pasta::{Pallas, Vesta},
+        secp256k1::Secp256k1,
+        secp256r1::Secp256r1,
+    },
+    EllipticCurve, IdentityEncoding,
+};
+
+const P_PTR: u64 = 0x1000;
+const Q_PTR: u64 = 0x2000;
+
+/// The number of additions and doublings of each run of the pooled benchmark.
+const POOLED_OPS: usize = 256;
+
+/// The trace mode, drawing the memory records of syscalls from the executor's record pool.
+struct PooledTrace;
+
+impl ExecutorConfig for PooledTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const POOL_EC_RECORDS: bool = true;
+}
+
+/// Returns an executor over an empty program whose memory holds only the given words, so that the
+/// event constructors run through a real [`SyscallContext`] without a guest.
+fn executor(memory: &[(u64, &[u64])]) -> Executor<'static> {
+    let mut rt = Executor::new(Arc::new(Program::new(vec![], 0, 0)), SP1CoreOpts::default());
+    // Start past the initial timestamp so that every access is after the memory initialization.
+    rt.state.clk = 8;
+    for &(ptr, words) in memory {
+        for (i, word) in words.iter().enumerate() {
+            rt.state.memory.page_table.insert(ptr + 8 * i as u64, MemoryEntry::init(*word));
+        }
+    }
+    rt
+}
+
+fn bench_curve<E: EllipticCurve>(c: &mut Criterion, name: &str) {
+    let generator = E::ec_generator();
+    let p = generator.to_words_le();
+    let q = E::ec_double(&generator).to_words_le();
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("add", |b| {
+        b.iter_batched(
+            || executor(&[(P_PTR, &p), (Q_PTR, &q)]),
+            |mut rt| {
+                let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+                create_ec_add_event::<E, Trace>(&mut ctx, P_PTR, Q_PTR)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.bench_function("double", |b| {
+        b.iter_batched(
+            || executor(&[(P_PTR, &p)]),
+            |mut rt| {
+                let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+                create_ec_double_event::<E, Trace>(&mut ctx, P_PTR, 0)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    if decompress_for_curve::<E>().is_some() {
+        // The compressed coordinate goes in the upper half of the slice: `x` on short Weierstrass
+        // curves and `y` on twisted Edwards curves.
+        let (x, y) = p.split_at(p.len() / 2);
+        let compressed = if E::IDENTITY_ENCODING == IdentityEncoding::Neutral { y } else { x };
+        let compressed_ptr = P_PTR + 8 * compressed.len() as u64;
+        group.bench_function("decompress", |b| {
+            b.iter_batched(
+                || executor(&[(compressed_ptr, compressed)]),
+                |mut rt| {
+                    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+                    create_ec_decompress_event::<E, Trace>(&mut ctx, P_PTR, 0)
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+/// Runs [`POOLED_OPS`] secp256k1 additions and doublings, recycling each event into the record
+/// pool as a consumer of the shard would if the pool is enabled, and returns the executor.
+fn run_pooled_secp256k1<Ex: ExecutorConfig>(mut rt: Executor<'static>) -> Executor<'static> {
+    for _ in 0..POOLED_OPS {
+        let mut ctx = SyscallContext::<Ex>::new(&mut rt, true);
+        let add = create_ec_add_event::<Secp256k1, Ex>(&mut ctx, P_PTR, Q_PTR);
+        let double = create_ec_double_event::<Secp256k1, Ex>(&mut ctx, Q_PTR, 0);
+        if Ex::POOL_EC_RECORDS {
+            ctx.rt.ec_record_pool.recycle(PrecompileEvent::Secp256k1Add(add));
+            ctx.rt.ec_record_pool.recycle(PrecompileEvent::Secp256k1Double(double));
+        }
+    }
+    rt
+}
+
+/// Compares secp256k1 events with freshly allocated and pooled record vectors, and reports the
+/// number of vectors each allocates.
+fn bench_pooled_secp256k1(c: &mut Criterion) {
+    let generator = Secp256k1::ec_generator();
+    let p = generator.to_words_le();
+    let q = Secp256k1::ec_double(&generator).to_words_le();
+    let setup = || executor(&[(P_PTR, &p), (Q_PTR, &q)]);
+
+    let pooled = run_pooled_secp256k1::<PooledTrace>(setup());
+    println!(
+        "secp256k1 x{POOLED_OPS}: {} record vectors allocated without the pool, {} with it",
+        pooled.ec_record_pool.takes(),
+        pooled.ec_record_pool.allocations()
+    );
+
+    let mut group = c.benchmark_group("secp256k1_records");
+    group.bench_function("allocated", |b| {
+        b.iter_batched(setup, run_pooled_secp256k1::<Trace>, BatchSize::SmallInput);
+    });
+    group.bench_function("pooled", |b| {
+        b.iter_batched(setup, run_pooled_secp256k1::<PooledTrace>, BatchSize::SmallInput);
+    });
+    group.finish();
+}
+
+fn bench_ec_events(c: &mut Criterion) {
+    bench_curve::<Secp256k1>(c, "secp256k1");
+    bench_curve::<Secp256r1>(c, "secp256r1");
+    bench_curve::<Bn254>(c, "bn254");
+    bench_curve::<Bls12381>(c, "bls12381");
+    bench_curve::<Pallas>(c, "pallas");
+    bench_curve::<Vesta>(c, "vesta");
+    bench_curve::<Ed25519>(c, "ed25519");
+    bench_curve::<Jubjub>(c, "jubjub");
+    bench_pooled_secp256k1(c);
+}
+
+criterion_group!(benches, bench_ec_events);
+criterion_main!(benches);
@@ -6,6 +6,7 @@ use crate::{
     subproof::SubproofVerifier,
 };
 use hashbrown::HashMap;
+use sp1_curves::registry::{CustomCurve, CustomCurveRegistry};
 use std::io::Write;
 
 use sp1_primitives::consts::fd::LOWEST_ALLOWED_FD;
@@ -65,6 +66,9 @@ pub struct SP1Context<'a> {
     /// The verifier for verifying subproofs.
     pub subproof_verifier: Option<Arc<dyn SubproofVerifier>>,
 
+    /// The implementations of the custom curves the elliptic curve events operate on.
+    pub custom_curves: CustomCurveRegistry,
+
     /// The maximum number of cpu cycles to use for execution.
     pub max_cycles: Option<u64>,
 
@@ -99,6 +103,7 @@ pub struct SP1ContextBuilder<'a> {
     no_default_hooks: bool,
     hook_registry_entries: Vec<(u32, BoxedHook<'a>)>,
     subproof_verifier: Option<Arc<dyn SubproofVerifier>>,
+    custom_curves: CustomCurveRegistry,
     max_cycles: Option<u64>,
     deferred_proof_verification: bool,
     calculate_gas: bool,
@@ -132,6 +137,7 @@ impl<'a> SP1ContextBuilder<'a> {
             no_default_hooks: false,
             hook_registry_entries: Vec::new(),
             subproof_verifier: None,
+            custom_curves: CustomCurveRegistry::new(),
             max_cycles: None,
             // Always verify deferred proofs by default.
             deferred_proof_verification: true,
@@ -173,6 +179,7 @@ impl<'a> SP1ContextBuilder<'a> {
             });
 
         let subproof_verifier = take(&mut self.subproof_verifier);
+        let custom_curves = take(&mut self.custom_curves);
         let cycle_limit = take(&mut self.max_cycles);
         let deferred_proof_verification = take(&mut self.deferred_proof_verification);
         let calculate_gas = take(&mut self.calculate_gas);
@@ -180,6 +187,7 @@ impl<'a> SP1ContextBuilder<'a> {
         SP1Context {
             hook_registry,
             subproof_verifier,
+            custom_curves,
             max_cycles: cycle_limit,
             deferred_proof_verification,
             calculate_gas,
@@ -236,6 +244,16 @@ impl<'a> SP1ContextBuilder<'a> {
         self
     }
 
+    /// Add the implementation of the custom curve [`CurveType::Custom`] with id `id`.
+    ///
+    /// Registering an id again replaces its implementation.
+    ///
+    /// [`CurveType::Custom`]: sp1_curves::CurveType::Custom
+    pub fn custom_curve(&mut self, id: u32, curve: Arc<dyn CustomCurve>) -> &mut Self {
+        self.custom_curves.register(id, curve);
+        self
+    }
+
     /// Set the maximum number of cpu cycles to use for execution.
     /// `report.total_instruction_count()` will be less than or equal to `max_cycles`.
     pub fn max_cycles(&mut self, max_cycles: u64) -> &mut Self {
@@ -312,8 +330,38 @@ impl<W: Write + Send> IoWriter for W {}
 mod tests {
     use std::sync::Arc;
 
+    use sp1_curves::{
+        registry::{CustomCurve, CustomPoint},
+        BigUint, CurveType,
+    };
+
     use crate::{subproof::NoOpSubproofVerifier, SP1Context};
 
+    /// A custom curve that is only registered, never operated on.
+    struct NoCurve;
+
+    impl CustomCurve for NoCurve {
+        fn prime_group_order(&self) -> BigUint {
+            unimplemented!()
+        }
+
+        fn add(&self, _: &CustomPoint, _: &CustomPoint) -> CustomPoint {
+            unimplemented!()
+        }
+
+        fn double(&self, _: &CustomPoint) -> CustomPoint {
+            unimplemented!()
+        }
+
+        fn decompress(&self, _: &[u8], _: u32) -> Option<CustomPoint> {
+            unimplemented!()
+        }
+
+        fn sqrt(&self, _: &BigUint) -> Option<BigUint> {
+            unimplemented!()
+        }
+    }
+
     #[test]
     fn defaults() {
         let SP1Context { hook_registry, subproof_verifier, max_cycles: cycle_limit, .. } =
@@ -344,6 +392,17 @@ mod tests {
         assert_eq!(&hook_registry.unwrap().table.into_keys().collect::<Vec<_>>(), &[30]);
     }
 
+    #[test]
+    fn custom_curve() {
+        let SP1Context { custom_curves, .. } = SP1Context::builder().build();
+        assert!(custom_curves.get(CurveType::Custom(7)).is_none());
+
+        let SP1Context { custom_curves, .. } =
+            SP1Context::builder().custom_curve(7, Arc::new(NoCurve)).build();
+        assert!(custom_curves.get(CurveType::Custom(7)).is_some());
+        assert!(custom_curves.get(CurveType::Custom(8)).is_none());
+    }
+
     #[test]
     fn subproof_verifier() {
         let verifier = NoOpSubproofVerifier;
@@ -0,0 +1,763 @@
+use thiserror::Error;
+
+use super::{
+    BlsDeserializeEvent, EcEventError, EcValidationStatus, EcrecoverEvent, EdwardsDecompressEvent,
+    EllipticCurveAddBatchEvent, EllipticCurveAddBytesEvent, EllipticCurveAddEvent,
+    EllipticCurveAddEventN, EllipticCurveAddProjectiveEvent, EllipticCurveAddToEvent,
+    EllipticCurveClearCofactorEvent, EllipticCurveCompressEvent, EllipticCurveDecompressBatchEvent,
+    EllipticCurveDecompressEvent, EllipticCurveDecompressMemSignEvent, EllipticCurveDoubleEvent,
+    EllipticCurveDoubleEventN, EllipticCurveEqualsEvent, EllipticCurveHashToCurveEvent,
+    EllipticCurveIsNegationEvent, EllipticCurveLadderStepEvent, EllipticCurveLoadValidateEvent,
+    EllipticCurveMsmEvent, EllipticCurveNegateEvent, EllipticCurveNoopEvent,
+    EllipticCurveNormalizeProjectiveEvent, EllipticCurvePageProtRecords,
+    EllipticCurvePointAndNegateEvent, EllipticCurveRepeatedDoubleEvent,
+    EllipticCurveScalarMulEvent, EllipticCurveSec1DecompressEvent, EllipticCurveSubgroupCheckEvent,
+    EllipticCurveToJacobianEvent, FieldInverseEvent, G2DecompressEvent, G2PsiEvent,
+    MillerStepEvent, ScalarReduceEvent,
+};
+use crate::events::{
+    MemoryLocalEvent, MemoryReadRecord, MemoryRecord, MemoryWriteRecord, PageProtLocalEvent,
+    PageProtRecord,
+};
+
+/// Errors that can occur while decoding an event from its compact encoding.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CompactDecodeError {
+    /// The input ended in the middle of a value.
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    /// A variable-length integer does not fit in 64 bits.
+    #[error("variable-length integer overflows 64 bits")]
+    VarintOverflow,
+    /// A boolean, option or enum tag has an unknown value.
+    #[error("invalid tag {0}")]
+    InvalidTag(u8),
+    /// The input continues after the end of the event.
+    #[error("{0} trailing bytes after the event")]
+    TrailingBytes(usize),
+    /// The decoded event is not well formed.
+    #[error("invalid event: {0}")]
+    Invalid(#[from] EcEventError),
+}
+
+/// A compact binary encoding of the elliptic curve events, for shipping shards over the network.
+///
+/// The layout is fixed by the field order of each event, with no field names. Counters, pointers
+/// and timestamps are LEB128 variable-length integers, which are short for the small values they
+/// usually hold. Point words and memory values are close to uniformly random, so they are stored as
+/// raw little-endian `u64`s. Lists are prefixed with their variable-length size, and the arrays of
+/// the fixed-size events, whose size is known, are not prefixed at all.
+pub trait CompactEncoding: Sized {
+    /// Appends the encoding of the value to `out`.
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Decodes a value from the front of `input`, advancing it past the value.
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError>;
+
+    /// Returns the compact encoding of the value.
+    #[must_use]
+    fn to_compact_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode(&mut out);
+        out
+    }
+
+    /// Decodes a value from its compact encoding, returning an error if any bytes are left over.
+    fn from_compact_bytes(bytes: &[u8]) -> Result<Self, CompactDecodeError> {
+        let mut input = bytes;
+        let value = Self::decode(&mut input)?;
+        if !input.is_empty() {
+            return Err(CompactDecodeError::TrailingBytes(input.len()));
+        }
+        Ok(value)
+    }
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], CompactDecodeError> {
+    if input.len() < len {
+        return Err(CompactDecodeError::UnexpectedEnd);
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+fn encode_word(word: u64, out: &mut Vec<u8>) {
+    out.extend_from_slice(&word.to_le_bytes());
+}
+
+fn decode_word(input: &mut &[u8]) -> Result<u64, CompactDecodeError> {
+    Ok(u64::from_le_bytes(take(input, 8)?.try_into().unwrap()))
+}
+
+fn decode_len(input: &mut &[u8]) -> Result<usize, CompactDecodeError> {
+    Ok(u64::decode(input)? as usize)
+}
+
+impl CompactEncoding for u64 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let mut value = *self;
+        while value >= 0x80 {
+            out.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = take(input, 1)?[0];
+            let bits = u64::from(byte & 0x7f);
+            if bits << shift >> shift != bits {
+                return Err(CompactDecodeError::VarintOverflow);
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(CompactDecodeError::VarintOverflow)
+    }
+}
+
+impl CompactEncoding for u32 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        u64::from(*self).encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        u64::decode(input)?.try_into().map_err(|_| CompactDecodeError::VarintOverflow)
+    }
+}
+
+impl CompactEncoding for u8 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        Ok(take(input, 1)?[0])
+    }
+}
+
+impl CompactEncoding for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(u8::from(*self));
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        match u8::decode(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(CompactDecodeError::InvalidTag(tag)),
+        }
+    }
+}
+
+impl CompactEncoding for EcValidationStatus {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        match u8::decode(input)? {
+            0 => Ok(Self::Valid),
+            1 => Ok(Self::NotOnCurve),
+            2 => Ok(Self::NotInSubgroup),
+            tag => Err(CompactDecodeError::InvalidTag(tag)),
+        }
+    }
+}
+
+impl<T: CompactEncoding> CompactEncoding for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.encode(out);
+            }
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        match u8::decode(input)? {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode(input)?)),
+            tag => Err(CompactDecodeError::InvalidTag(tag)),
+        }
+    }
+}
+
+/// Point words, encoded as raw words after their count.
+impl CompactEncoding for Vec<u64> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (self.len() as u64).encode(out);
+        for &word in self {
+            encode_word(word, out);
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        let len = decode_len(input)?;
+        (0..len).map(|_| decode_word(input)).collect()
+    }
+}
+
+impl<const N: usize> CompactEncoding for [u64; N] {
+    fn encode(&self, out: &mut Vec<u8>) {
+        for &word in self {
+            encode_word(word, out);
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        let mut words = [0; N];
+        for word in &mut words {
+            *word = decode_word(input)?;
+        }
+        Ok(words)
+    }
+}
+
+/// Implements [`CompactEncoding`] for lists, and for arrays when given a default value, of the
+/// given element types.
+macro_rules! impl_compact_encoding_for_lists {
+    ($($ty:ty $(= $default:expr)?),* $(,)?) => {
+        $(
+            impl CompactEncoding for Vec<$ty> {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    (self.len() as u64).encode(out);
+                    for item in self {
+                        item.encode(out);
+                    }
+                }
+
+                fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+                    let len = decode_len(input)?;
+                    // Cap the preallocation, since a corrupted length may be arbitrarily large.
+                    let mut items = Vec::with_capacity(len.min(input.len()));
+                    for _ in 0..len {
+                        items.push(<$ty>::decode(input)?);
+                    }
+                    Ok(items)
+                }
+            }
+
+            $(
+                impl<const N: usize> CompactEncoding for [$ty; N] {
+                    fn encode(&self, out: &mut Vec<u8>) {
+                        for item in self {
+                            item.encode(out);
+                        }
+                    }
+
+                    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+                        let mut items = [$default; N];
+                        for item in &mut items {
+                            *item = <$ty>::decode(input)?;
+                        }
+                        Ok(items)
+                    }
+                }
+            )?
+        )*
+    };
+}
+
+impl_compact_encoding_for_lists!(
+    u8,
+    PageProtRecord,
+    PageProtLocalEvent,
+    MemoryLocalEvent,
+    MemoryReadRecord = MemoryReadRecord::default(),
+    MemoryWriteRecord = MemoryWriteRecord::default(),
+);
+
+/// Implements [`CompactEncoding`] for structs by encoding the given fields in order.
+///
+/// The list must name every field, since decoding builds the struct from it. A struct marked with
+/// `#[method]` is checked by calling that method on the decoded value.
+macro_rules! impl_compact_encoding {
+    ($($(#[$check:ident])? $name:ident $(<$n:ident>)? { $($field:ident),* $(,)? })*) => {
+        $(
+            impl$(<const $n: usize>)? CompactEncoding for $name$(<$n>)? {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    $(self.$field.encode(out);)*
+                }
+
+                fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+                    let value = Self { $($field: CompactEncoding::decode(input)?,)* };
+                    $(value.$check()?;)?
+                    Ok(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_compact_encoding! {
+    PageProtRecord { external_flag, timestamp, page_prot }
+    PageProtLocalEvent { page_idx, initial_page_prot_access, final_page_prot_access }
+    MemoryLocalEvent { addr, initial_mem_access, final_mem_access }
+    EllipticCurvePageProtRecords { read_page_prot_records, write_page_prot_records }
+
+    #[validate]
+    EllipticCurveAddEvent {
+        clk, syscall_id, p_ptr, p, q_ptr, q, p_memory_records, q_memory_records, local_mem_access,
+        page_prot_records, local_page_prot_access, result_is_identity, cycles, checksum,
+    }
+    EllipticCurveAddToEvent {
+        clk, syscall_id, p_ptr, p, q_ptr, q, out_ptr, result, p_memory_records, q_memory_records,
+        out_memory_records, local_mem_access, page_prot_records, local_page_prot_access,
+        result_is_identity, cycles, checksum,
+    }
+    EllipticCurveAddBytesEvent {
+        clk, syscall_id, p_ptr, p, q_ptr, q, len, p_memory_records, q_memory_records,
+        local_mem_access, page_prot_records, local_page_prot_access, result_is_identity, checksum,
+    }
+    EllipticCurveDoubleEvent {
+        clk, syscall_id, p_ptr, p, p_memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, result_is_identity, unchanged_write,
+        cycles, jacobian_z_words, checksum,
+    }
+    EllipticCurveAddEventN<N> {
+        clk, syscall_id, p_ptr, p, q_ptr, q, p_memory_records, q_memory_records, local_mem_access,
+        page_prot_records, local_page_prot_access, result_is_identity, cycles, checksum,
+    }
+    EllipticCurveRepeatedDoubleEvent {
+        clk, syscall_id, p_ptr, p, n, p_memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, result_is_identity, cycles, checksum,
+    }
+    EllipticCurveDoubleEventN<N> {
+        clk, syscall_id, p_ptr, p, p_memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, result_is_identity, unchanged_write,
+        cycles, jacobian_z_words, checksum,
+    }
+    EllipticCurveAddProjectiveEvent {
+        clk, syscall_id, acc_ptr, acc, q_ptr, q, acc_memory_records, q_memory_records,
+        local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveNormalizeProjectiveEvent {
+        clk, syscall_id, acc_ptr, acc, result_ptr, result, acc_memory_records,
+        result_memory_records, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveNoopEvent {
+        clk, syscall_id, p_ptr, p, q_ptr, q, p_memory_records, q_memory_records, local_mem_access,
+        page_prot_records, local_page_prot_access, cycles, checksum,
+    }
+    EllipticCurveToJacobianEvent {
+        clk, syscall_id, p_ptr, p, result_ptr, result, p_memory_records, result_memory_records,
+        local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveNegateEvent {
+        clk, syscall_id, p_ptr, p, p_memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, checksum,
+    }
+    EllipticCurvePointAndNegateEvent {
+        clk, syscall_id, p_ptr, p, neg_ptr, neg, p_memory_records, neg_memory_records,
+        local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveLadderStepEvent {
+        clk, syscall_id, ptr, bit, points, result, memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, checksum,
+    }
+    G2PsiEvent {
+        clk, syscall_id, p_ptr, p, p_memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, checksum,
+    }
+    EllipticCurveClearCofactorEvent {
+        clk, syscall_id, p_ptr, p, p_memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, checksum,
+    }
+    EllipticCurveDecompressEvent {
+        clk, syscall_id, ptr, sign_bit, x_bytes, decompressed_y_bytes, sign_corrected,
+        x_memory_records, y_memory_records, local_mem_access, page_prot_records,
+        local_page_prot_access, cycles, checksum,
+    }
+    EllipticCurveDecompressMemSignEvent { sign_ptr, sign_memory_record, decompress, checksum }
+    EllipticCurveDecompressBatchEvent {
+        clk, syscall_id, input_ptr, output_ptr, n, sign_bits, x_words, status, points,
+        n_memory_record, sign_bits_memory_records, x_memory_records, status_memory_record,
+        points_memory_records, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveSec1DecompressEvent {
+        clk, syscall_id, compressed_ptr, compressed, output_ptr, uncompressed,
+        compressed_memory_records, uncompressed_memory_records, local_mem_access, page_prot_records,
+        local_page_prot_access, checksum,
+    }
+    EdwardsDecompressEvent {
+        clk, syscall_id, compressed_ptr, compressed, output_ptr, status, point,
+        compressed_memory_records, status_memory_record, point_memory_records, local_mem_access,
+        page_prot_records, local_page_prot_access, checksum,
+    }
+    G2DecompressEvent {
+        clk, syscall_id, compressed_ptr, compressed, output_ptr, status, point,
+        compressed_memory_records, status_memory_record, point_memory_records, local_mem_access,
+        page_prot_records, local_page_prot_access, checksum,
+    }
+    BlsDeserializeEvent {
+        clk, syscall_id, g1_ptr, g1_compressed, g2_ptr, g2_compressed, output_ptr, g1_status,
+        g2_status, g1_point, g2_point, g1_memory_records, g2_memory_records, status_memory_record,
+        point_memory_records, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveCompressEvent {
+        clk, syscall_id, p_ptr, p, compressed_ptr, compressed, p_memory_records,
+        compressed_memory_records, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveScalarMulEvent {
+        clk, syscall_id, p_ptr, p, scalar_ptr, scalar, scalar_montgomery, p_memory_records,
+        scalar_memory_records, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveMsmEvent {
+        clk, syscall_id, result_ptr, input_ptr, n, points, scalars, n_memory_record,
+        points_memory_records, scalars_memory_records, result_memory_records, local_mem_access,
+        page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveAddBatchEvent {
+        clk, syscall_id, base_ptr, result_ptr, count, points, result, points_memory_records,
+        result_memory_records, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveSubgroupCheckEvent {
+        clk, syscall_id, p_ptr, p, result_ptr, in_subgroup, p_memory_records, result_memory_record,
+        local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveLoadValidateEvent {
+        clk, syscall_id, p_ptr, p, result_ptr, check_subgroup, status, p_memory_records,
+        result_memory_record, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveEqualsEvent {
+        clk, syscall_id, p_ptr, p, q_ptr, q, result_ptr, equal, p_memory_records, q_memory_records,
+        result_memory_record, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+    EllipticCurveIsNegationEvent {
+        clk, syscall_id, p_ptr, p, q_ptr, q, result_ptr, negation, p_memory_records,
+        q_memory_records, result_memory_record, local_mem_access, page_prot_records,
+        local_page_prot_access, checksum,
+    }
+    ScalarReduceEvent {
+        clk, syscall_id, ptr, scalar, reduced, scalar_memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, checksum,
+    }
+    FieldInverseEvent {
+        clk, syscall_id, ptr, x, inverse, x_memory_records, local_mem_access,
+        write_slice_page_prot_access, local_page_prot_access, checksum,
+    }
+    EllipticCurveHashToCurveEvent {
+        clk, syscall_id, p_ptr, p, u_ptr, u, p_memory_records, u_memory_records, local_mem_access,
+        page_prot_records, local_page_prot_access, checksum,
+    }
+    EcrecoverEvent {
+        clk, syscall_id, result_ptr, input_ptr, hash, r, s, recovery_id, public_key, valid,
+        input_memory_records, result_memory_records, local_mem_access, page_prot_records,
+        local_page_prot_access, checksum,
+    }
+    MillerStepEvent {
+        clk, syscall_id, t_ptr, t, p_ptr, p, line_ptr, line, t_memory_records, p_memory_records,
+        line_memory_records, local_mem_access, page_prot_records, local_page_prot_access, checksum,
+    }
+}
+
+impl CompactEncoding for MemoryRecord {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.timestamp.encode(out);
+        encode_word(self.value, out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        Ok(Self { timestamp: u64::decode(input)?, value: decode_word(input)? })
+    }
+}
+
+impl CompactEncoding for MemoryReadRecord {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_word(self.value, out);
+        self.timestamp.encode(out);
+        self.prev_timestamp.encode(out);
+        self.prev_page_prot_record.encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        Ok(Self {
+            value: decode_word(input)?,
+            timestamp: u64::decode(input)?,
+            prev_timestamp: u64::decode(input)?,
+            prev_page_prot_record: CompactEncoding::decode(input)?,
+        })
+    }
+}
+
+impl CompactEncoding for MemoryWriteRecord {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_word(self.value, out);
+        self.timestamp.encode(out);
+        encode_word(self.prev_value, out);
+        self.prev_timestamp.encode(out);
+        self.prev_page_prot_record.encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, CompactDecodeError> {
+        Ok(Self {
+            value: decode_word(input)?,
+            timestamp: u64::decode(input)?,
+            prev_value: decode_word(input)?,
+            prev_timestamp: u64::decode(input)?,
+            prev_page_prot_record: CompactEncoding::decode(input)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+    use sp1_curves::{
+        edwards::ed25519::Ed25519BaseField,
+        params::NumWords,
+        weierstrass::{
+            bls12_381::Bls12381BaseField, bn254::Bn254BaseField, secp256k1::Secp256k1BaseField,
+            secp256r1::Secp256r1BaseField,
+        },
+    };
+    use typenum::Unsigned;
+
+    use super::*;
+
+    /// The SplitMix64 generator, enough to draw arbitrary field values.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        }
+
+        /// Draws a value of a random bit length, so that both short and long varints occur.
+        fn int(&mut self) -> u64 {
+            let bits = self.next() % 65;
+            self.next().checked_shr(64 - bits as u32).unwrap_or(0)
+        }
+
+        fn bool(&mut self) -> bool {
+            self.next() & 1 == 1
+        }
+
+        fn words(&mut self, len: usize) -> Vec<u64> {
+            (0..len).map(|_| self.next()).collect()
+        }
+
+        fn page_prot(&mut self) -> PageProtRecord {
+            PageProtRecord {
+                external_flag: self.bool(),
+                timestamp: self.int(),
+                page_prot: self.next() as u8,
+            }
+        }
+
+        fn page_prots(&mut self) -> Vec<PageProtRecord> {
+            (0..self.next() % 3).map(|_| self.page_prot()).collect()
+        }
+
+        fn page_prot_records(&mut self) -> EllipticCurvePageProtRecords {
+            EllipticCurvePageProtRecords {
+                read_page_prot_records: self.page_prots(),
+                write_page_prot_records: self.page_prots(),
+            }
+        }
+
+        fn prev_page_prot(&mut self) -> Option<PageProtRecord> {
+            self.bool().then(|| self.page_prot())
+        }
+
+        fn checksum(&mut self) -> Option<u64> {
+            self.bool().then(|| self.next())
+        }
+
+        fn reads(&mut self, len: usize) -> Vec<MemoryReadRecord> {
+            (0..len)
+                .map(|_| MemoryReadRecord {
+                    value: self.next(),
+                    timestamp: self.int(),
+                    prev_timestamp: self.int(),
+                    prev_page_prot_record: self.prev_page_prot(),
+                })
+                .collect()
+        }
+
+        fn writes(&mut self, len: usize) -> Vec<MemoryWriteRecord> {
+            (0..len)
+                .map(|_| MemoryWriteRecord {
+                    value: self.next(),
+                    timestamp: self.int(),
+                    prev_value: self.next(),
+                    prev_timestamp: self.int(),
+                    prev_page_prot_record: self.prev_page_prot(),
+                })
+                .collect()
+        }
+
+        fn memory_record(&mut self) -> MemoryRecord {
+            MemoryRecord { timestamp: self.int(), value: self.next() }
+        }
+
+        fn local_mem_access(&mut self, len: usize) -> Vec<MemoryLocalEvent> {
+            (0..len)
+                .map(|_| MemoryLocalEvent {
+                    addr: self.int(),
+                    initial_mem_access: self.memory_record(),
+                    final_mem_access: self.memory_record(),
+                })
+                .collect()
+        }
+
+        fn local_page_prot_access(&mut self) -> Vec<PageProtLocalEvent> {
+            (0..self.next() % 3)
+                .map(|_| PageProtLocalEvent {
+                    page_idx: self.int(),
+                    initial_page_prot_access: self.page_prot(),
+                    final_page_prot_access: self.page_prot(),
+                })
+                .collect()
+        }
+    }
+
+    fn random_add_event(rng: &mut Rng, num_words: usize) -> EllipticCurveAddEvent {
+        EllipticCurveAddEvent {
+            clk: rng.int(),
+            syscall_id: rng.int() as u32,
+            p_ptr: rng.int(),
+            p: rng.words(num_words),
+            q_ptr: rng.int(),
+            q: rng.words(num_words),
+            p_memory_records: rng.writes(num_words),
+            q_memory_records: rng.reads(num_words),
+            local_mem_access: rng.local_mem_access(2 * num_words),
+            page_prot_records: rng.page_prot_records(),
+            local_page_prot_access: rng.local_page_prot_access(),
+            result_is_identity: rng.bool(),
+            cycles: rng.int(),
+            checksum: rng.checksum(),
+        }
+    }
+
+    fn random_double_event(rng: &mut Rng, num_words: usize) -> EllipticCurveDoubleEvent {
+        EllipticCurveDoubleEvent {
+            clk: rng.int(),
+            syscall_id: rng.int() as u32,
+            p_ptr: rng.int(),
+            p: rng.words(num_words),
+            p_memory_records: rng.writes(num_words),
+            local_mem_access: rng.local_mem_access(num_words),
+            write_slice_page_prot_access: rng.page_prots(),
+            local_page_prot_access: rng.local_page_prot_access(),
+            result_is_identity: rng.bool(),
+            unchanged_write: rng.bool(),
+            cycles: rng.int(),
+            jacobian_z_words: rng.bool().then(|| rng.words(num_words / 2)),
+            checksum: rng.checksum(),
+        }
+    }
+
+    fn random_decompress_event(rng: &mut Rng, num_words: usize) -> EllipticCurveDecompressEvent {
+        let num_bytes = num_words * 4;
+        EllipticCurveDecompressEvent {
+            clk: rng.int(),
+            syscall_id: rng.int() as u32,
+            ptr: rng.int(),
+            sign_bit: rng.bool(),
+            x_bytes: (0..num_bytes).map(|_| rng.next() as u8).collect(),
+            decompressed_y_bytes: (0..num_bytes).map(|_| rng.next() as u8).collect(),
+            sign_corrected: rng.bool(),
+            x_memory_records: rng.reads(num_words / 2),
+            y_memory_records: rng.writes(num_words / 2),
+            local_mem_access: rng.local_mem_access(num_words),
+            page_prot_records: rng.page_prot_records(),
+            local_page_prot_access: rng.local_page_prot_access(),
+            cycles: rng.int(),
+            checksum: rng.checksum(),
+        }
+    }
+
+    /// Checks that the event round-trips, comparing the bincode encodings as the events do not
+    /// implement `PartialEq`, and returns the sizes of the compact and bincode encodings.
+    fn check_round_trip<T: CompactEncoding + Serialize>(event: &T) -> (usize, usize) {
+        let bytes = event.to_compact_bytes();
+        let decoded = T::from_compact_bytes(&bytes).unwrap();
+        let bincode_bytes = bincode::serialize(event).unwrap();
+        assert_eq!(bincode::serialize(&decoded).unwrap(), bincode_bytes);
+        (bytes.len(), bincode_bytes.len())
+    }
+
+    #[test]
+    fn test_compact_round_trip() {
+        let mut rng = Rng(0x5eed);
+        for num_words in [
+            <Secp256k1BaseField as NumWords>::WordsCurvePoint::USIZE,
+            <Secp256r1BaseField as NumWords>::WordsCurvePoint::USIZE,
+            <Bn254BaseField as NumWords>::WordsCurvePoint::USIZE,
+            <Bls12381BaseField as NumWords>::WordsCurvePoint::USIZE,
+            <Ed25519BaseField as NumWords>::WordsCurvePoint::USIZE,
+        ] {
+            for _ in 0..20 {
+                check_round_trip(&random_add_event(&mut rng, num_words));
+                check_round_trip(&random_double_event(&mut rng, num_words));
+                check_round_trip(&random_decompress_event(&mut rng, num_words));
+            }
+        }
+
+        let event = random_add_event(&mut rng, 8);
+        check_round_trip(&EllipticCurveAddEventN::<8>::try_from(event).unwrap());
+        check_round_trip(&EllipticCurveLoadValidateEvent {
+            status: EcValidationStatus::NotInSubgroup,
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_compact_smaller_than_bincode() {
+        // Timestamps and pointers of a typical shard are far from using all 64 bits.
+        let mut rng = Rng(0x5eed);
+        let mut event = random_add_event(&mut rng, 12);
+        event.clk = 1 << 20;
+        event.p_ptr = 0x1000;
+        event.q_ptr = 0x2000;
+        for record in &mut event.p_memory_records {
+            (record.timestamp, record.prev_timestamp) = (1 << 20, 1 << 18);
+        }
+        for record in &mut event.q_memory_records {
+            (record.timestamp, record.prev_timestamp) = (1 << 20, 1 << 18);
+        }
+        for local in &mut event.local_mem_access {
+            local.addr = 0x1000;
+            local.initial_mem_access.timestamp = 1 << 18;
+            local.final_mem_access.timestamp = 1 << 20;
+        }
+        let (compact, bincode) = check_round_trip(&event);
+        assert!(compact * 4 < bincode * 3, "{compact} bytes is not much less than {bincode}");
+    }
+
+    #[test]
+    fn test_compact_decode_errors() {
+        let bytes = random_double_event(&mut Rng(1), 8).to_compact_bytes();
+        assert_eq!(
+            EllipticCurveDoubleEvent::from_compact_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+            CompactDecodeError::UnexpectedEnd
+        );
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            EllipticCurveDoubleEvent::from_compact_bytes(&trailing).unwrap_err(),
+            CompactDecodeError::TrailingBytes(1)
+        );
+
+        assert_eq!(bool::from_compact_bytes(&[2]).unwrap_err(), CompactDecodeError::InvalidTag(2));
+        assert_eq!(
+            u64::from_compact_bytes(&[0xff; 10]).unwrap_err(),
+            CompactDecodeError::VarintOverflow
+        );
+        assert_eq!(u64::from_compact_bytes(&u64::MAX.to_compact_bytes()), Ok(u64::MAX));
+    }
+}
@@ -0,0 +1,3175 @@
+use std::sync::Arc;
+
+use deepsize2::DeepSizeOf;
+use serde::{Deserialize, Serialize};
+
+use sp1_curves::{
+    edwards::{ed25519::Ed25519Parameters, EdwardsParameters},
+    params::{FieldParameters, NumLimbs, NumWords},
+    registry::CustomCurve,
+    weierstrass::{
+        bls12_381::{
+            bls12381_compress, bls12381_g2_psi, Bls12381, Bls12381BaseField, Bls12381G2Parameters,
+        },
+        g2::{Fp2, G2AffinePoint, G2Parameters},
+        jacobian::{co_z_ladder_step, JacobianPoint},
+        WeierstrassParameters,
+    },
+    AffinePoint, BigUint, CurveType, EcOp, EllipticCurve,
+};
+use sp1_primitives::consts::{
+    bytes_to_words_le_vec, words_to_bytes_be_vec, words_to_bytes_le_vec, PAGE_SIZE, PROT_READ,
+    PROT_WRITE,
+};
+use typenum::Unsigned;
+
+use super::{
+    events::{
+        with_checksum, BlsDeserializeEvent, EcValidationStatus, EcrecoverEvent,
+        EdwardsDecompressEvent, EllipticCurveAddBatchEvent, EllipticCurveAddBytesEvent,
+        EllipticCurveAddEvent, EllipticCurveAddProjectiveEvent, EllipticCurveAddToEvent,
+        EllipticCurveClearCofactorEvent, EllipticCurveCompressEvent,
+        EllipticCurveDecompressBatchEvent, EllipticCurveDecompressEvent,
+        EllipticCurveDecompressMemSignEvent, EllipticCurveDoubleEvent, EllipticCurveEqualsEvent,
+        EllipticCurveHashToCurveEvent, EllipticCurveIsNegationEvent, EllipticCurveLadderStepEvent,
+        EllipticCurveLoadValidateEvent, EllipticCurveMsmEvent, EllipticCurveNegateEvent,
+        EllipticCurveNormalizeProjectiveEvent, EllipticCurvePageProtRecords,
+        EllipticCurvePointAndNegateEvent, EllipticCurveRepeatedDoubleEvent,
+        EllipticCurveScalarMulEvent, EllipticCurveSec1DecompressEvent,
+        EllipticCurveSubgroupCheckEvent, EllipticCurveToJacobianEvent, FieldInverseEvent,
+        G2DecompressEvent, G2PsiEvent, MillerStepEvent, ScalarReduceEvent,
+    },
+    helpers::{
+        bls_deserialize_status, canonical_root, check_aligned, check_chord_operands,
+        check_num_words, check_on_curve, check_tangent_operand, covering_words,
+        debug_assert_result_on_curve, decode_g2_point, decode_point, decompress_for_curve,
+        ec_add_or_identity, ec_add_or_identity_with, ec_double_or_identity,
+        ec_double_or_identity_with, ec_negate, ec_scalar_mul, ecrecover, edwards_decompress,
+        edwards_recover_x, encode_g2_point, encode_point, finite_point, from_montgomery,
+        g1_decompress, g2_decompress, in_prime_subgroup, is_identity, jacobian_double_z_words,
+        lifts_to_point, limbs_to_words, map_to_curve_for_curve, ranges_overlap,
+        secp256k1_glv_scalar_mul,
+    },
+};
+use crate::{
+    events::{
+        memory::MemoryReadRecord, AssertionPolicy, EcSyscallError, EC_SYSCALL_FAILED,
+        EC_SYSCALL_FAILED_EXIT_CODE,
+    },
+    syscalls::{SyscallContext, SyscallMemory},
+    ExecutorConfig, HALT_PC,
+};
+
+/// An event created by [`create_ec_event`], one per [`EcOp`].
+#[derive(Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub enum EllipticCurveEvent {
+    /// An event created by [`create_ec_add_event`].
+    Add(EllipticCurveAddEvent),
+    /// An event created by [`create_ec_double_event`].
+    Double(EllipticCurveDoubleEvent),
+    /// An event created by [`create_ec_decompress_event`].
+    Decompress(EllipticCurveDecompressEvent),
+}
+
+impl EllipticCurveEvent {
+    /// Returns the operation that created the event.
+    #[must_use]
+    pub fn op(&self) -> EcOp {
+        match self {
+            Self::Add(_) => EcOp::Add,
+            Self::Double(_) => EcOp::Double,
+            Self::Decompress(_) => EcOp::Decompress,
+        }
+    }
+}
+
+/// Create an elliptic curve event for the given operation.
+///
+/// The arguments are those of the syscall, passed on to [`create_ec_add_event`],
+/// [`create_ec_double_event`] or [`create_ec_decompress_event`], which also document the panics.
+pub fn create_ec_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    op: EcOp,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveEvent {
+    match op {
+        EcOp::Add => EllipticCurveEvent::Add(create_ec_add_event::<E, Ex>(rt, arg1, arg2)),
+        EcOp::Double => EllipticCurveEvent::Double(create_ec_double_event::<E, Ex>(rt, arg1, arg2)),
+        EcOp::Decompress => {
+            EllipticCurveEvent::Decompress(create_ec_decompress_event::<E, Ex>(rt, arg1, arg2))
+        }
+    }
+}
+
+/// Create an elliptic curve add event. It takes two pointers to memory locations, reads the points
+/// from memory, adds them together, and writes the result back to the first memory location.
+/// The generic parameter `N` is the number of u32 words in the point representation. For example,
+/// for the secp256k1 curve, `N` would be 16 (64 bytes) because the x and y coordinates are 32 bytes
+/// each.
+///
+/// This is also used for twisted Edwards curves such as ed25519. Their addition law is complete, so
+/// the case `p == q` needs no special handling.
+///
+/// The identity is encoded as given by [`EllipticCurve::IDENTITY_ENCODING`]. On short Weierstrass
+/// curves, all-zero words encode the point at infinity: adding it returns the other operand
+/// unchanged, and `P + (-P)` is written as all-zero words. Adding a point to itself,
+/// whether through aliased pointers or two copies of the same point, uses the doubling formula.
+///
+/// # Panics
+///
+/// Panics if either pointer is misaligned or, with [`ExecutorConfig::VALIDATE_EC_INPUTS`], a point
+/// is not on the curve. See [`try_create_ec_add_event`] for a non-panicking variant.
+pub fn create_ec_add_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveAddEvent {
+    try_create_ec_add_event::<E, Ex>(rt, arg1, arg2).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Create an elliptic curve add event, returning an error instead of panicking if either pointer is
+/// misaligned.
+///
+/// An error is also returned, before any memory is accessed, if page protection forbids reading
+/// `q` or updating `p`, or if the points partially overlap. `p_ptr` and `q_ptr` may be equal, to
+/// double a point, but a `q` sharing only some words with `p` would be read from a mix of the two
+/// points and is rejected rather than snapshotted. If [`ExecutorConfig::VALIDATE_EC_INPUTS`] is
+/// set, an error is also returned if either point is not on the curve.
+pub fn try_create_ec_add_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> Result<EllipticCurveAddEvent, EcSyscallError> {
+    let (p_ptr, q_ptr) = (arg1, arg2);
+    check_add_args::<E, Ex>(rt, p_ptr, q_ptr)?;
+    let custom = resolve_custom_curve::<E, Ex>(rt)?;
+    charge_ec_op(rt)?;
+
+    ec_add_event::<E, Ex>(rt, custom.as_deref(), p_ptr, q_ptr)
+}
+
+/// Create an elliptic curve add event for the `*_ADD` syscalls of short Weierstrass curves.
+///
+/// The add chips constrain the chord formula alone, so unlike [`try_create_ec_add_event`] the
+/// operands must be two finite points with different `x` coordinates. An
+/// [`EcSyscallError::ExceptionalOperands`] error is returned, before any memory is accessed, if
+/// either point is the point at infinity or if `P = Q` or `P = -Q`, including when `p_ptr` and
+/// `q_ptr` are equal. The sum written is then the one the chips compute.
+pub fn try_create_ec_add_assign_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> Result<EllipticCurveAddEvent, EcSyscallError> {
+    let (p_ptr, q_ptr) = (arg1, arg2);
+    let num_words = check_add_args::<E, Ex>(rt, p_ptr, q_ptr)?;
+    check_chord_operands::<E>(
+        &rt.slice_unsafe(p_ptr, num_words),
+        &rt.slice_unsafe(q_ptr, num_words),
+    )?;
+    let custom = resolve_custom_curve::<E, Ex>(rt)?;
+    charge_ec_op(rt)?;
+
+    ec_add_event::<E, Ex>(rt, custom.as_deref(), p_ptr, q_ptr)
+}
+
+/// Runs the checks of [`try_create_ec_add_event`] on its pointers, returning the number of words of
+/// a point.
+fn check_add_args<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    q_ptr: u64,
+) -> Result<usize, EcSyscallError> {
+    check_aligned("p_ptr", p_ptr)?;
+    check_aligned("q_ptr", q_ptr)?;
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_num_words(num_words)?;
+    if p_ptr != q_ptr && ranges_overlap(p_ptr, q_ptr, num_words) {
+        return Err(EcSyscallError::PartialOverlap { p_ptr, q_ptr });
+    }
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)?;
+    check_page_prot(rt, q_ptr, num_words, PROT_READ)?;
+    Ok(num_words)
+}
+
+/// The memory accesses and arithmetic of [`try_create_ec_add_event`], once its checks have passed.
+///
+/// This is generic over [`SyscallMemory`] so that it can run against a mock context in tests.
+pub(super) fn ec_add_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut impl SyscallMemory,
+    custom: Option<&dyn CustomCurve>,
+    p_ptr: u64,
+    q_ptr: u64,
+) -> Result<EllipticCurveAddEvent, EcSyscallError> {
+    let start_clk = rt.clk();
+    let num_words = AffinePoint::<E>::num_memory_words();
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    // The points are checked on the words peeked, so that an error leaves no access recorded.
+    let (result_affine, result_is_identity) = add_decoded_points::<E, Ex>(
+        custom,
+        decode_point::<E>(&p),
+        decode_point::<E>(&rt.slice_unsafe(q_ptr, num_words)),
+    )?;
+
+    let (q_memory_records, q, read_page_prot_records) = rt.mr_slice(q_ptr, num_words);
+
+    // When we write to p, we want the clk to be incremented because p and q could be the same.
+    rt.advance_for_aliasing()?;
+
+    let (p_memory_records, write_page_prot_records) =
+        rt.mw_slice(p_ptr, &result_affine.to_memory_words(), true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(EllipticCurveAddEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_id(),
+        p_ptr,
+        p,
+        q_ptr,
+        q,
+        p_memory_records,
+        q_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        result_is_identity,
+        cycles: rt.clk() - start_clk,
+        checksum: None,
+    }))
+}
+
+/// Create an elliptic curve add event from byte pointers, for guests that do not keep points in
+/// word-aligned memory.
+///
+/// This is [`create_ec_add_event`] with each point given as the `len` bytes at a pointer that need
+/// not be 8-byte aligned. The words covering each point are accessed, and the bytes of the covering
+/// words of `p` outside of the point are written back unchanged.
+///
+/// # Panics
+///
+/// Panics if `len` is not the size of a point or, with [`ExecutorConfig::VALIDATE_EC_INPUTS`], a
+/// point is not on the curve. See [`try_create_ec_add_event_bytes`] for a non-panicking variant.
+pub fn create_ec_add_event_bytes<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    q_ptr: u64,
+    len: u64,
+) -> EllipticCurveAddBytesEvent {
+    try_create_ec_add_event_bytes::<E, Ex>(rt, p_ptr, q_ptr, len)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Create an elliptic curve add event from byte pointers, returning an error instead of panicking
+/// if `len` is not the size of a point, two elements of the base field.
+///
+/// As with [`try_create_ec_add_event`], an error is also returned if page protection forbids the
+/// accesses, or if [`ExecutorConfig::VALIDATE_EC_INPUTS`] is set and a point is not on the curve.
+pub fn try_create_ec_add_event_bytes<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    q_ptr: u64,
+    len: u64,
+) -> Result<EllipticCurveAddBytesEvent, EcSyscallError> {
+    let start_clk = rt.clk;
+    let expected = 2 * <E::BaseField as NumLimbs>::Limbs::U64;
+    if len != expected {
+        return Err(EcSyscallError::InvalidLength { len, expected });
+    }
+    let (p_start, p_num_words) = covering_words(p_ptr, len);
+    let (q_start, q_num_words) = covering_words(q_ptr, len);
+    check_page_prot(rt, p_start, p_num_words, PROT_READ | PROT_WRITE)?;
+    check_page_prot(rt, q_start, q_num_words, PROT_READ)?;
+    let custom = resolve_custom_curve::<E, Ex>(rt)?;
+    charge_ec_op(rt)?;
+
+    let p_bytes = (p_ptr - p_start) as usize..(p_ptr - p_start + len) as usize;
+    let mut p_covering = words_to_bytes_le_vec(&rt.slice_unsafe(p_start, p_num_words));
+    let p = bytes_to_words_le_vec(&p_covering[p_bytes.clone()]);
+
+    let q_bytes = (q_ptr - q_start) as usize..(q_ptr - q_start + len) as usize;
+    let q_covering = words_to_bytes_le_vec(&rt.slice_unsafe(q_start, q_num_words));
+    let q = bytes_to_words_le_vec(&q_covering[q_bytes]);
+
+    // The points are checked on the words peeked, so that an error leaves no access recorded.
+    let (result_affine, result_is_identity) = add_decoded_points::<E, Ex>(
+        custom.as_deref(),
+        decode_point::<E>(&p),
+        decode_point::<E>(&q),
+    )?;
+
+    let (q_memory_records, _, read_page_prot_records) = rt.mr_slice(q_start, q_num_words);
+
+    // When we write to p, we want the clk to be incremented because p and q could overlap.
+    rt.advance_for_aliasing()?;
+
+    p_covering[p_bytes].copy_from_slice(&words_to_bytes_le_vec(&result_affine.to_memory_words()));
+
+    let (p_memory_records, write_page_prot_records) =
+        rt.mw_slice(p_start, &bytes_to_words_le_vec(&p_covering), true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(EllipticCurveAddBytesEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        q_ptr,
+        q,
+        len,
+        p_memory_records,
+        q_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        result_is_identity,
+        checksum: None,
+    }))
+}
+
+/// Handles an error of a fallible elliptic curve event constructor called by a syscall, according
+/// to [`ExecutorConfig::ASSERTION_POLICY`], returning the value the syscall returns.
+///
+/// An [`EcSyscallError::Execution`] error is not subject to the policy: it is set as the
+/// [`SyscallContext::execution_error`], which the executor returns. No precompile event is emitted
+/// for a failed syscall, which is why the executor only runs a trace under
+/// [`AssertionPolicy::Panic`].
+///
+/// # Panics
+///
+/// Panics with the error under [`AssertionPolicy::Panic`].
+pub(crate) fn handle_ec_syscall_error<Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    err: &EcSyscallError,
+) -> Option<u64> {
+    if let EcSyscallError::Execution(err) = err {
+        rt.execution_error = Some(err.clone());
+        return None;
+    }
+    match Ex::ASSERTION_POLICY {
+        AssertionPolicy::Panic => panic!("{err}"),
+        AssertionPolicy::ReturnError => Some(EC_SYSCALL_FAILED),
+        AssertionPolicy::LogAndHalt => {
+            tracing::error!("{} failed: {err}", rt.syscall_code);
+            rt.set_next_pc(HALT_PC);
+            rt.set_exit_code(EC_SYSCALL_FAILED_EXIT_CODE);
+            None
+        }
+    }
+}
+
+/// Counts an elliptic curve event against [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`], returning an
+/// [`EcSyscallError::QuotaExceeded`] error without counting it if the shard has none left.
+///
+/// The count is reset at every shard boundary. Constructors charge once their pointers and page
+/// protection are validated, so a failed check uses up no operation. The fallible constructors
+/// return the error before accessing memory, and the others panic with it.
+fn charge_ec_op<Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+) -> Result<(), EcSyscallError> {
+    charge_ec_ops(rt, 1)
+}
+
+/// Counts an event performing `count` elliptic curve operations like [`charge_ec_op`], returning
+/// an error without counting any of them if the shard does not have all of them left.
+fn charge_ec_ops<Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    count: u64,
+) -> Result<(), EcSyscallError> {
+    if let Some(max) = Ex::MAX_EC_OPS_PER_SHARD {
+        let ec_ops = &mut rt.rt.local_counts.ec_ops;
+        if ec_ops.checked_add(count).is_none_or(|total| total > max) {
+            return Err(EcSyscallError::QuotaExceeded { max });
+        }
+        *ec_ops += count;
+    }
+    Ok(())
+}
+
+/// Adds two points decoded from memory for [`try_create_ec_add_event`] and
+/// [`create_ec_add_to_event`], returning the encoded sum and whether it is the identity.
+fn add_decoded_points<E: EllipticCurve, Ex: ExecutorConfig>(
+    custom: Option<&dyn CustomCurve>,
+    p_affine: Option<AffinePoint<E>>,
+    q_affine: Option<AffinePoint<E>>,
+) -> Result<(AffinePoint<E>, bool), EcSyscallError> {
+    if Ex::VALIDATE_EC_INPUTS {
+        check_on_curve(p_affine.as_ref())?;
+        check_on_curve(q_affine.as_ref())?;
+    }
+    let inputs_on_curve = cfg!(debug_assertions)
+        && [&p_affine, &q_affine].iter().all(|point| point.as_ref().is_none_or(E::ec_is_on_curve));
+
+    // The point at infinity is the identity, `P + (-P)` is the point at infinity, and `P + P` is
+    // computed with the doubling formula.
+    let result_affine = match (p_affine, q_affine) {
+        (None, q_affine) => q_affine,
+        (p_affine, None) => p_affine,
+        (Some(p_affine), Some(q_affine)) => ec_add_or_identity_with(custom, &p_affine, &q_affine),
+    };
+
+    let result_is_identity = result_affine.as_ref().is_none_or(is_identity);
+    let result_affine = encode_point(result_affine);
+    debug_assert_result_on_curve(inputs_on_curve, &result_affine);
+    Ok((result_affine, result_is_identity))
+}
+
+/// Create an elliptic curve add-to event.
+///
+/// This is [`create_ec_add_event`] with both points read-only: it reads the points at `p_ptr` and
+/// `q_ptr` and writes their sum to `out_ptr`, leaving the inputs in place. The clk is incremented
+/// before reading `q` and before writing the sum, as the accesses may share a word or a page.
+///
+/// # Panics
+///
+/// Panics where [`try_create_ec_add_to_event`] returns an error.
+pub fn create_ec_add_to_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    q_ptr: u64,
+    out_ptr: u64,
+) -> EllipticCurveAddToEvent {
+    try_create_ec_add_to_event::<E, Ex>(rt, p_ptr, q_ptr, out_ptr)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Create an elliptic curve add-to event, returning an error instead of panicking if a pointer is
+/// misaligned.
+///
+/// As with [`try_create_ec_add_event`], an error is also returned, before any memory is accessed,
+/// if page protection forbids reading the points or writing the sum, if the curve is a custom curve
+/// with no registered implementation, or if [`ExecutorConfig::VALIDATE_EC_INPUTS`] is set and a
+/// point is not on the curve.
+pub fn try_create_ec_add_to_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    q_ptr: u64,
+    out_ptr: u64,
+) -> Result<EllipticCurveAddToEvent, EcSyscallError> {
+    let start_clk = rt.clk;
+    check_aligned("p_ptr", p_ptr)?;
+    check_aligned("q_ptr", q_ptr)?;
+    check_aligned("out_ptr", out_ptr)?;
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_num_words(num_words)?;
+    check_page_prot(rt, p_ptr, num_words, PROT_READ)?;
+    check_page_prot(rt, q_ptr, num_words, PROT_READ)?;
+    check_page_prot(rt, out_ptr, num_words, PROT_WRITE)?;
+    let custom = resolve_custom_curve::<E, Ex>(rt)?;
+
+    // The points are checked on the words peeked, so that an error leaves no access recorded.
+    let (result_affine, result_is_identity) = add_decoded_points::<E, Ex>(
+        custom.as_deref(),
+        decode_point::<E>(&rt.slice_unsafe(p_ptr, num_words)),
+        decode_point::<E>(&rt.slice_unsafe(q_ptr, num_words)),
+    )?;
+    charge_ec_op(rt)?;
+
+    let (p_memory_records, p, mut read_page_prot_records) = rt.mr_slice(p_ptr, num_words);
+
+    // The points may alias or share a page, so q is read at a later clk than p.
+    rt.advance_for_aliasing()?;
+    let (q_memory_records, q, q_page_prot_records) = rt.mr_slice(q_ptr, num_words);
+    read_page_prot_records.extend(q_page_prot_records);
+
+    // When we write the sum, we want the clk to be incremented because it could overlap a point.
+    rt.advance_for_aliasing()?;
+
+    let result = result_affine.to_memory_words();
+
+    let (out_memory_records, write_page_prot_records) =
+        rt.write_affine_point(out_ptr, &result_affine, false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(EllipticCurveAddToEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        q_ptr,
+        q,
+        out_ptr,
+        result,
+        p_memory_records,
+        q_memory_records,
+        out_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        result_is_identity,
+        cycles: rt.clk - start_clk,
+        checksum: None,
+    }))
+}
+
+/// Create an elliptic curve double event.
+///
+/// It takes a pointer to a memory location, reads the point from memory, doubles it, and writes the
+/// result back to the memory location. For twisted Edwards curves, doubling is the complete addition
+/// law applied to the point and itself.
+///
+/// The identity is detected and written in the encoding given by
+/// [`EllipticCurve::IDENTITY_ENCODING`]. It is written back as is, without applying the doubling
+/// formula. No other point doubles to itself, but on short Weierstrass curves a point with `y = 0`
+/// doubles to the point at infinity.
+///
+/// # Panics
+///
+/// Panics if the pointer is misaligned or, with [`ExecutorConfig::VALIDATE_EC_INPUTS`], the point
+/// is not on the curve. See [`try_create_ec_double_event`] for a non-panicking variant.
+pub fn create_ec_double_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveDoubleEvent {
+    try_create_ec_double_event::<E, Ex>(rt, arg1, arg2).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Create an elliptic curve double event, returning an error instead of panicking if the pointer is
+/// misaligned.
+///
+/// An error is also returned, before any memory is accessed, if page protection forbids updating
+/// the point. If [`ExecutorConfig::VALIDATE_EC_INPUTS`] is set, an error is also returned if the
+/// point is not on the curve.
+pub fn try_create_ec_double_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    _: u64,
+) -> Result<EllipticCurveDoubleEvent, EcSyscallError> {
+    let p_ptr = arg1;
+    check_double_args::<E, Ex>(rt, p_ptr)?;
+    let custom = resolve_custom_curve::<E, Ex>(rt)?;
+    charge_ec_op(rt)?;
+
+    ec_double_event::<E, Ex>(rt, custom.as_deref(), p_ptr)
+}
+
+/// Create an elliptic curve double event for the `*_DOUBLE` syscalls of short Weierstrass curves.
+///
+/// The double chips constrain the tangent formula alone, which inverts `2y`, so unlike
+/// [`try_create_ec_double_event`] the point must be finite with `y != 0`. An
+/// [`EcSyscallError::ExceptionalOperands`] error is returned, before any memory is accessed,
+/// otherwise.
+pub fn try_create_ec_double_assign_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    _: u64,
+) -> Result<EllipticCurveDoubleEvent, EcSyscallError> {
+    let p_ptr = arg1;
+    let num_words = check_double_args::<E, Ex>(rt, p_ptr)?;
+    check_tangent_operand::<E>(&rt.slice_unsafe(p_ptr, num_words))?;
+    let custom = resolve_custom_curve::<E, Ex>(rt)?;
+    charge_ec_op(rt)?;
+
+    ec_double_event::<E, Ex>(rt, custom.as_deref(), p_ptr)
+}
+
+/// Runs the checks of [`try_create_ec_double_event`] on its pointer, returning the number of words
+/// of a point.
+fn check_double_args<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+) -> Result<usize, EcSyscallError> {
+    check_aligned("p_ptr", p_ptr)?;
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_num_words(num_words)?;
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)?;
+    Ok(num_words)
+}
+
+/// The memory accesses and arithmetic of [`try_create_ec_double_event`], once its checks have
+/// passed.
+fn ec_double_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    custom: Option<&dyn CustomCurve>,
+    p_ptr: u64,
+) -> Result<EllipticCurveDoubleEvent, EcSyscallError> {
+    let start_clk = rt.clk;
+    let num_words = AffinePoint::<E>::num_memory_words();
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    let p_point = AffinePoint::<E>::from_memory_words(&p);
+    let p_affine = finite_point(p_point.clone());
+    if Ex::VALIDATE_EC_INPUTS {
+        check_on_curve(p_affine.as_ref())?;
+    }
+    let input_on_curve = cfg!(debug_assertions) && p_affine.as_ref().is_none_or(E::ec_is_on_curve);
+    // Only twisted Edwards curves have an affine neutral element.
+    let jacobian_z_words = (Ex::RECORD_JACOBIAN_Z && E::ec_neutral().is_none())
+        .then(|| jacobian_double_z_words::<E>(p_affine.as_ref()));
+
+    let result_affine = if is_identity(&p_point) {
+        p_point.clone()
+    } else {
+        encode_point(p_affine.and_then(|p_affine| ec_double_or_identity_with(custom, &p_affine)))
+    };
+    let result_is_identity = is_identity(&result_affine);
+    debug_assert_result_on_curve(input_on_curve, &result_affine);
+
+    // Doubling fixes the identity, in which case the words that were read are written back as is.
+    let unchanged_write = Ex::ELIDE_UNCHANGED_EC_WRITES && result_affine == p_point;
+    let (p_memory_records, write_page_prot_records) = if unchanged_write {
+        rt.mw_slice(p_ptr, &p, true)
+    } else {
+        rt.write_affine_point(p_ptr, &result_affine, true)
+    };
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(EllipticCurveDoubleEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        p_memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        result_is_identity,
+        unchanged_write,
+        cycles: rt.clk - start_clk,
+        jacobian_z_words,
+        checksum: None,
+    }))
+}
+
+/// Create an elliptic curve repeated double event.
+///
+/// It reads the point at `arg1`, doubles it `arg2` times, and writes `[2^n]P` back, recording a
+/// single read and write of the point. The point is doubled as by [`create_ec_double_event`], and
+/// once it reaches the identity the remaining doublings are skipped.
+///
+/// The event counts as `n` operations, or one if `n` is 0, against
+/// [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`], which bounds `n`.
+///
+/// # Panics
+///
+/// Panics where [`try_create_ec_repeated_double_event`] returns an error.
+pub fn create_ec_repeated_double_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveRepeatedDoubleEvent {
+    try_create_ec_repeated_double_event::<E, Ex>(rt, arg1, arg2)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// The largest number of doublings a repeated double event performs, which bounds the work of a
+/// single syscall even when [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`] sets no quota.
+pub const MAX_EC_REPEATED_DOUBLINGS: u64 = 1 << 12;
+
+/// Create an elliptic curve repeated double event, returning an error where
+/// [`try_create_ec_double_event`] would, if `n` is larger than [`MAX_EC_REPEATED_DOUBLINGS`], or if
+/// the shard quota does not allow `n` doublings.
+///
+/// If an error is returned, nothing has been written back to memory.
+pub fn try_create_ec_repeated_double_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> Result<EllipticCurveRepeatedDoubleEvent, EcSyscallError> {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    let n = arg2;
+    check_aligned("p_ptr", p_ptr)?;
+
+    if n > MAX_EC_REPEATED_DOUBLINGS {
+        return Err(EcSyscallError::TooManyOperations { count: n, max: MAX_EC_REPEATED_DOUBLINGS });
+    }
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)?;
+    let custom = resolve_custom_curve::<E, Ex>(rt)?;
+    charge_ec_ops(rt, n.max(1))?;
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    let p_point = AffinePoint::<E>::from_memory_words(&p);
+    let mut result = finite_point(p_point.clone());
+    if Ex::VALIDATE_EC_INPUTS {
+        check_on_curve(result.as_ref())?;
+    }
+    let result_affine = if is_identity(&p_point) {
+        p_point
+    } else {
+        for _ in 0..n {
+            let Some(point) = result else { break };
+            result = ec_double_or_identity_with(custom.as_deref(), &point);
+        }
+        encode_point(result)
+    };
+    let result_is_identity = is_identity(&result_affine);
+
+    let (p_memory_records, write_page_prot_records) =
+        rt.write_affine_point(p_ptr, &result_affine, true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(EllipticCurveRepeatedDoubleEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        n,
+        p_memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        result_is_identity,
+        cycles: rt.clk - start_clk,
+        checksum: None,
+    }))
+}
+
+/// Create an elliptic curve add projective event.
+///
+/// It adds the affine point at `arg2` to the accumulator at `arg1`, which is kept in Jacobian
+/// coordinates so that a loop of additions performs no field inversion. The accumulator is a guest
+/// buffer of three base field elements `X, Y, Z`, laid out as the coordinates of a point in
+/// memory, representing the affine point `(X / Z^2, Y / Z^3)`. See [`JacobianPoint`].
+///
+/// Any accumulator with `Z = 0`, in particular an all-zero buffer, is the point at infinity, so a
+/// zeroed buffer starts a sum. The affine point is encoded as in [`create_ec_add_event`], the
+/// all-zero words being the point at infinity. The sum is converted to affine coordinates with
+/// [`create_ec_normalize_projective_event`].
+pub fn create_ec_add_projective_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveAddProjectiveEvent {
+    let start_clk = rt.clk;
+    let acc_ptr = arg1;
+    assert!(acc_ptr.is_multiple_of(8), "acc_ptr must be 8-byte aligned");
+    let q_ptr = arg2;
+    assert!(q_ptr.is_multiple_of(8), "q_ptr must be 8-byte aligned");
+
+    let acc_num_words = JacobianPoint::<E>::num_memory_words();
+    check_page_prot(rt, acc_ptr, acc_num_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, q_ptr, AffinePoint::<E>::num_memory_words(), PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let acc = rt.slice_unsafe(acc_ptr, acc_num_words);
+
+    let (q_point, q_memory_records, read_page_prot_records) = rt.read_affine_point::<E>(q_ptr);
+    let q = q_point.to_memory_words();
+
+    // When we write the accumulator, we want the clk to be incremented because it could overlap
+    // the point.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let acc_point = JacobianPoint::<E>::from_memory_words(&acc);
+    let result = match finite_point(q_point) {
+        Some(q_affine) => acc_point.add_affine(&q_affine),
+        None => acc_point,
+    };
+
+    let (acc_memory_records, write_page_prot_records) =
+        rt.mw_slice(acc_ptr, &result.to_memory_words(), true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveAddProjectiveEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        acc_ptr,
+        acc,
+        q_ptr,
+        q,
+        acc_memory_records,
+        q_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve normalize projective event.
+///
+/// It reads the Jacobian accumulator at `arg1`, laid out as described for
+/// [`create_ec_add_projective_event`], and writes the affine point it represents to `arg2`. This
+/// performs the single inversion of a sum, and also undoes [`create_ec_to_jacobian_event`]. Any
+/// point with `Z = 0` is written as the all-zero point at infinity.
+pub fn create_ec_normalize_projective_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveNormalizeProjectiveEvent {
+    let start_clk = rt.clk;
+    let acc_ptr = arg1;
+    assert!(acc_ptr.is_multiple_of(8), "acc_ptr must be 8-byte aligned");
+    let result_ptr = arg2;
+    assert!(result_ptr.is_multiple_of(8), "result_ptr must be 8-byte aligned");
+
+    let acc_num_words = JacobianPoint::<E>::num_memory_words();
+    check_page_prot(rt, acc_ptr, acc_num_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, result_ptr, AffinePoint::<E>::num_memory_words(), PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (acc_memory_records, acc, read_page_prot_records) = rt.mr_slice(acc_ptr, acc_num_words);
+
+    // When we write the result, we want the clk to be incremented because it could overlap the
+    // accumulator.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let result_affine = encode_point(JacobianPoint::<E>::from_memory_words(&acc).to_affine());
+    let result = result_affine.to_memory_words();
+    let (result_memory_records, write_page_prot_records) =
+        rt.write_affine_point(result_ptr, &result_affine, false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveNormalizeProjectiveEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        acc_ptr,
+        acc,
+        result_ptr,
+        result,
+        acc_memory_records,
+        result_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve to Jacobian event.
+///
+/// It reads the affine point at `arg1` and writes it in Jacobian coordinates to `arg2`, laid out as
+/// the accumulator of [`create_ec_add_projective_event`]. A finite point is written with `Z = 1`,
+/// and the all-zero point at infinity as `(1, 1, 0)`. No field element is inverted; the inverse
+/// conversion is [`create_ec_normalize_projective_event`].
+pub fn create_ec_to_jacobian_event<E: EllipticCurve + WeierstrassParameters, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveToJacobianEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    let result_ptr = arg2;
+    assert!(result_ptr.is_multiple_of(8), "result_ptr must be 8-byte aligned");
+
+    check_page_prot(rt, p_ptr, AffinePoint::<E>::num_memory_words(), PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, result_ptr, JacobianPoint::<E>::num_memory_words(), PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (p_point, p_memory_records, read_page_prot_records) = rt.read_affine_point::<E>(p_ptr);
+    let p = p_point.to_memory_words();
+
+    // When we write the result, we want the clk to be incremented because it could overlap the
+    // point.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let result = finite_point(p_point)
+        .map_or_else(JacobianPoint::<E>::identity, |p_affine| JacobianPoint::from_affine(&p_affine))
+        .to_memory_words();
+    let (result_memory_records, write_page_prot_records) = rt.mw_slice(result_ptr, &result, false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveToJacobianEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        result_ptr,
+        result,
+        p_memory_records,
+        result_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve negate event.
+///
+/// It takes a pointer to a memory location, reads the point from memory, negates it, and writes the
+/// result back to the memory location. Short Weierstrass curves negate `y` and twisted Edwards
+/// curves negate `x`, both modulo the base field. The identity is its own negation, so the
+/// all-zero point at infinity is written back unchanged.
+pub fn create_ec_negate_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    _: u64,
+) -> EllipticCurveNegateEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    let result_affine = decode_point::<E>(&p).map(|p_affine| ec_negate(&p_affine));
+
+    let (p_memory_records, write_page_prot_records) =
+        rt.write_affine_point(p_ptr, &encode_point(result_affine), true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveNegateEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        p_memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve point and negate event.
+///
+/// It reads the point at `p_ptr`, leaving it in place, and writes its negation to `neg_ptr`, as
+/// [`create_ec_negate_event`] would negate a copy of the point. This saves a syscall when both `P`
+/// and `-P` are needed, as in the tables of a double scalar multiplication. The identity is its own
+/// negation, so the all-zero point at infinity is written unchanged. The clk is incremented before
+/// the write, as `neg_ptr` may share a word or a page with the point.
+///
+/// # Panics
+///
+/// Panics where [`try_create_ec_point_and_negate_event`] returns an error.
+pub fn create_ec_point_and_negate_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    neg_ptr: u64,
+) -> EllipticCurvePointAndNegateEvent {
+    try_create_ec_point_and_negate_event::<E, Ex>(rt, p_ptr, neg_ptr)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Create an elliptic curve point and negate event, returning an error instead of panicking if a
+/// pointer is misaligned.
+///
+/// An error is also returned, before any memory is accessed, if page protection forbids reading
+/// the point or writing its negation.
+pub fn try_create_ec_point_and_negate_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    neg_ptr: u64,
+) -> Result<EllipticCurvePointAndNegateEvent, EcSyscallError> {
+    let start_clk = rt.clk;
+    check_aligned("p_ptr", p_ptr)?;
+    check_aligned("neg_ptr", neg_ptr)?;
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_num_words(num_words)?;
+    check_page_prot(rt, p_ptr, num_words, PROT_READ)?;
+    check_page_prot(rt, neg_ptr, num_words, PROT_WRITE)?;
+    charge_ec_op(rt)?;
+
+    let (p_memory_records, p, read_page_prot_records) = rt.mr_slice(p_ptr, num_words);
+
+    let neg_affine = encode_point(decode_point::<E>(&p).map(|p_affine| ec_negate(&p_affine)));
+    let neg = neg_affine.to_memory_words();
+
+    // Increment clk because read and write could be on same page prot page.
+    rt.advance_for_aliasing()?;
+    let (neg_memory_records, write_page_prot_records) =
+        rt.write_affine_point(neg_ptr, &neg_affine, false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(EllipticCurvePointAndNegateEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        neg_ptr,
+        neg,
+        p_memory_records,
+        neg_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    }))
+}
+
+/// Create an elliptic curve ladder step event.
+///
+/// It reads the pair of points `r0, r1` at `arg1`, laid out as two consecutive points of
+/// [`AffinePoint::num_memory_words`] words each, and writes the pair after one step of the
+/// Montgomery ladder back to the same location. For a ladder bit `arg2` of 0 the pair becomes
+/// `(2 r0, r0 + r1)`, and for 1 it becomes `(r0 + r1, 2 r1)`.
+///
+/// The addition and the doubling share their intermediate values and `Z` coordinate through the
+/// co-Z formulas of [`co_z_ladder_step`], converting both results back to affine with a single
+/// inversion. Where those formulas do not apply, because `r0 = ±r1`, a point is the all-zero point
+/// at infinity or a result is, the pair is computed with a separate addition and doubling.
+///
+/// # Panics
+///
+/// Panics if the pointer is misaligned, the bit is neither 0 nor 1, page protection forbids an
+/// access or the shard has no operation left.
+pub fn create_ec_ladder_step_event<E: EllipticCurve + WeierstrassParameters, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveLadderStepEvent {
+    let start_clk = rt.clk;
+    let ptr = arg1;
+    assert!(ptr.is_multiple_of(8), "ptr must be 8-byte aligned");
+    assert!(arg2 <= 1, "the ladder bit must be 0 or 1, got {arg2}");
+    let bit = arg2 == 1;
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_page_prot(rt, ptr, 2 * num_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let points = rt.slice_unsafe(ptr, 2 * num_words);
+    let r0 = decode_point::<E>(&points[..num_words]);
+    let r1 = decode_point::<E>(&points[num_words..]);
+
+    let co_z = match (&r0, &r1) {
+        (Some(r0), Some(r1)) => co_z_ladder_step(r0, r1, bit),
+        _ => None,
+    };
+    let (r0, r1) = co_z.map_or_else(
+        || {
+            let sum = match (&r0, &r1) {
+                (None, q) => q.clone(),
+                (p, None) => p.clone(),
+                (Some(p), Some(q)) => ec_add_or_identity(p, q),
+            };
+            if bit {
+                (sum, r1.as_ref().and_then(ec_double_or_identity))
+            } else {
+                (r0.as_ref().and_then(ec_double_or_identity), sum)
+            }
+        },
+        |(r0, r1)| (Some(r0), Some(r1)),
+    );
+
+    let mut result = encode_point(r0).to_memory_words();
+    result.extend(encode_point(r1).to_memory_words());
+
+    let (memory_records, write_page_prot_records) = rt.mw_slice(ptr, &result, true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveLadderStepEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        ptr,
+        bit,
+        points,
+        result,
+        memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create a G2 psi event.
+///
+/// It reads a point of the BLS12-381 G2 group at `arg1` and writes `psi(Q)` back to the same
+/// location, with the layout of [`create_g2_add_event`]. `psi` is the untwist-Frobenius-twist
+/// endomorphism computed by [`bls12381_g2_psi`], used to speed up subgroup checks and the
+/// optimal ate pairing. The all-zero point at infinity is its own image and is written back
+/// unchanged.
+///
+/// # Panics
+///
+/// Panics if the pointer is misaligned, page protection forbids an access or the shard has no
+/// operation left.
+pub fn create_g2_psi_event<Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    _: u64,
+) -> G2PsiEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+
+    let num_words = G2AffinePoint::<Bls12381G2Parameters>::num_words();
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    let result = decode_g2_point::<Bls12381G2Parameters>(&p).map(|q| bls12381_g2_psi(&q));
+
+    let (p_memory_records, write_page_prot_records) =
+        rt.mw_slice(p_ptr, &encode_g2_point(result.as_ref()), true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(G2PsiEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        p_memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve clear cofactor event.
+///
+/// It takes a pointer to a memory location, reads the point from memory, multiplies it by
+/// [`WeierstrassParameters::effective_cofactor`], and writes the result back to the memory location.
+/// The result is in the prime-order subgroup.
+///
+/// This is the fast version for BLS12-381 G1: the point is multiplied by the 64-bit effective
+/// cofactor `1 - z` of RFC 9380 rather than by the 126-bit cofactor `h`, as hash-to-curve
+/// specifies. Both land in the same subgroup but, for a point outside of it, generally at different
+/// representatives, so the result does not match a naive multiplication by `h`. Curves with a
+/// cofactor of 1 leave the point unchanged. The all-zero point at infinity is written back
+/// unchanged, as is any point that the multiplication sends to infinity.
+pub fn create_ec_clear_cofactor_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    _: u64,
+) -> EllipticCurveClearCofactorEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    let result_affine = decode_point::<E>(&p)
+        .and_then(|p_affine| ec_scalar_mul(&p_affine, &E::effective_cofactor()));
+
+    let (p_memory_records, write_page_prot_records) =
+        rt.write_affine_point(p_ptr, &encode_point(result_affine), true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveClearCofactorEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        p_memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create a hash to curve event.
+///
+/// It reads an element `u` of `E::BaseField` from `arg2`, maps it to a curve point, and writes the
+/// point to `arg1`. This is the `map_to_curve` step of [RFC 9380]: `u` is the output of
+/// `hash_to_field`, which the guest computes, and the point still has to be multiplied by the
+/// effective cofactor, as with [`create_ec_clear_cofactor_event`]. Inputs that are not reduced are
+/// reduced modulo the field modulus first.
+///
+/// Only BLS12-381 G1 is supported, with the simplified SWU map of the
+/// `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite. Both pointers must be 8-byte aligned.
+///
+/// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+pub fn create_hash_to_curve_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveHashToCurveEvent {
+    let start_clk = rt.clk;
+    let map_to_curve = map_to_curve_for_curve::<E>().expect("Unsupported curve");
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    let u_ptr = arg2;
+    assert!(u_ptr.is_multiple_of(8), "u_ptr must be 8-byte aligned");
+
+    let num_words_field_element = limbs_to_words(<E::BaseField as NumLimbs>::Limbs::USIZE);
+    check_page_prot(rt, u_ptr, num_words_field_element, PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, p_ptr, AffinePoint::<E>::num_memory_words(), PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (u_memory_records, u, read_page_prot_records) = rt.mr_slice(u_ptr, num_words_field_element);
+
+    // When we write the point, we want the clk to be incremented because it could overlap `u`.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let u_int = BigUint::from_bytes_le(&words_to_bytes_le_vec(&u));
+    let p = encode_point(map_to_curve(&u_int)).to_memory_words();
+    let (p_memory_records, write_page_prot_records) = rt.mw_slice(p_ptr, &p, false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveHashToCurveEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        u_ptr,
+        u,
+        p_memory_records,
+        u_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an add event for a curve over `Fp2`, such as the BLS12-381 G2 group.
+///
+/// This mirrors [`create_ec_add_event`], with each coordinate taking two base field elements in
+/// memory, `c0` then `c1`. See [`G2AffinePoint`] for the layout. The point at infinity is encoded
+/// as all-zero words.
+pub fn create_g2_add_event<G: G2Parameters, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveAddEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    let q_ptr = arg2;
+    assert!(q_ptr.is_multiple_of(8), "q_ptr must be 8-byte aligned");
+
+    let num_words = G2AffinePoint::<G>::num_words();
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, q_ptr, num_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    let (q_memory_records, q, read_page_prot_records) = rt.mr_slice(q_ptr, num_words);
+
+    // When we write to p, we want the clk to be incremented because p and q could be the same.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let result_affine = match (decode_g2_point::<G>(&p), decode_g2_point::<G>(&q)) {
+        (None, q_affine) => q_affine,
+        (p_affine, None) => p_affine,
+        (Some(p_affine), Some(q_affine)) => p_affine.g2_add(&q_affine),
+    };
+    let result_is_identity = result_affine.is_none();
+    let result_words = encode_g2_point(result_affine.as_ref());
+
+    let (p_memory_records, write_page_prot_records) = rt.mw_slice(p_ptr, &result_words, true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveAddEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        q_ptr,
+        q,
+        p_memory_records,
+        q_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        result_is_identity,
+        cycles: rt.clk - start_clk,
+        checksum: None,
+    })
+}
+
+/// Create a double event for a curve over `Fp2`, such as the BLS12-381 G2 group.
+///
+/// This mirrors [`create_ec_double_event`], with the memory layout of [`create_g2_add_event`].
+pub fn create_g2_double_event<G: G2Parameters, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    _: u64,
+) -> EllipticCurveDoubleEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+
+    let num_words = G2AffinePoint::<G>::num_words();
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    let result_affine = decode_g2_point::<G>(&p).and_then(|p_affine| p_affine.g2_double());
+    let result_is_identity = result_affine.is_none();
+    let result_words = encode_g2_point(result_affine.as_ref());
+    let unchanged_write = Ex::ELIDE_UNCHANGED_EC_WRITES && result_words == p;
+
+    let (p_memory_records, write_page_prot_records) = rt.mw_slice(p_ptr, &result_words, true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveDoubleEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        p_memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        result_is_identity,
+        unchanged_write,
+        cycles: rt.clk - start_clk,
+        jacobian_z_words: None,
+        checksum: None,
+    })
+}
+
+/// Create a BLS12-381 Miller loop doubling step event.
+///
+/// It reads the accumulator `T`, a point of G2 at `arg1`, and the G1 point `P` at `arg2`. It writes
+/// `2T` back to `arg1` and the tangent line at `T` evaluated at `P` to `arg3`. `T` has the layout
+/// of [`G2AffinePoint`] and `P` that of any other point. The line is an element of
+///
+/// `Fp12 = Fp6[w] / (w^2 - v)`, `Fp6 = Fp2[v] / (v^3 - (u + 1))`, `Fp2 = Fp[u] / (u^2 + 1)`,
+///
+/// written as its 12 base field coefficients in the order `c0.c0.c0, c0.c0.c1, c0.c1.c0, ...,
+/// c1.c2.c1`, where `c0 + c1 * w` is the element, `c0 + c1 * v + c2 * v^2` each `Fp6` element and
+/// `c0 + c1 * u` each `Fp2` element. Each coefficient takes 6 little-endian words, 72 words in
+/// total. The line `l0 + l2 * w^2 + l3 * w^3` of [`G2AffinePoint::g2_double_with_line`] sets only
+/// `c0.c0`, `c0.c1` and `c1.c1`.
+///
+/// If `T` is the all-zero point at infinity, or `2T` is, the point at infinity is written back and
+/// the line is one.
+///
+/// # Panics
+///
+/// Panics if any pointer is misaligned, the line overlaps `T`, page protection forbids an access
+/// or the shard has no operation left.
+pub fn create_miller_step_event<Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+    arg3: u64,
+) -> MillerStepEvent {
+    let start_clk = rt.clk;
+    let t_ptr = arg1;
+    assert!(t_ptr.is_multiple_of(8), "t_ptr must be 8-byte aligned");
+    let p_ptr = arg2;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    let line_ptr = arg3;
+    assert!(line_ptr.is_multiple_of(8), "line_ptr must be 8-byte aligned");
+    let t_words = G2AffinePoint::<Bls12381G2Parameters>::num_words();
+    // A line sharing a word with `T` would overwrite part of `2T`.
+    let lower_words = if line_ptr > t_ptr { t_words } else { 72 };
+    assert!(t_ptr.abs_diff(line_ptr) >= 8 * lower_words as u64, "the line must not overlap T");
+    check_page_prot(rt, t_ptr, t_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, p_ptr, AffinePoint::<Bls12381>::num_memory_words(), PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, line_ptr, 72, PROT_WRITE).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (p_point, p_memory_records, read_page_prot_records) =
+        rt.read_affine_point::<Bls12381>(p_ptr);
+    let p = p_point.to_memory_words();
+    let t = rt.slice_unsafe(t_ptr, t_words);
+
+    // When we write the results, we want the clk to be incremented because they could overlap P.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let step = decode_g2_point::<Bls12381G2Parameters>(&t)
+        .and_then(|t_affine| t_affine.g2_double_with_line(&p_point.x, &p_point.y));
+    let (result, line) = match step {
+        Some((result, [l0, l2, l3])) => {
+            let zero = Fp2::zero();
+            // `w^2 = v` and `w^3 = v * w`.
+            let coeffs = [l0, l2, zero.clone(), zero.clone(), l3, zero];
+            (Some(result), coeffs.iter().flat_map(Fp2::to_words_le).collect::<Vec<_>>())
+        }
+        None => {
+            let mut one = Fp2::<Bls12381BaseField>::from_u32(1, 0).to_words_le();
+            one.resize(6 * one.len(), 0);
+            (None, one)
+        }
+    };
+
+    let (t_memory_records, mut write_page_prot_records) =
+        rt.mw_slice(t_ptr, &encode_g2_point(result.as_ref()), true);
+    // The line can be on a page of `T`, so it is written a cycle later.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let (line_memory_records, line_page_prot_records) = rt.mw_slice(line_ptr, &line, false);
+    write_page_prot_records.extend(line_page_prot_records);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(MillerStepEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        t_ptr,
+        t,
+        p_ptr,
+        p,
+        line_ptr,
+        line,
+        t_memory_records,
+        p_memory_records,
+        line_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve compress event.
+///
+/// It takes a pointer to a point and a pointer to an output buffer, reads the point, and writes its
+/// compressed serialization to the buffer. Only BLS12-381 G1 is supported, using the 48-byte
+/// format of [`bls12381_compress`]: the big-endian x coordinate with the compression, infinity and
+/// sign flags in the most significant byte. The all-zero point at infinity is written as `0xc0`
+/// followed by zeros.
+///
+/// # Panics
+///
+/// Panics if either pointer is misaligned, the curve is not BLS12-381, page protection forbids an
+/// access or the shard has no operation left.
+pub fn create_ec_compress_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveCompressEvent {
+    assert_eq!(E::CURVE_TYPE, CurveType::Bls12381, "Unsupported curve");
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    let compressed_ptr = arg2;
+    assert!(compressed_ptr.is_multiple_of(8), "compressed_ptr must be 8-byte aligned");
+
+    let num_words_field_element = limbs_to_words(<E::BaseField as NumLimbs>::Limbs::USIZE);
+    check_page_prot(rt, p_ptr, AffinePoint::<E>::num_memory_words(), PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, compressed_ptr, num_words_field_element, PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (p_point, p_memory_records, read_page_prot_records) = rt.read_affine_point::<E>(p_ptr);
+    let p = p_point.to_memory_words();
+
+    // When we write the result, we want the clk to be incremented because it could overlap p.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let compressed = bls12381_compress(finite_point(p_point).as_ref()).to_vec();
+
+    let (compressed_memory_records, write_page_prot_records) =
+        rt.mw_slice(compressed_ptr, &bytes_to_words_le_vec(&compressed), false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveCompressEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        compressed_ptr,
+        compressed,
+        p_memory_records,
+        compressed_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve scalar multiplication event.
+///
+/// It takes a pointer to a point and a pointer to a scalar, reads both from memory, computes
+/// `[k]P`, and writes the result back to the memory location of the point. The scalar is read as a
+/// little-endian slice of as many words as a field element.
+///
+/// On curves without an affine identity, a result at infinity (for example when `k == 0` or `k` is
+/// the group order) is written as all-zero words. Twisted Edwards curves write their affine neutral
+/// element instead.
+///
+/// On secp256k1, points on the curve are multiplied with the GLV endomorphism, which writes the
+/// same result as double-and-add with half the doublings.
+pub fn create_ec_scalar_mul_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveScalarMulEvent {
+    scalar_mul_event::<E, Ex>(rt, arg1, arg2, false)
+}
+
+/// Create an elliptic curve scalar multiplication event for a scalar stored in Montgomery form.
+///
+/// This is [`create_ec_scalar_mul_event`] for guests that keep scalars as `k * R mod n`, where `n`
+/// is the order of the prime-order group and `R = 2^(64 * l)`, `l` being the number of words needed
+/// to hold `n`. This is 4 on every supported curve, as in most scalar field libraries. The scalar
+/// is converted back to `k` by multiplying it by the inverse of `R` modulo `n`.
+///
+/// The result is `[k mod n]P`, which is the point written for a canonical `k` when `P` is in the
+/// prime-order group, as any point of a prime-order curve is.
+pub fn create_ec_montgomery_scalar_mul_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveScalarMulEvent {
+    scalar_mul_event::<E, Ex>(rt, arg1, arg2, true)
+}
+
+/// Returns the order of the prime-order group of `E`, given by the implementation registered with
+/// the executor for a [`CurveType::Custom`] curve.
+fn prime_group_order<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &SyscallContext<'_, '_, Ex>,
+) -> Result<BigUint, EcSyscallError> {
+    Ok(resolve_custom_curve::<E, Ex>(rt)?
+        .map_or_else(|| E::CURVE_TYPE.prime_group_order(), |curve| curve.prime_group_order()))
+}
+
+fn scalar_mul_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+    scalar_montgomery: bool,
+) -> EllipticCurveScalarMulEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    let scalar_ptr = arg2;
+    assert!(scalar_ptr.is_multiple_of(8), "scalar_ptr must be 8-byte aligned");
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    let num_scalar_words = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+    check_page_prot(rt, p_ptr, num_words, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, scalar_ptr, num_scalar_words, PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    let n = scalar_montgomery
+        .then(|| prime_group_order::<E, Ex>(rt).unwrap_or_else(|err| panic!("{err}")));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let p = rt.slice_unsafe(p_ptr, num_words);
+
+    let (scalar_memory_records, scalar, read_page_prot_records) =
+        rt.mr_slice(scalar_ptr, num_scalar_words);
+
+    // When we write to p, we want the clk to be incremented because p and the scalar could share a
+    // page.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let mut k = BigUint::from_bytes_le(&words_to_bytes_le_vec(&scalar));
+    if let Some(n) = &n {
+        k = from_montgomery(&k, n);
+    }
+    let result_affine = decode_point::<E>(&p).and_then(|p_affine| {
+        // The endomorphism only agrees with double-and-add on the prime order group, so points off
+        // the curve take the naive path.
+        if E::CURVE_TYPE == CurveType::Secp256k1 && E::ec_is_on_curve(&p_affine) {
+            secp256k1_glv_scalar_mul(&p_affine, &k)
+        } else {
+            ec_scalar_mul(&p_affine, &k)
+        }
+    });
+    let (p_memory_records, write_page_prot_records) =
+        rt.write_affine_point(p_ptr, &encode_point(result_affine), true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveScalarMulEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        scalar_ptr,
+        scalar,
+        scalar_montgomery,
+        p_memory_records,
+        scalar_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// The largest number of points a multi-scalar multiplication event takes. The count is read from
+/// guest memory, so it bounds the buffers sized from it.
+pub const MAX_EC_MSM_POINTS: u64 = 1 << 12;
+
+/// Create an elliptic curve multi-scalar multiplication event.
+///
+/// It computes `[k_0]P_0 + ... + [k_{n-1}]P_{n-1}` and writes the result to `arg1`. The input buffer
+/// at `arg2` is laid out as follows, with every element little-endian:
+///
+/// - one word holding `n`,
+/// - the `n` points, each as many words as a curve point,
+/// - the `n` scalars, each as many words as a field element.
+///
+/// Both pointers must be 8-byte aligned. The identity is written as in [`create_ec_scalar_mul_event`].
+/// The event counts as `n` operations against [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`]. `n` is read
+/// at `clk`, point `i` at `clk + 1 + i` and scalar `i` at `clk + 1 + n + i`, and the result is
+/// written at `clk + 1 + 2n`.
+///
+/// # Panics
+///
+/// Panics if a pointer is misaligned, `n` is larger than [`MAX_EC_MSM_POINTS`], page protection
+/// forbids an access, or the shard quota does not allow `n` operations. These are all checked
+/// before any memory access is recorded.
+pub fn create_ec_msm_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveMsmEvent {
+    let start_clk = rt.clk;
+    let result_ptr = arg1;
+    assert!(result_ptr.is_multiple_of(8), "result_ptr must be 8-byte aligned");
+    let input_ptr = arg2;
+    assert!(input_ptr.is_multiple_of(8), "input_ptr must be 8-byte aligned");
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    let num_scalar_words = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+
+    // `n` comes from the guest, so it is peeked and bounded before the input is sized from it.
+    check_page_prot(rt, input_ptr, 1, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    let n = rt.slice_unsafe(input_ptr, 1)[0];
+    assert!(n <= MAX_EC_MSM_POINTS, "n must be at most {MAX_EC_MSM_POINTS}, got {n}");
+    // Once `n` is bounded the size cannot overflow, but the buffer can still run past the end of
+    // the address space.
+    let input_words = 1 + n as usize * (num_words + num_scalar_words);
+    assert!(input_ptr.checked_add(input_words as u64 * 8).is_some(), "the input buffer overflows");
+    check_page_prot(rt, input_ptr, input_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, result_ptr, num_words, PROT_WRITE).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_ops(rt, n.max(1)).unwrap_or_else(|err| panic!("{err}"));
+
+    let (n_memory_records, _, mut read_page_prot_records) = rt.mr_slice(input_ptr, 1);
+    let points_ptr = input_ptr + 8;
+    let scalars_ptr = points_ptr + n * num_words as u64 * 8;
+
+    // Each point and scalar is read separately, as a single slice read may span at most two pages.
+    // Consecutive reads can share a page, so each one is a cycle after the previous one.
+    let mut points = Vec::with_capacity(n as usize * num_words);
+    let mut points_memory_records = Vec::with_capacity(n as usize * num_words);
+    for i in 0..n {
+        rt.clk_checked_add(1).unwrap_or_else(|err| panic!("{err}"));
+        let (point, records, page_prot_records) =
+            rt.read_affine_point::<E>(points_ptr + i * num_words as u64 * 8);
+        points.extend(point.to_memory_words());
+        points_memory_records.extend(records);
+        read_page_prot_records.extend(page_prot_records);
+    }
+
+    let mut scalars = Vec::with_capacity(n as usize * num_scalar_words);
+    let mut scalars_memory_records = Vec::with_capacity(n as usize * num_scalar_words);
+    for i in 0..n {
+        rt.clk_checked_add(1).unwrap_or_else(|err| panic!("{err}"));
+        let (records, values, page_prot_records) =
+            rt.mr_slice(scalars_ptr + i * num_scalar_words as u64 * 8, num_scalar_words);
+        scalars.extend(values);
+        scalars_memory_records.extend(records);
+        read_page_prot_records.extend(page_prot_records);
+    }
+
+    // When we write the result, we want the clk to be incremented because it could overlap the
+    // input buffer.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let result = points.chunks_exact(num_words).zip(scalars.chunks_exact(num_scalar_words)).fold(
+        E::ec_neutral(),
+        |acc, (point, scalar)| {
+            let k = BigUint::from_bytes_le(&words_to_bytes_le_vec(scalar));
+            let term = decode_point::<E>(point).and_then(|p_affine| ec_scalar_mul(&p_affine, &k));
+            match (acc, term) {
+                (Some(acc), Some(term)) => ec_add_or_identity(&acc, &term),
+                (acc, None) => acc,
+                (None, term) => term,
+            }
+        },
+    );
+    let (result_memory_records, write_page_prot_records) =
+        rt.write_affine_point(result_ptr, &encode_point(result), false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveMsmEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        result_ptr,
+        input_ptr,
+        n,
+        points,
+        scalars,
+        n_memory_record: n_memory_records[0],
+        points_memory_records,
+        scalars_memory_records,
+        result_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Returns an [`EcSyscallError::PageProtViolation`] error if the `num_words` words at `ptr` are on
+/// a page whose protection forbids an access with `page_prot_bitmap`.
+pub(super) fn check_page_prot(
+    rt: &impl SyscallMemory,
+    ptr: u64,
+    num_words: usize,
+    page_prot_bitmap: u8,
+) -> Result<(), EcSyscallError> {
+    if rt.page_prot_allows(ptr, num_words, page_prot_bitmap) {
+        Ok(())
+    } else {
+        Err(EcSyscallError::PageProtViolation { ptr })
+    }
+}
+
+/// The largest number of points a batched add event takes, which bounds the buffers sized from the
+/// count even when [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`] sets no quota.
+pub const MAX_EC_ADD_BATCH_POINTS: u64 = 1 << 12;
+
+/// Create a batched elliptic curve add event.
+///
+/// It reads `count` contiguous points starting at `base_ptr`, adds them up, and writes the sum to
+/// `result_ptr`, all within a single event. Point `i` is read at `clk + i` and the result is written
+/// at `clk + count`, so each logical add advances the clock just like a sequence of add events. An
+/// empty batch writes the identity, encoded as in [`create_ec_add_event`]. The event counts as
+/// `count` operations, or one if `count` is 0, against [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`].
+///
+/// # Panics
+///
+/// Panics where [`try_create_ec_add_batch_event`] returns an error.
+pub fn create_ec_add_batch_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    base_ptr: u64,
+    result_ptr: u64,
+    count: u64,
+) -> EllipticCurveAddBatchEvent {
+    try_create_ec_add_batch_event::<E, Ex>(rt, base_ptr, result_ptr, count)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Create a batched elliptic curve add event, returning an error if a pointer is misaligned,
+/// `count` is larger than [`MAX_EC_ADD_BATCH_POINTS`], the points run past the end of the address
+/// space, page protection forbids an access, or the shard quota does not allow `count` operations.
+///
+/// These are all checked before any memory access is recorded.
+pub fn try_create_ec_add_batch_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    base_ptr: u64,
+    result_ptr: u64,
+    count: u64,
+) -> Result<EllipticCurveAddBatchEvent, EcSyscallError> {
+    let start_clk = rt.clk;
+    check_aligned("base_ptr", base_ptr)?;
+    check_aligned("result_ptr", result_ptr)?;
+    if count > MAX_EC_ADD_BATCH_POINTS {
+        return Err(EcSyscallError::TooManyOperations { count, max: MAX_EC_ADD_BATCH_POINTS });
+    }
+
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_num_words(num_words)?;
+    // Once `count` is bounded the size cannot overflow, but the points can still run past the end
+    // of the address space.
+    let points_words = count as usize * num_words;
+    if base_ptr.checked_add(points_words as u64 * 8).is_none() {
+        return Err(EcSyscallError::AddressOverflow { name: "base_ptr", ptr: base_ptr });
+    }
+    check_page_prot(rt, base_ptr, points_words, PROT_READ)?;
+    check_page_prot(rt, result_ptr, num_words, PROT_WRITE)?;
+    charge_ec_ops(rt, count.max(1))?;
+
+    let mut points = Vec::with_capacity(points_words);
+    let mut points_memory_records = Vec::with_capacity(points_words);
+    let mut read_page_prot_records = Vec::new();
+    let mut sum = E::ec_neutral();
+    for i in 0..count {
+        let (point, records, page_prot_records) =
+            rt.read_affine_point::<E>(base_ptr + i * num_words as u64 * 8);
+        points.extend(point.to_memory_words());
+        sum = match (sum, finite_point(point)) {
+            (Some(sum), Some(point)) => ec_add_or_identity(&sum, &point),
+            (sum, None) => sum,
+            (None, point) => point,
+        };
+        points_memory_records.extend(records);
+        read_page_prot_records.extend(page_prot_records);
+
+        // Each logical add advances the clock, which also orders the result write after the reads.
+        rt.clk_checked_add(1)?;
+    }
+
+    let result = encode_point(sum);
+
+    let (result_memory_records, write_page_prot_records) =
+        rt.write_affine_point(result_ptr, &result, false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(EllipticCurveAddBatchEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        base_ptr,
+        result_ptr,
+        count,
+        points,
+        result: result.to_memory_words(),
+        points_memory_records,
+        result_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    }))
+}
+
+/// Create an elliptic curve subgroup check event.
+///
+/// It takes a pointer to a point and a pointer to a result word, reads the point from memory, and
+/// writes 1 to the result word if the point is in the prime-order subgroup, or 0 otherwise. The
+/// point at infinity is in the subgroup.
+pub fn create_ec_subgroup_check_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveSubgroupCheckEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    let result_ptr = arg2;
+    assert!(result_ptr.is_multiple_of(8), "result_ptr must be 8-byte aligned");
+
+    check_page_prot(rt, p_ptr, AffinePoint::<E>::num_memory_words(), PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, result_ptr, 1, PROT_WRITE).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (p_point, p_memory_records, read_page_prot_records) = rt.read_affine_point::<E>(p_ptr);
+    let p = p_point.to_memory_words();
+
+    // When we write the result, we want the clk to be incremented because it could overlap p.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let in_subgroup = in_prime_subgroup::<E>(p_point);
+
+    let (result_memory_records, write_page_prot_records) =
+        rt.mw_slice(result_ptr, &[u64::from(in_subgroup)], false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveSubgroupCheckEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        result_ptr,
+        in_subgroup,
+        p_memory_records,
+        result_memory_record: result_memory_records[0],
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve load validate event.
+///
+/// It reads the point at `p_ptr`, validates it, and writes an [`EcValidationStatus`] to the word at
+/// `result_ptr` instead of panicking on an invalid point:
+///
+/// - 0 if the point is valid,
+/// - 1 if the point does not lie on the curve,
+/// - 2 if `check_subgroup` is set and the point lies on the curve but not in the prime-order
+///   subgroup.
+///
+/// The all-zero point at infinity is valid.
+pub fn create_ec_load_validate_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    result_ptr: u64,
+    check_subgroup: bool,
+) -> EllipticCurveLoadValidateEvent {
+    let start_clk = rt.clk;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    assert!(result_ptr.is_multiple_of(8), "result_ptr must be 8-byte aligned");
+    check_page_prot(rt, p_ptr, AffinePoint::<E>::num_memory_words(), PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, result_ptr, 1, PROT_WRITE).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (p_point, p_memory_records, read_page_prot_records) = rt.read_affine_point::<E>(p_ptr);
+    let p = p_point.to_memory_words();
+
+    // When we write the result, we want the clk to be incremented because it could overlap p.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let status = if check_on_curve(finite_point(p_point.clone()).as_ref()).is_err() {
+        EcValidationStatus::NotOnCurve
+    } else if check_subgroup && !in_prime_subgroup::<E>(p_point) {
+        EcValidationStatus::NotInSubgroup
+    } else {
+        EcValidationStatus::Valid
+    };
+
+    let (result_memory_records, write_page_prot_records) =
+        rt.mw_slice(result_ptr, &[status as u64], false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveLoadValidateEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p,
+        result_ptr,
+        check_subgroup,
+        status,
+        p_memory_records,
+        result_memory_record: result_memory_records[0],
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve equals event.
+///
+/// It reads the points at `p_ptr` and `q_ptr` and writes 1 to the word at `result_ptr` if they are
+/// equal, or 0 otherwise. Coordinates are compared modulo the base field, so every encoding of the
+/// same point, including the identity, compares equal. Both points are always read in full and
+/// every coordinate is compared, so the cost does not depend on the outcome.
+pub fn create_ec_equals_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    q_ptr: u64,
+    result_ptr: u64,
+) -> EllipticCurveEqualsEvent {
+    let start_clk = rt.clk;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    assert!(q_ptr.is_multiple_of(8), "q_ptr must be 8-byte aligned");
+    assert!(result_ptr.is_multiple_of(8), "result_ptr must be 8-byte aligned");
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_page_prot(rt, p_ptr, num_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, q_ptr, num_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, result_ptr, 1, PROT_WRITE).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (p_point, p_memory_records, mut read_page_prot_records) = rt.read_affine_point::<E>(p_ptr);
+    // The points may alias, so q is read at a later clk than p.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let (q_point, q_memory_records, q_page_prot_records) = rt.read_affine_point::<E>(q_ptr);
+    read_page_prot_records.extend(q_page_prot_records);
+
+    // When we write the result, we want the clk to be incremented because it could overlap a point.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let modulus = E::BaseField::modulus();
+    let equal = [(&p_point.x, &q_point.x), (&p_point.y, &q_point.y)]
+        .iter()
+        .fold(true, |equal, (a, b)| equal & (*a % &modulus == *b % &modulus));
+
+    let (result_memory_records, write_page_prot_records) =
+        rt.mw_slice(result_ptr, &[u64::from(equal)], false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveEqualsEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p: p_point.to_memory_words(),
+        q_ptr,
+        q: q_point.to_memory_words(),
+        result_ptr,
+        equal,
+        p_memory_records,
+        q_memory_records,
+        result_memory_record: result_memory_records[0],
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve is negation event.
+///
+/// It reads the points at `p_ptr` and `q_ptr` and writes 1 to the word at `result_ptr` if `q` is
+/// the negation of `p`, or 0 otherwise. No negation is computed: on short Weierstrass curves the
+/// points are negatives when `x_p = x_q` and `y_p + y_q = 0`, and on twisted Edwards curves when
+/// `x_p + x_q = 0` and `y_p = y_q`, modulo the base field. The identity is its own negation, which
+/// both conditions already account for with either identity encoding. As for
+/// [`create_ec_equals_event`], the cost does not depend on the outcome.
+pub fn create_ec_is_negation_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    p_ptr: u64,
+    q_ptr: u64,
+    result_ptr: u64,
+) -> EllipticCurveIsNegationEvent {
+    let start_clk = rt.clk;
+    assert!(p_ptr.is_multiple_of(8), "p_ptr must be 8-byte aligned");
+    assert!(q_ptr.is_multiple_of(8), "q_ptr must be 8-byte aligned");
+    assert!(result_ptr.is_multiple_of(8), "result_ptr must be 8-byte aligned");
+    let num_words = AffinePoint::<E>::num_memory_words();
+    check_page_prot(rt, p_ptr, num_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, q_ptr, num_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, result_ptr, 1, PROT_WRITE).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (p_point, p_memory_records, mut read_page_prot_records) = rt.read_affine_point::<E>(p_ptr);
+    // The points may alias, so q is read at a later clk than p.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let (q_point, q_memory_records, q_page_prot_records) = rt.read_affine_point::<E>(q_ptr);
+    read_page_prot_records.extend(q_page_prot_records);
+
+    // When we write the result, we want the clk to be incremented because it could overlap a point.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let modulus = E::BaseField::modulus();
+    let equal = |a: &BigUint, b: &BigUint| a % &modulus == b % &modulus;
+    let opposite = |a: &BigUint, b: &BigUint| (a + b) % &modulus == BigUint::ZERO;
+    // Only twisted Edwards curves have an affine neutral element.
+    let negation = if E::ec_neutral().is_some() {
+        opposite(&p_point.x, &q_point.x) & equal(&p_point.y, &q_point.y)
+    } else {
+        equal(&p_point.x, &q_point.x) & opposite(&p_point.y, &q_point.y)
+    };
+
+    let (result_memory_records, write_page_prot_records) =
+        rt.mw_slice(result_ptr, &[u64::from(negation)], false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveIsNegationEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        p_ptr,
+        p: p_point.to_memory_words(),
+        q_ptr,
+        q: q_point.to_memory_words(),
+        result_ptr,
+        negation,
+        p_memory_records,
+        q_memory_records,
+        result_memory_record: result_memory_records[0],
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create a field inverse event.
+///
+/// It takes a pointer to an element of `E::BaseField`, reads it from memory, and writes its inverse
+/// back to the same memory location. Zero has no inverse and is written back as zero, and inputs
+/// that are not reduced are reduced modulo the field modulus first.
+pub fn create_field_inverse_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    _: u64,
+) -> FieldInverseEvent {
+    let start_clk = rt.clk;
+    let ptr = arg1;
+    assert!(ptr.is_multiple_of(8), "ptr must be 8-byte aligned");
+
+    let num_words_field_element = limbs_to_words(<E::BaseField as NumLimbs>::Limbs::USIZE);
+    check_page_prot(rt, ptr, num_words_field_element, PROT_READ | PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let x = rt.slice_unsafe(ptr, num_words_field_element);
+
+    // Every field is prime, so `x^(p - 2)` is the inverse of `x`, and zero maps to zero.
+    let modulus = E::BaseField::modulus();
+    let x_int = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+    let inverse_int = x_int.modpow(&(&modulus - 2u32), &modulus);
+
+    let mut inverse_bytes = inverse_int.to_bytes_le();
+    inverse_bytes.resize(num_words_field_element * 8, 0u8);
+    let inverse = bytes_to_words_le_vec(&inverse_bytes);
+
+    let (x_memory_records, write_page_prot_records) = rt.mw_slice(ptr, &inverse, true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(FieldInverseEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        ptr,
+        x,
+        inverse,
+        x_memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// The largest number of words of a scalar reduce event, one page, so that the scalar is written
+/// with a single slice access.
+pub const MAX_SCALAR_REDUCE_WORDS: u64 = PAGE_SIZE as u64 / 8;
+
+/// Create a scalar reduce event.
+///
+/// It reads the little-endian scalar of `arg2` words at `arg1`, such as the 8 words of a 512-bit
+/// hash output, and writes it back reduced modulo the order `n` of the prime-order group of `E`.
+/// The result is written over the whole input, so the words above the width of `n` are zeroed and
+/// the scalar can be passed to [`create_ec_scalar_mul_event`] from the same location.
+///
+/// # Panics
+///
+/// Panics where [`try_create_scalar_reduce_event`] returns an error.
+pub fn create_scalar_reduce_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> ScalarReduceEvent {
+    try_create_scalar_reduce_event::<E, Ex>(rt, arg1, arg2).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Create a scalar reduce event, returning an error if the pointer is misaligned, the scalar has
+/// no words or more than [`MAX_SCALAR_REDUCE_WORDS`], it runs past the end of the address space,
+/// page protection forbids updating it, the curve is a custom curve with no registered
+/// implementation, or the shard quota is used up.
+///
+/// These are all checked before any memory access is recorded.
+pub fn try_create_scalar_reduce_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> Result<ScalarReduceEvent, EcSyscallError> {
+    let start_clk = rt.clk;
+    let ptr = arg1;
+    check_aligned("ptr", ptr)?;
+    if arg2 == 0 || arg2 > MAX_SCALAR_REDUCE_WORDS {
+        return Err(EcSyscallError::InvalidNumWords {
+            num_words: arg2,
+            max: MAX_SCALAR_REDUCE_WORDS,
+        });
+    }
+    let num_words = arg2 as usize;
+    if ptr.checked_add(arg2 * 8).is_none() {
+        return Err(EcSyscallError::AddressOverflow { name: "ptr", ptr });
+    }
+    check_page_prot(rt, ptr, num_words, PROT_READ | PROT_WRITE)?;
+    let n = prime_group_order::<E, Ex>(rt)?;
+    charge_ec_op(rt)?;
+
+    let scalar = rt.slice_unsafe(ptr, num_words);
+
+    let reduced_int = BigUint::from_bytes_le(&words_to_bytes_le_vec(&scalar)) % n;
+
+    let mut reduced_bytes = reduced_int.to_bytes_le();
+    reduced_bytes.resize(num_words * 8, 0u8);
+    let reduced = bytes_to_words_le_vec(&reduced_bytes);
+
+    let (scalar_memory_records, write_page_prot_records) = rt.mw_slice(ptr, &reduced, true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(ScalarReduceEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        ptr,
+        scalar,
+        reduced,
+        scalar_memory_records,
+        local_mem_access,
+        write_slice_page_prot_access: write_page_prot_records,
+        local_page_prot_access,
+        checksum: None,
+    }))
+}
+
+/// The number of words of each 32-byte integer in the input of [`create_ecrecover_event`].
+const ECRECOVER_INT_WORDS: usize = 4;
+
+/// Create an ecrecover event.
+///
+/// It recovers the secp256k1 public key that produced an ECDSA signature and writes it to `arg1`.
+/// The input buffer at `arg2` holds 13 words:
+///
+/// - the 32-byte message hash,
+/// - the 32-byte `r`,
+/// - the 32-byte `s`,
+/// - one word holding the recovery id, from 0 to 3.
+///
+/// As in Ethereum calldata, each 32-byte integer is big-endian in memory, so its most significant
+/// byte comes first. Bit 0 of the recovery id is the parity of the `y` coordinate of `R`, and bit 1
+/// is set if its `x` coordinate is `r + n`. The public key is written like any other point, as
+/// little-endian `x` and `y`. If the signature is invalid, the all-zero point is written instead.
+///
+/// Both pointers must be 8-byte aligned.
+pub fn create_ecrecover_event<Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EcrecoverEvent {
+    let start_clk = rt.clk;
+    let result_ptr = arg1;
+    assert!(result_ptr.is_multiple_of(8), "result_ptr must be 8-byte aligned");
+    let input_ptr = arg2;
+    assert!(input_ptr.is_multiple_of(8), "input_ptr must be 8-byte aligned");
+
+    let input_words = 3 * ECRECOVER_INT_WORDS + 1;
+    check_page_prot(rt, input_ptr, input_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, result_ptr, 2 * ECRECOVER_INT_WORDS, PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+
+    let (input_memory_records, input, read_page_prot_records) = rt.mr_slice(input_ptr, input_words);
+    let mut ints = input.chunks_exact(ECRECOVER_INT_WORDS);
+    let [hash, r, s] = std::array::from_fn(|_| ints.next().unwrap().to_vec());
+    let recovery_id = input[3 * ECRECOVER_INT_WORDS];
+
+    // When we write the result, we want the clk to be incremented because it could overlap the
+    // input buffer.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let to_int = |words: &[u64]| BigUint::from_bytes_be(&words_to_bytes_le_vec(words));
+    let public_key = ecrecover(&to_int(&hash), &to_int(&r), &to_int(&s), recovery_id);
+    let valid = public_key.is_some();
+    let public_key = encode_point(public_key).to_memory_words();
+    let (result_memory_records, write_page_prot_records) =
+        rt.mw_slice(result_ptr, &public_key, false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EcrecoverEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        result_ptr,
+        input_ptr,
+        hash,
+        r,
+        s,
+        recovery_id,
+        public_key,
+        valid,
+        input_memory_records,
+        result_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Returns the implementation registered with the executor for `E` if it is a
+/// [`CurveType::Custom`] curve, or `None` for the curves of this crate.
+///
+/// The add, double and decompress events resolve it once, before any memory access. The other
+/// events operate on a custom curve with the [`EllipticCurve`] methods of its type.
+fn resolve_custom_curve<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &SyscallContext<'_, '_, Ex>,
+) -> Result<Option<Arc<dyn CustomCurve>>, EcSyscallError> {
+    match E::CURVE_TYPE {
+        CurveType::Custom(id) => rt
+            .rt
+            .custom_curves
+            .get(E::CURVE_TYPE)
+            .map(|curve| Some(curve.clone()))
+            .ok_or(EcSyscallError::UnregisteredCurve(id)),
+        _ => Ok(None),
+    }
+}
+
+/// Create an elliptic curve decompress event.
+///
+/// It takes a pointer to a memory location, reads the point from memory, decompresses it, and
+/// writes the result back to the memory location.
+///
+/// The compressed coordinate is read from the upper half of the slice and the recovered coordinate
+/// is written to the lower half, each half being an element of the base field in whole words. The two curve families compress differently:
+///
+/// - Short Weierstrass curves store `x`, and the sign bit selects the parity of `y`.
+/// - Twisted Edwards curves store `y`, and the sign bit selects the parity of `x`.
+///
+/// # Panics
+///
+/// Panics if the pointer is misaligned, the sign bit is invalid, or the x coordinate is not on the
+/// curve. See
+/// [`try_create_ec_decompress_event`] for a non-panicking variant.
+pub fn create_ec_decompress_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    slice_ptr: u64,
+    sign_bit: u64,
+) -> EllipticCurveDecompressEvent {
+    try_create_ec_decompress_event::<E, Ex>(rt, slice_ptr, sign_bit)
+        .unwrap_or_else(|err| panic!("{} decompression failed: {err}", E::CURVE_TYPE))
+}
+
+/// Create an elliptic curve decompress event, returning an error if the pointer is misaligned, the
+/// sign bit is neither 0 nor 1, page protection forbids the slice accesses, or the x coordinate
+/// read from memory is not on the curve.
+///
+/// If [`ExecutorConfig::VALIDATE_EC_INPUTS`] is set, an error is also returned if the compressed
+/// coordinate is not less than the base field modulus, or if the decompressed point is not on the
+/// curve. Otherwise, the decompress functions may reduce a non-canonical coordinate.
+///
+/// Ed25519 points are decoded as in RFC 8032, with the sign bit passed separately, and the
+/// encodings it rejects are always errors: a `y` that is not less than the modulus, and the sign
+/// bit set when `x` is zero.
+///
+/// If an error is returned, no memory access has been recorded.
+pub fn try_create_ec_decompress_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    slice_ptr: u64,
+    sign_bit: u64,
+) -> Result<EllipticCurveDecompressEvent, EcSyscallError> {
+    ec_decompress_event::<E, Ex>(rt, slice_ptr, sign_bit, None).map(|(event, _)| event)
+}
+
+/// The body of [`try_create_ec_decompress_event`], which also reads the word at `sign_ptr`, if
+/// given, once every check has passed and a cycle after the compressed coordinate.
+fn ec_decompress_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    slice_ptr: u64,
+    sign_bit: u64,
+    sign_ptr: Option<u64>,
+) -> Result<(EllipticCurveDecompressEvent, Option<MemoryReadRecord>), EcSyscallError> {
+    let start_clk = rt.clk;
+    check_aligned("slice_ptr", slice_ptr)?;
+    if sign_bit > 1 {
+        return Err(EcSyscallError::InvalidSignBit(sign_bit));
+    }
+
+    let num_words_field_element = limbs_to_words(<E::BaseField as NumLimbs>::Limbs::USIZE);
+    check_num_words(num_words_field_element)?;
+    let x_ptr = slice_ptr + num_words_field_element as u64 * 8;
+    check_page_prot(rt, x_ptr, num_words_field_element, PROT_READ)?;
+    check_page_prot(rt, slice_ptr, num_words_field_element, PROT_WRITE)?;
+    let custom = resolve_custom_curve::<E, Ex>(rt)?;
+    charge_ec_op(rt)?;
+
+    // Every check runs on the words peeked, so that an error leaves no access recorded.
+    let x_vec = rt.slice_unsafe(x_ptr, num_words_field_element);
+
+    // The event keeps the little-endian bytes as read from memory, while the decompress functions
+    // take big-endian bytes.
+    let x_bytes = words_to_bytes_le_vec(&x_vec);
+    let compressed = BigUint::from_bytes_le(&x_bytes);
+    if Ex::VALIDATE_EC_INPUTS && compressed >= E::BaseField::modulus() {
+        return Err(EcSyscallError::NonCanonical);
+    }
+    let x_bytes_be = words_to_bytes_be_vec(&x_vec);
+
+    let computed_point = match &custom {
+        Some(curve) => {
+            curve.decompress(&x_bytes_be, sign_bit as u32).map(|(x, y)| AffinePoint::<E>::new(x, y))
+        }
+        // RFC 8032 specifies the encodings of Ed25519 points to reject, so their errors are
+        // returned as is whatever the config.
+        None if E::CURVE_TYPE == CurveType::Ed25519 => {
+            match edwards_recover_x::<Ed25519Parameters>(&compressed, sign_bit == 1) {
+                Ok(x) => Some(AffinePoint::new(x, compressed.clone())),
+                Err(EcSyscallError::NonResidue) => None,
+                Err(err) => return Err(err),
+            }
+        }
+        None => {
+            decompress_for_curve::<E>().expect("Unsupported curve")(&x_bytes_be, sign_bit as u32)
+        }
+    };
+    let Some(computed_point) = computed_point else {
+        rt.rt.ec_stats.record_decompress_non_residue();
+        return Err(EcSyscallError::NonResidue);
+    };
+    if Ex::VALIDATE_EC_INPUTS {
+        check_on_curve(Some(&computed_point))?;
+    }
+
+    // Only twisted Edwards curves have an affine neutral element.
+    let (recovered, sign_corrected) = if E::ec_neutral().is_some() {
+        // The Edwards square roots are normalized to be even, so x was negated exactly if it is odd.
+        (&computed_point.x, computed_point.x.bit(0))
+    } else {
+        let y = &computed_point.y;
+        let root = canonical_root::<E>(custom.as_deref(), y).ok_or(EcSyscallError::NonResidue)?;
+        (y, root.bit(0) != (sign_bit == 1))
+    };
+
+    let mut decompressed_y_bytes = recovered.to_bytes_le();
+    decompressed_y_bytes.resize(num_words_field_element * 8, 0u8);
+    let y_words = bytes_to_words_le_vec(&decompressed_y_bytes);
+
+    let (x_memory_records, _, read_page_prot_records) = rt.mr_slice(x_ptr, num_words_field_element);
+    // The sign bit can be on the page of the compressed coordinate, so it is read a cycle later.
+    let sign_memory_record = match sign_ptr {
+        Some(sign_ptr) => {
+            rt.advance_for_aliasing()?;
+            Some(rt.mr(sign_ptr).0)
+        }
+        None => None,
+    };
+    // Increment clk because read and write could be on same page prot page
+    rt.advance_for_aliasing()?;
+    // Only the lower half holding the recovered coordinate is written, so the compressed
+    // coordinate at `x_ptr` is left as is and has no write records.
+    let (y_memory_records, write_page_prot_records) = rt.mw_slice(slice_ptr, &y_words, false);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    let event = with_checksum::<_, Ex>(EllipticCurveDecompressEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        ptr: slice_ptr,
+        sign_bit: sign_bit != 0,
+        x_bytes,
+        decompressed_y_bytes,
+        sign_corrected,
+        x_memory_records,
+        y_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        cycles: rt.clk - start_clk,
+        checksum: None,
+    });
+    Ok((event, sign_memory_record))
+}
+
+/// Create an elliptic curve decompress event with the sign bit read from memory.
+///
+/// This is [`create_ec_decompress_event`] for calling conventions that store the sign bit in the
+/// word at `sign_ptr` rather than passing it as an argument. The read of the sign bit is recorded
+/// in the event.
+///
+/// # Panics
+///
+/// Panics if either pointer is misaligned, the sign bit is invalid, or the x coordinate is not on
+/// the curve. See [`try_create_ec_decompress_mem_sign_event`] for a non-panicking variant.
+pub fn create_ec_decompress_mem_sign_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    slice_ptr: u64,
+    sign_ptr: u64,
+) -> EllipticCurveDecompressMemSignEvent {
+    try_create_ec_decompress_mem_sign_event::<E, Ex>(rt, slice_ptr, sign_ptr)
+        .unwrap_or_else(|err| panic!("{} decompression failed: {err}", E::CURVE_TYPE))
+}
+
+/// Create an elliptic curve decompress event with the sign bit read from memory, returning an error
+/// where [`try_create_ec_decompress_event`] would with the sign bit passed as an argument.
+///
+/// An error is also returned if `sign_ptr` is misaligned, page protection forbids reading it, or
+/// it lies within the slice. The sign bit is peeked, and read only once every check of the
+/// decompression has passed, so an error leaves no read record behind.
+pub fn try_create_ec_decompress_mem_sign_event<E: EllipticCurve, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    slice_ptr: u64,
+    sign_ptr: u64,
+) -> Result<EllipticCurveDecompressMemSignEvent, EcSyscallError> {
+    check_aligned("sign_ptr", sign_ptr)?;
+    // The sign bit must be a word of its own rather than a word of a coordinate of the slice.
+    let slice_len = 16 * limbs_to_words(<E::BaseField as NumLimbs>::Limbs::USIZE) as u64;
+    if sign_ptr.wrapping_sub(slice_ptr) < slice_len {
+        return Err(EcSyscallError::Overlap { name: "sign_ptr", ptr: sign_ptr });
+    }
+    check_page_prot(rt, sign_ptr, 1, PROT_READ)?;
+    let sign_bit = rt.slice_unsafe(sign_ptr, 1)[0];
+    if sign_bit > 1 {
+        return Err(EcSyscallError::InvalidSignBit(sign_bit));
+    }
+
+    let (decompress, sign_memory_record) =
+        ec_decompress_event::<E, Ex>(rt, slice_ptr, sign_bit, Some(sign_ptr))?;
+    let sign_memory_record = sign_memory_record.expect("the sign bit is read with the slice");
+    Ok(with_checksum::<_, Ex>(EllipticCurveDecompressMemSignEvent {
+        sign_ptr,
+        sign_memory_record,
+        decompress,
+        checksum: None,
+    }))
+}
+
+/// The largest number of points a decompress batch event takes. The count is read from guest
+/// memory, so it bounds the buffers sized from it.
+pub const MAX_EC_DECOMPRESS_BATCH_SIZE: u64 = 1 << 12;
+
+/// Create an elliptic curve decompress batch event.
+///
+/// It decompresses `n` points of a short Weierstrass curve at once. The input buffer at `arg1` is
+/// laid out as follows, with every element little-endian:
+///
+/// - one word holding `n`,
+/// - `ceil(n / 64)` words of sign bits, bit `i % 64` of word `i / 64` being the sign bit of point
+///   `i`,
+/// - the `n` x coordinates, each as many words as a field element.
+///
+/// The output buffer at `arg2` receives a status word followed by the `n` points. The sign bits
+/// are interpreted as by [`create_ec_decompress_event`]. If an x coordinate is not reduced or does
+/// not lie on the curve, its point is written as all-zero words and, instead of panicking, the
+/// status is set to its index plus one. The status is that of the first such coordinate, or 0 if
+/// every point was decompressed. Every point is read and written either way, so the trace does not
+/// depend on the outcome. The event counts as `n` operations against
+/// [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`].
+///
+/// Each slice is accessed a cycle after the previous one: `n` at `clk`, the sign bits at `clk + 1`,
+/// x coordinate `i` at `clk + 2 + i`, the status at `clk + 2 + n` and point `i` at
+/// `clk + 3 + n + i`.
+///
+/// # Panics
+///
+/// Panics if either pointer is misaligned, the curve is not decompressed through
+/// [`decompress_for_curve`], `n` is larger than [`MAX_EC_DECOMPRESS_BATCH_SIZE`], page protection
+/// forbids an access, or the shard quota does not allow `n` operations. These are all checked
+/// before any memory access is recorded.
+pub fn create_ec_decompress_batch_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveDecompressBatchEvent {
+    let start_clk = rt.clk;
+    let input_ptr = arg1;
+    assert!(input_ptr.is_multiple_of(8), "input_ptr must be 8-byte aligned");
+    let output_ptr = arg2;
+    assert!(output_ptr.is_multiple_of(8), "output_ptr must be 8-byte aligned");
+
+    let decompress_fn = decompress_for_curve::<E>().expect("Unsupported curve");
+    let num_words = AffinePoint::<E>::num_memory_words();
+    let num_x_words = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+
+    // `n` comes from the guest, so it is peeked and bounded before the buffers are sized from it.
+    check_page_prot(rt, input_ptr, 1, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    let n = rt.slice_unsafe(input_ptr, 1)[0];
+    assert!(
+        n <= MAX_EC_DECOMPRESS_BATCH_SIZE,
+        "n must be at most {MAX_EC_DECOMPRESS_BATCH_SIZE}, got {n}"
+    );
+    // Once `n` is bounded the sizes cannot overflow, but the buffers can still run past the end of
+    // the address space.
+    let input_words = 1 + n.div_ceil(64) as usize + n as usize * num_x_words;
+    let output_words = 1 + n as usize * num_words;
+    for (ptr, words) in [(input_ptr, input_words), (output_ptr, output_words)] {
+        assert!(ptr.checked_add(words as u64 * 8).is_some(), "the buffer at {ptr:#x} overflows");
+    }
+    check_page_prot(rt, input_ptr, input_words, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, output_ptr, output_words, PROT_WRITE).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_ops(rt, n.max(1)).unwrap_or_else(|err| panic!("{err}"));
+
+    // Consecutive slice accesses can share a page, so each one is a cycle after the previous one.
+    let (n_memory_records, _, mut read_page_prot_records) = rt.mr_slice(input_ptr, 1);
+    rt.clk_checked_add(1).unwrap_or_else(|err| panic!("{err}"));
+    let (sign_bits_memory_records, sign_bits, page_prot_records) =
+        rt.mr_slice(input_ptr + 8, n.div_ceil(64) as usize);
+    read_page_prot_records.extend(page_prot_records);
+
+    // Each coordinate is read separately, as a single slice read may span at most two pages.
+    let x_ptr = input_ptr + 8 + 8 * sign_bits.len() as u64;
+    let mut x_words = Vec::with_capacity(n as usize * num_x_words);
+    let mut x_memory_records = Vec::with_capacity(n as usize * num_x_words);
+    for i in 0..n {
+        rt.clk_checked_add(1).unwrap_or_else(|err| panic!("{err}"));
+        let (records, values, page_prot_records) =
+            rt.mr_slice(x_ptr + i * num_x_words as u64 * 8, num_x_words);
+        x_words.extend(values);
+        x_memory_records.extend(records);
+        read_page_prot_records.extend(page_prot_records);
+    }
+
+    // When we write the output, we want the clk to be incremented because it could overlap the
+    // input buffer.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+
+    let mut status = 0;
+    let mut points = Vec::with_capacity(n as usize * num_words);
+    for (i, x) in x_words.chunks_exact(num_x_words).enumerate() {
+        let sign_bit = (sign_bits[i / 64] >> (i % 64)) & 1;
+        let x_int = BigUint::from_bytes_le(&words_to_bytes_le_vec(x));
+        let lifts = lifts_to_point::<E>(&x_int);
+        if !lifts && x_int < E::BaseField::modulus() {
+            rt.rt.ec_stats.record_decompress_non_residue();
+        }
+        let point =
+            lifts.then(|| decompress_fn(&words_to_bytes_be_vec(x), sign_bit as u32)).flatten();
+        if point.is_none() && status == 0 {
+            status = i as u64 + 1;
+        }
+        points.extend(encode_point(point).to_memory_words());
+    }
+
+    let (status_memory_records, mut write_page_prot_records) =
+        rt.mw_slice(output_ptr, &[status], false);
+    let mut points_memory_records = Vec::with_capacity(points.len());
+    for (i, point) in points.chunks_exact(num_words).enumerate() {
+        rt.clk_checked_add(1).unwrap_or_else(|err| panic!("{err}"));
+        let (records, page_prot_records) =
+            rt.mw_slice(output_ptr + 8 + (i * num_words) as u64 * 8, point, false);
+        points_memory_records.extend(records);
+        write_page_prot_records.extend(page_prot_records);
+    }
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EllipticCurveDecompressBatchEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        input_ptr,
+        output_ptr,
+        n,
+        sign_bits,
+        x_words,
+        status,
+        points,
+        n_memory_record: n_memory_records[0],
+        sign_bits_memory_records,
+        x_memory_records,
+        status_memory_record: status_memory_records[0],
+        points_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create an elliptic curve x-only decompress event.
+///
+/// This is [`create_ec_decompress_event`] without a sign bit: the point with the even `y` is always
+/// chosen, as for BIP340 x-only public keys. On secp256k1 this is the BIP340 `lift_x` function.
+///
+/// # Panics
+///
+/// Panics if the pointer is misaligned or `x` is not the x coordinate of a point on the curve. See
+/// [`try_create_ec_decompress_xonly_event`] for a non-panicking variant.
+pub fn create_ec_decompress_xonly_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    slice_ptr: u64,
+    _: u64,
+) -> EllipticCurveDecompressEvent {
+    try_create_ec_decompress_xonly_event::<E, Ex>(rt, slice_ptr)
+        .unwrap_or_else(|err| panic!("{} decompression failed: {err}", E::CURVE_TYPE))
+}
+
+/// Create an elliptic curve x-only decompress event, returning an error where
+/// [`try_create_ec_decompress_event`] would with a sign bit of 0.
+///
+/// As `lift_x` requires, an `x` that is not less than the base field modulus is rejected with
+/// [`EcSyscallError::NonCanonical`] whether or not [`ExecutorConfig::VALIDATE_EC_INPUTS`] is set.
+/// Like a non-residue, it is rejected before any memory is accessed.
+pub fn try_create_ec_decompress_xonly_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    slice_ptr: u64,
+) -> Result<EllipticCurveDecompressEvent, EcSyscallError> {
+    check_aligned("slice_ptr", slice_ptr)?;
+    let num_words_field_element = limbs_to_words(<E::BaseField as NumLimbs>::Limbs::USIZE);
+    let x = BigUint::from_bytes_le(&words_to_bytes_le_vec(
+        &rt.slice_unsafe(slice_ptr + num_words_field_element as u64 * 8, num_words_field_element),
+    ));
+    if x >= E::BaseField::modulus() {
+        return Err(EcSyscallError::NonCanonical);
+    }
+    if !lifts_to_point::<E>(&x) {
+        rt.rt.ec_stats.record_decompress_non_residue();
+        return Err(EcSyscallError::NonResidue);
+    }
+
+    // A sign bit of 0 selects the even root.
+    try_create_ec_decompress_event::<E, Ex>(rt, slice_ptr, 0)
+}
+
+/// Create an elliptic curve SEC1 decompress event.
+///
+/// It decodes a point of a short Weierstrass curve from its SEC1 compressed encoding: the bytes at
+/// `arg1` hold a prefix byte, `0x02` for an even `y` and `0x03` for an odd one, followed by the
+/// big-endian `x`. The point is written to `arg2` in the SEC1 uncompressed encoding, the prefix byte
+/// `0x04` followed by the big-endian `x` and `y`. On secp256k1 these are the 33-byte and 65-byte
+/// public key encodings.
+///
+/// Neither pointer needs to be 8-byte aligned. The words covering each encoding are accessed, and
+/// the bytes of the covering words of the output outside of the encoding are written back
+/// unchanged.
+///
+/// # Panics
+///
+/// Panics if the prefix byte is invalid or `x` is not the x coordinate of a point on the curve. See
+/// [`try_create_ec_decompress_sec1_event`] for a non-panicking variant.
+pub fn create_ec_decompress_sec1_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EllipticCurveSec1DecompressEvent {
+    try_create_ec_decompress_sec1_event::<E, Ex>(rt, arg1, arg2)
+        .unwrap_or_else(|err| panic!("{} decompression failed: {err}", E::CURVE_TYPE))
+}
+
+/// Create an elliptic curve SEC1 decompress event, returning an
+/// [`EcSyscallError::InvalidFlags`] error if the prefix byte is neither `0x02` nor `0x03`.
+///
+/// As SEC1 requires, an `x` that is not less than the base field modulus is rejected with
+/// [`EcSyscallError::NonCanonical`] whether or not [`ExecutorConfig::VALIDATE_EC_INPUTS`] is set,
+/// and an `x` with no point is rejected with [`EcSyscallError::NonResidue`]. An error is also
+/// returned if page protection forbids the accesses. If an error is returned, no memory access
+/// has been recorded.
+pub fn try_create_ec_decompress_sec1_event<
+    E: EllipticCurve + WeierstrassParameters,
+    Ex: ExecutorConfig,
+>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> Result<EllipticCurveSec1DecompressEvent, EcSyscallError> {
+    let start_clk = rt.clk;
+    let compressed_ptr = arg1;
+    let output_ptr = arg2;
+    let num_limbs = <E::BaseField as NumLimbs>::Limbs::USIZE;
+    let compressed_len = 1 + num_limbs as u64;
+    let uncompressed_len = 1 + 2 * num_limbs as u64;
+
+    let (compressed_start, compressed_num_words) = covering_words(compressed_ptr, compressed_len);
+    let (output_start, output_num_words) = covering_words(output_ptr, uncompressed_len);
+    check_page_prot(rt, compressed_start, compressed_num_words, PROT_READ)?;
+    check_page_prot(rt, output_start, output_num_words, PROT_READ | PROT_WRITE)?;
+
+    // The encoding is checked before any record is made, so a rejected one leaves no trace.
+    let compressed_bytes = (compressed_ptr - compressed_start) as usize
+        ..(compressed_ptr - compressed_start + compressed_len) as usize;
+    let compressed =
+        words_to_bytes_le_vec(&rt.slice_unsafe(compressed_start, compressed_num_words))
+            [compressed_bytes.clone()]
+        .to_vec();
+    let prefix = compressed[0];
+    if prefix != 0x02 && prefix != 0x03 {
+        return Err(EcSyscallError::InvalidFlags(prefix));
+    }
+    let x_bytes_be = &compressed[1..];
+    let x = BigUint::from_bytes_be(x_bytes_be);
+    if x >= E::BaseField::modulus() {
+        return Err(EcSyscallError::NonCanonical);
+    }
+    if !lifts_to_point::<E>(&x) {
+        rt.rt.ec_stats.record_decompress_non_residue();
+        return Err(EcSyscallError::NonResidue);
+    }
+    charge_ec_op(rt)?;
+
+    // The decompress functions do not all select `y` by its parity, so it is normalized here.
+    let sign_bit = u32::from(prefix & 1);
+    let decompress_fn = decompress_for_curve::<E>().expect("Unsupported curve");
+    let point = decompress_fn(x_bytes_be, sign_bit).ok_or(EcSyscallError::NonResidue)?;
+    let modulus = E::BaseField::modulus();
+    let y = if point.y.bit(0) == (sign_bit == 1) { point.y } else { &modulus - point.y };
+    if Ex::VALIDATE_EC_INPUTS {
+        check_on_curve(Some(&AffinePoint::<E>::new(x, y.clone())))?;
+    }
+
+    let mut uncompressed = Vec::with_capacity(uncompressed_len as usize);
+    uncompressed.push(0x04);
+    uncompressed.extend_from_slice(x_bytes_be);
+    let y_bytes = y.to_bytes_be();
+    uncompressed.resize(uncompressed.len() + num_limbs - y_bytes.len(), 0);
+    uncompressed.extend(y_bytes);
+
+    let (compressed_memory_records, _, read_page_prot_records) =
+        rt.mr_slice(compressed_start, compressed_num_words);
+    // When we write the output, we want the clk to be incremented because it could overlap the
+    // compressed encoding.
+    rt.advance_for_aliasing()?;
+
+    let output_bytes = (output_ptr - output_start) as usize
+        ..(output_ptr - output_start + uncompressed_len) as usize;
+    let mut output_covering =
+        words_to_bytes_le_vec(&rt.slice_unsafe(output_start, output_num_words));
+    output_covering[output_bytes].copy_from_slice(&uncompressed);
+    let (uncompressed_memory_records, write_page_prot_records) =
+        rt.mw_slice(output_start, &bytes_to_words_le_vec(&output_covering), true);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    Ok(with_checksum::<_, Ex>(EllipticCurveSec1DecompressEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        compressed_ptr,
+        compressed,
+        output_ptr,
+        uncompressed,
+        compressed_memory_records,
+        uncompressed_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    }))
+}
+
+/// Create an Edwards decompress event.
+///
+/// It decodes a point of a twisted Edwards curve from the compressed encoding of RFC 8032, which
+/// Jubjub shares: the 32 little-endian bytes at `arg1` hold `y`, with the parity of `x` in the top
+/// bit. This is the opposite of the convention of [`create_ec_decompress_event`], where the
+/// compressed coordinate and the sign bit are passed separately.
+///
+/// The output buffer at `arg2` receives a status word followed by the point. If `y` is not reduced,
+/// `(y^2 - 1) / (dy^2 + 1)` has no square root, or the sign bit is set while `x` is 0, the encoding
+/// is rejected as RFC 8032 requires: the status is set to 1 and the point is written as all-zero
+/// words, which is not on the curve. Otherwise the status is 0.
+///
+/// # Panics
+///
+/// Panics if either pointer is misaligned, the curve is neither Ed25519 nor Jubjub, page
+/// protection forbids an access or the shard has no operation left.
+pub fn create_edwards_decompress_event<E: EllipticCurve + EdwardsParameters, Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> EdwardsDecompressEvent {
+    let start_clk = rt.clk;
+    let compressed_ptr = arg1;
+    assert!(compressed_ptr.is_multiple_of(8), "compressed_ptr must be 8-byte aligned");
+    let output_ptr = arg2;
+    assert!(output_ptr.is_multiple_of(8), "output_ptr must be 8-byte aligned");
+
+    let num_words_field_element = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+    let output_words = 1 + AffinePoint::<E>::num_memory_words();
+    check_page_prot(rt, compressed_ptr, num_words_field_element, PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, output_ptr, output_words, PROT_WRITE).unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+    let (compressed_memory_records, compressed, read_page_prot_records) =
+        rt.mr_slice(compressed_ptr, num_words_field_element);
+
+    let point = edwards_decompress::<E>(&words_to_bytes_le_vec(&compressed));
+    if point == Err(EcSyscallError::NonResidue) {
+        rt.rt.ec_stats.record_decompress_non_residue();
+    }
+    let status = u64::from(point.is_err());
+    let point = point.map_or_else(
+        |_| vec![0; AffinePoint::<E>::num_memory_words()],
+        |point| point.to_memory_words(),
+    );
+
+    // When we write the output, we want the clk to be incremented because it could overlap the
+    // input.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let (status_memory_records, mut write_page_prot_records) =
+        rt.mw_slice(output_ptr, &[status], false);
+    // The point follows the status on its page, so it is written a cycle later.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let (point_memory_records, page_prot_records) = rt.mw_slice(output_ptr + 8, &point, false);
+    write_page_prot_records.extend(page_prot_records);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(EdwardsDecompressEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        compressed_ptr,
+        compressed,
+        output_ptr,
+        status,
+        point,
+        compressed_memory_records,
+        status_memory_record: status_memory_records[0],
+        point_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create a BLS12-381 G2 decompress event.
+///
+/// It decodes a point of G2 from its 96-byte compressed serialization at `arg1`, in the order it is
+/// serialized: `x.c1` then `x.c0`, each a big-endian integer, with the flags in the top three bits
+/// of the first byte. The compression flag must be set. The infinity flag encodes the point at
+/// infinity, with all other bits zero. Otherwise the sign flag is set if `y` is lexicographically
+/// largest, comparing `y.c1` first.
+///
+/// The output buffer at `arg2` receives a status word followed by the point with the layout of
+/// [`G2AffinePoint`], all-zero words encoding the point at infinity. If the flags are
+/// inconsistent, a coefficient of `x` is not reduced, or `x` is not the coordinate of a point, the
+/// status is set to 1 and the point is written as all-zero words. Otherwise the status is 0.
+///
+/// # Panics
+///
+/// Panics if either pointer is misaligned, page protection forbids an access or the shard has no
+/// operation left.
+pub fn create_g2_decompress_event<Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    arg1: u64,
+    arg2: u64,
+) -> G2DecompressEvent {
+    let start_clk = rt.clk;
+    let compressed_ptr = arg1;
+    assert!(compressed_ptr.is_multiple_of(8), "compressed_ptr must be 8-byte aligned");
+    let output_ptr = arg2;
+    assert!(output_ptr.is_multiple_of(8), "output_ptr must be 8-byte aligned");
+
+    let num_words = G2AffinePoint::<Bls12381G2Parameters>::num_words();
+    check_page_prot(rt, compressed_ptr, num_words / 2, PROT_READ)
+        .unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, output_ptr, 1 + num_words, PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+    let (compressed_memory_records, compressed, read_page_prot_records) =
+        rt.mr_slice(compressed_ptr, num_words / 2);
+
+    let point = g2_decompress(&words_to_bytes_le_vec(&compressed));
+    if point == Err(EcSyscallError::NonResidue) {
+        rt.rt.ec_stats.record_decompress_non_residue();
+    }
+    let status = u64::from(point.is_err());
+    let point = encode_g2_point(point.ok().flatten().as_ref());
+
+    // When we write the output, we want the clk to be incremented because it could overlap the
+    // input.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let (status_memory_records, mut write_page_prot_records) =
+        rt.mw_slice(output_ptr, &[status], false);
+    // The point follows the status on its page, so it is written a cycle later.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let (point_memory_records, page_prot_records) = rt.mw_slice(output_ptr + 8, &point, false);
+    write_page_prot_records.extend(page_prot_records);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(G2DecompressEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        compressed_ptr,
+        compressed,
+        output_ptr,
+        status,
+        point,
+        compressed_memory_records,
+        status_memory_record: status_memory_records[0],
+        point_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
+
+/// Create a BLS deserialize event, decoding a BLS12-381 signature and public key in one call.
+///
+/// It decodes a G1 point from its 48-byte compressed serialization at `g1_ptr` and a G2 point from
+/// its 96-byte compressed serialization at `g2_ptr`, with the flags and byte order described in
+/// [`create_g2_decompress_event`], and checks that both lie in the prime-order subgroup.
+///
+/// The output buffer at `output_ptr` receives a status word followed by the G1 point in memory
+/// layout, 12 words, then the G2 point with the layout of [`G2AffinePoint`], 24 words. The status
+/// word holds an [`EcValidationStatus`] for the G1 point in its low 32 bits and one for the G2
+/// point in its high 32 bits, see [`BlsDeserializeEvent::status`]. An invalid serialization is
+/// reported as [`EcValidationStatus::NotOnCurve`]. A point that is not valid is written as
+/// all-zero words, as is the point at infinity, which is valid.
+///
+/// # Panics
+///
+/// Panics if any pointer is misaligned, page protection forbids an access or the shard has no
+/// operation left.
+pub fn create_bls_deserialize_event<Ex: ExecutorConfig>(
+    rt: &mut SyscallContext<'_, '_, Ex>,
+    g1_ptr: u64,
+    g2_ptr: u64,
+    output_ptr: u64,
+) -> BlsDeserializeEvent {
+    let start_clk = rt.clk;
+    assert!(g1_ptr.is_multiple_of(8), "g1_ptr must be 8-byte aligned");
+    assert!(g2_ptr.is_multiple_of(8), "g2_ptr must be 8-byte aligned");
+    assert!(output_ptr.is_multiple_of(8), "output_ptr must be 8-byte aligned");
+
+    let g1_num_words = <Bls12381BaseField as NumWords>::WordsCurvePoint::USIZE;
+    let g2_num_words = G2AffinePoint::<Bls12381G2Parameters>::num_words();
+    check_page_prot(rt, g1_ptr, g1_num_words / 2, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, g2_ptr, g2_num_words / 2, PROT_READ).unwrap_or_else(|err| panic!("{err}"));
+    check_page_prot(rt, output_ptr, 1 + g1_num_words + g2_num_words, PROT_WRITE)
+        .unwrap_or_else(|err| panic!("{err}"));
+    charge_ec_op(rt).unwrap_or_else(|err| panic!("{err}"));
+    let (g1_memory_records, g1_compressed, mut read_page_prot_records) =
+        rt.mr_slice(g1_ptr, g1_num_words / 2);
+    // The serializations may alias or share a page, so g2 is read at a later clk than g1.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let (g2_memory_records, g2_compressed, page_prot_records) =
+        rt.mr_slice(g2_ptr, g2_num_words / 2);
+    read_page_prot_records.extend(page_prot_records);
+
+    let g1 = g1_decompress(&words_to_bytes_le_vec(&g1_compressed));
+    let g2 = g2_decompress(&words_to_bytes_le_vec(&g2_compressed));
+    for err in [g1.as_ref().err(), g2.as_ref().err()].into_iter().flatten() {
+        if *err == EcSyscallError::NonResidue {
+            rt.rt.ec_stats.record_decompress_non_residue();
+        }
+    }
+    let (g1_status, g1) = match g1 {
+        Err(_) => (EcValidationStatus::NotOnCurve, None),
+        Ok(Some(point)) if !in_prime_subgroup(point.clone()) => {
+            (EcValidationStatus::NotInSubgroup, None)
+        }
+        Ok(point) => (EcValidationStatus::Valid, point),
+    };
+    let (g2_status, g2) = match g2 {
+        Err(_) => (EcValidationStatus::NotOnCurve, None),
+        Ok(Some(point)) if point.g2_mul(&Bls12381::prime_group_order()).is_some() => {
+            (EcValidationStatus::NotInSubgroup, None)
+        }
+        Ok(point) => (EcValidationStatus::Valid, point),
+    };
+    let g1_point = encode_point::<Bls12381>(g1).to_memory_words();
+    let g2_point = encode_g2_point(g2.as_ref());
+
+    // When we write the output, we want the clk to be incremented because it could overlap the
+    // inputs.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let status = bls_deserialize_status(g1_status, g2_status);
+    let (status_memory_records, mut write_page_prot_records) =
+        rt.mw_slice(output_ptr, &[status], false);
+    // The points follow the status on its page, so they are written a cycle later.
+    rt.advance_for_aliasing().unwrap_or_else(|err| panic!("{err}"));
+    let points = [g1_point.as_slice(), &g2_point].concat();
+    let (point_memory_records, page_prot_records) = rt.mw_slice(output_ptr + 8, &points, false);
+    write_page_prot_records.extend(page_prot_records);
+
+    let (local_mem_access, local_page_prot_access) = rt.postprocess();
+
+    with_checksum::<_, Ex>(BlsDeserializeEvent {
+        clk: start_clk,
+        syscall_id: rt.syscall_code.syscall_id(),
+        g1_ptr,
+        g1_compressed,
+        g2_ptr,
+        g2_compressed,
+        output_ptr,
+        g1_status,
+        g2_status,
+        g1_point,
+        g2_point,
+        g1_memory_records,
+        g2_memory_records,
+        status_memory_record: status_memory_records[0],
+        point_memory_records,
+        local_mem_access,
+        page_prot_records: EllipticCurvePageProtRecords {
+            read_page_prot_records,
+            write_page_prot_records,
+        },
+        local_page_prot_access,
+        checksum: None,
+    })
+}
@@ -0,0 +1,2816 @@
+use std::collections::BTreeMap;
+
+use deepsize2::DeepSizeOf;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use sp1_curves::{AffinePoint, CurveType, EllipticCurve};
+use thiserror::Error;
+
+use super::helpers::{
+    bls_deserialize_status, coordinate_words, count_pages, covering_words, decode_point,
+    ec_add_or_identity, encode_point, is_identity,
+};
+use crate::{
+    events::{
+        memory::{MemoryReadRecord, MemoryWriteRecord},
+        CompactEncoding, MemoryLocalEvent, PageProtLocalEvent, PageProtRecord,
+    },
+    syscalls::SyscallCode,
+    ExecutorConfig,
+};
+
+/// Errors found by validating an elliptic curve event constructed outside of the executor, such as
+/// a deserialized one.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EcEventError {
+    /// A list of the event does not have as many entries as the curve requires.
+    #[error("{field} has {actual} entries, expected {expected}")]
+    LengthMismatch {
+        /// The name of the list.
+        field: &'static str,
+        /// The number of entries required.
+        expected: usize,
+        /// The number of entries in the event.
+        actual: usize,
+    },
+    /// A field of the event disagrees with the operation recomputed from its operands.
+    #[error("{field} is inconsistent with the recomputed operation")]
+    Inconsistent {
+        /// The name of the field.
+        field: &'static str,
+    },
+}
+
+/// Returns the curve of the elliptic curve syscall with the given id, as recorded in the events.
+fn curve_of_syscall_id(syscall_id: u32) -> Option<CurveType> {
+    SyscallCode::iter()
+        .find(|code| code.syscall_id() == syscall_id)
+        .and_then(|code| CurveType::from_syscall_code(code as u32))
+}
+
+fn check_len(field: &'static str, expected: usize, actual: usize) -> Result<(), EcEventError> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(EcEventError::LengthMismatch { field, expected, actual })
+    }
+}
+
+/// Elliptic Curve Page Prot Records.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurvePageProtRecords {
+    /// The page prot records for reading the address.
+    pub read_page_prot_records: Vec<PageProtRecord>,
+    /// The page prot records for writing the address.
+    pub write_page_prot_records: Vec<PageProtRecord>,
+}
+
+impl EllipticCurvePageProtRecords {
+    /// Returns the number of pages that the writes of the event were the first to touch, those whose
+    /// previous page prot record is the initial one at timestamp 0.
+    ///
+    /// This attributes the cost of initializing fresh pages to the precompile that touched them. A
+    /// page read earlier in the same event was first touched by the read, so it is not counted.
+    #[must_use]
+    pub fn write_initialized_new_pages(&self) -> usize {
+        self.write_page_prot_records.iter().filter(|record| record.timestamp == 0).count()
+    }
+}
+
+/// The heap memory owned by a field of an event, as counted by [`DeepSizeOf`], computed from the
+/// vector capacities without visiting the elements.
+trait HeapSize {
+    fn heap_size(&self) -> usize;
+}
+
+/// The elements of the vectors of the events own no heap memory.
+impl<T> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * std::mem::size_of::<T>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, HeapSize::heap_size)
+    }
+}
+
+impl HeapSize for EllipticCurvePageProtRecords {
+    fn heap_size(&self) -> usize {
+        self.read_page_prot_records.heap_size() + self.write_page_prot_records.heap_size()
+    }
+}
+
+/// Implements `pages_touched` and `estimated_size` for events, from the `(ptr, num_words)` memory
+/// ranges they access and the fields that own heap memory.
+macro_rules! impl_event_sizes {
+    ($(
+        $event:ident {
+            pages: |$e:ident| [$($range:expr),* $(,)?],
+            heap: [$first:ident $(, $field:ident)* $(,)?] $(,)?
+        }
+    )*) => {
+        $(
+            impl $event {
+                /// Returns the number of distinct pages read or written by the event.
+                #[must_use]
+                pub fn pages_touched(&self) -> usize {
+                    let $e = self;
+                    count_pages(&[$($range),*])
+                }
+
+                /// Returns an estimate of [`DeepSizeOf::deep_size_of`] computed from the vector
+                /// capacities, without visiting the elements.
+                #[must_use]
+                pub fn estimated_size(&self) -> usize {
+                    std::mem::size_of::<Self>() + self.heap_size()
+                }
+            }
+
+            impl HeapSize for $event {
+                fn heap_size(&self) -> usize {
+                    self.$first.heap_size() $(+ self.$field.heap_size())*
+                }
+            }
+        )*
+    };
+}
+
+/// A borrowed memory record of an elliptic curve event.
+#[derive(Debug, Clone, Copy)]
+pub enum MemoryRecordRef<'a> {
+    /// Read.
+    Read(&'a MemoryReadRecord),
+    /// Write.
+    Write(&'a MemoryWriteRecord),
+}
+
+/// Accessors shared by the elliptic curve events.
+pub trait EcEvent {
+    /// Returns the memory records of the event, reads and writes alike, in the order the accesses
+    /// were made.
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>>
+    where
+        Self: Sized;
+
+    /// Returns the local memory access records of the event.
+    fn local_mem_access(&self) -> &[MemoryLocalEvent];
+
+    /// Returns the local page prot access records of the event.
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent];
+}
+
+/// Merges the local memory and page prot accesses of several events into one access per address
+/// and one per page, sorted by address and by page.
+///
+/// An address or page accessed by several events keeps the initial access with the smallest
+/// timestamp and the final access with the largest, as if the events were a single event spanning
+/// all of their accesses. The events are typically from the same shard, for consistency checks
+/// across events, or from consecutive shards being combined.
+#[must_use]
+pub fn merge_local_accesses(
+    events: &[&dyn EcEvent],
+) -> (Vec<MemoryLocalEvent>, Vec<PageProtLocalEvent>) {
+    let mut mem_accesses = BTreeMap::<u64, MemoryLocalEvent>::new();
+    for &access in events.iter().flat_map(|event| event.local_mem_access()) {
+        mem_accesses
+            .entry(access.addr)
+            .and_modify(|merged| {
+                if access.initial_mem_access.timestamp < merged.initial_mem_access.timestamp {
+                    merged.initial_mem_access = access.initial_mem_access;
+                }
+                if access.final_mem_access.timestamp > merged.final_mem_access.timestamp {
+                    merged.final_mem_access = access.final_mem_access;
+                }
+            })
+            .or_insert(access);
+    }
+
+    let mut page_prot_accesses = BTreeMap::<u64, PageProtLocalEvent>::new();
+    for &access in events.iter().flat_map(|event| event.local_page_prot_access()) {
+        page_prot_accesses
+            .entry(access.page_idx)
+            .and_modify(|merged| {
+                if access.initial_page_prot_access.timestamp
+                    < merged.initial_page_prot_access.timestamp
+                {
+                    merged.initial_page_prot_access = access.initial_page_prot_access;
+                }
+                if access.final_page_prot_access.timestamp > merged.final_page_prot_access.timestamp
+                {
+                    merged.final_page_prot_access = access.final_page_prot_access;
+                }
+            })
+            .or_insert(access);
+    }
+
+    (mem_accesses.into_values().collect(), page_prot_accesses.into_values().collect())
+}
+
+/// The optional checksum carried by every elliptic curve event, for detecting events that were
+/// corrupted while persisted.
+///
+/// The checksum is a 64-bit FNV-1a hash of the [`CompactEncoding`] of the event without its
+/// checksum, covering every input, output and record. It is not a cryptographic hash, so it only
+/// detects accidental corruption. Events are created with a checksum when
+/// [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is enabled.
+pub trait EcEventChecksum: CompactEncoding + Clone {
+    /// Returns the checksum stored in the event.
+    fn checksum(&self) -> Option<u64>;
+
+    /// Returns a mutable reference to the checksum stored in the event.
+    fn checksum_mut(&mut self) -> &mut Option<u64>;
+
+    /// Computes the checksum of the event from its current contents.
+    #[must_use]
+    fn compute_checksum(&self) -> u64 {
+        let mut event = self.clone();
+        *event.checksum_mut() = None;
+        event.to_compact_bytes().iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+    }
+
+    /// Returns whether the event has a checksum and it matches the contents of the event.
+    #[must_use]
+    fn verify_checksum(&self) -> bool {
+        self.checksum() == Some(self.compute_checksum())
+    }
+}
+
+/// Sets the checksum of a newly created event if [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is enabled.
+pub(super) fn with_checksum<T: EcEventChecksum, Ex: ExecutorConfig>(event: T) -> T {
+    checksummed(event, Ex::EC_EVENT_CHECKSUMS)
+}
+
+/// Sets the checksum of an event if `enabled`, which for an event converted from another is
+/// whether that one has a checksum.
+fn checksummed<T: EcEventChecksum>(mut event: T, enabled: bool) -> T {
+    if enabled {
+        *event.checksum_mut() = Some(event.compute_checksum());
+    }
+    event
+}
+
+/// Implements [`EcEventChecksum`] for events with a `checksum` field.
+macro_rules! impl_ec_event_checksum {
+    ($($event:ty),* $(,)?) => {
+        $(
+            impl EcEventChecksum for $event {
+                fn checksum(&self) -> Option<u64> {
+                    self.checksum
+                }
+
+                fn checksum_mut(&mut self) -> &mut Option<u64> {
+                    &mut self.checksum
+                }
+            }
+        )*
+    };
+}
+
+// The inline events carry the checksum of the event they convert to, which has a different
+// compact encoding, so they do not compute their own. A no-op event has a checksum of its own if
+// the event it was converted from has one.
+impl_ec_event_checksum!(
+    EllipticCurveAddEvent,
+    EllipticCurveDoubleEvent,
+    EllipticCurveDecompressEvent,
+    EllipticCurveAddToEvent,
+    EllipticCurveAddBytesEvent,
+    EllipticCurveRepeatedDoubleEvent,
+    EllipticCurveNoopEvent,
+    EllipticCurveAddProjectiveEvent,
+    EllipticCurveNormalizeProjectiveEvent,
+    EllipticCurveToJacobianEvent,
+    EllipticCurveNegateEvent,
+    EllipticCurvePointAndNegateEvent,
+    EllipticCurveLadderStepEvent,
+    G2PsiEvent,
+    EllipticCurveClearCofactorEvent,
+    EllipticCurveHashToCurveEvent,
+    EllipticCurveDecompressMemSignEvent,
+    EllipticCurveDecompressBatchEvent,
+    EllipticCurveSec1DecompressEvent,
+    EdwardsDecompressEvent,
+    G2DecompressEvent,
+    BlsDeserializeEvent,
+    EllipticCurveCompressEvent,
+    EllipticCurveScalarMulEvent,
+    EllipticCurveMsmEvent,
+    EllipticCurveAddBatchEvent,
+    EllipticCurveSubgroupCheckEvent,
+    EllipticCurveLoadValidateEvent,
+    EllipticCurveEqualsEvent,
+    EllipticCurveIsNegationEvent,
+    FieldInverseEvent,
+    ScalarReduceEvent,
+    EcrecoverEvent,
+    MillerStepEvent,
+);
+
+fn reads(records: &[MemoryReadRecord]) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+    records.iter().map(MemoryRecordRef::Read)
+}
+
+fn writes(records: &[MemoryWriteRecord]) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+    records.iter().map(MemoryRecordRef::Write)
+}
+
+/// Elliptic Curve Add Event.
+///
+/// This event is emitted when an elliptic curve addition operation is performed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveAddEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the first point.
+    pub p_ptr: u64,
+    /// The first point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the second point.
+    pub q_ptr: u64,
+    /// The second point as a list of words.
+    pub q: Vec<u64>,
+    /// The memory records for the first point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the second point.
+    pub q_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// Whether the result written back to `p` is the point at infinity.
+    pub result_is_identity: bool,
+    /// The number of cycles consumed by the operation.
+    pub cycles: u64,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EllipticCurveAddEvent {
+    /// Checks that the points and their memory records have one entry per word of a point.
+    ///
+    /// The number of words is that of the curve of the syscall recorded in the event. For a
+    /// syscall that is not tied to a curve, such as the G2 additions, it is the length of `p`.
+    /// [`CompactEncoding::from_compact_bytes`] calls this on every decoded event.
+    ///
+    /// [`CompactEncoding::from_compact_bytes`]: super::CompactEncoding::from_compact_bytes
+    pub fn validate(&self) -> Result<(), EcEventError> {
+        let num_words =
+            curve_of_syscall_id(self.syscall_id).map_or(self.p.len(), CurveType::words_per_point);
+        check_len("p", num_words, self.p.len())?;
+        check_len("q", num_words, self.q.len())?;
+        check_len("p_memory_records", num_words, self.p_memory_records.len())?;
+        check_len("q_memory_records", num_words, self.q_memory_records.len())
+    }
+
+    /// Recomputes the sum of `p` and `q` as [`create_ec_add_event`] does, returning the words it
+    /// writes over `p`.
+    ///
+    /// `E` must be the curve of the syscall that emitted the event. Events of the G2 additions are
+    /// not supported.
+    ///
+    /// [`create_ec_add_event`]: super::create_ec_add_event
+    #[must_use]
+    pub fn recompute<E: EllipticCurve>(&self) -> Vec<u64> {
+        let sum = match (decode_point::<E>(&self.p), decode_point::<E>(&self.q)) {
+            (None, q_affine) => q_affine,
+            (p_affine, None) => p_affine,
+            (Some(p_affine), Some(q_affine)) => ec_add_or_identity(&p_affine, &q_affine),
+        };
+        encode_point(sum).to_memory_words()
+    }
+
+    /// Returns whether the values written in `p_memory_records` are the sum given by
+    /// [`Self::recompute`].
+    #[must_use]
+    pub fn verify<E: EllipticCurve>(&self) -> bool {
+        self.p_memory_records.iter().map(|record| record.value).eq(self.recompute::<E>())
+    }
+
+    /// Replays the event offline, checking that its records are consistent with the addition
+    /// recomputed from the stored `p` and `q`, without a [`SyscallContext`] or a memory image.
+    ///
+    /// Beyond [`Self::verify`], this checks the lengths with [`Self::validate`], that `q` is the
+    /// value read and `p` the value overwritten, that [`Self::result_is_identity`] matches the sum,
+    /// and that the sum is written after `q` is read, as the two may alias. Returns the first
+    /// inconsistency found.
+    ///
+    /// `E` must be the curve of the syscall that emitted the event.
+    ///
+    /// [`SyscallContext`]: crate::syscalls::SyscallContext
+    pub fn replay<E: EllipticCurve>(&self) -> Result<(), EcEventError> {
+        self.validate()?;
+        let inconsistent = |field| Err(EcEventError::Inconsistent { field });
+        if !self.q_memory_records.iter().map(|record| record.value).eq(self.q.iter().copied()) {
+            return inconsistent("q_memory_records");
+        }
+        if !self.p_memory_records.iter().map(|record| record.prev_value).eq(self.p.iter().copied())
+        {
+            return inconsistent("p_memory_records");
+        }
+        let sum = self.recompute::<E>();
+        if !self.p_memory_records.iter().map(|record| record.value).eq(sum.iter().copied()) {
+            return inconsistent("p_memory_records");
+        }
+        if self.result_is_identity != is_identity(&AffinePoint::<E>::from_memory_words(&sum)) {
+            return inconsistent("result_is_identity");
+        }
+        let last_read = self.q_memory_records.iter().map(|record| record.timestamp).max();
+        let first_write = self.p_memory_records.iter().map(|record| record.timestamp).min();
+        if first_write <= last_read {
+            return inconsistent("p_memory_records");
+        }
+        Ok(())
+    }
+
+    /// Returns the words of the x coordinate of the first point.
+    #[must_use]
+    pub fn p_x_words(&self) -> &[u64] {
+        coordinate_words(&self.p).0
+    }
+
+    /// Returns the words of the y coordinate of the first point.
+    #[must_use]
+    pub fn p_y_words(&self) -> &[u64] {
+        coordinate_words(&self.p).1
+    }
+
+    /// Returns the words of the x coordinate of the second point.
+    #[must_use]
+    pub fn q_x_words(&self) -> &[u64] {
+        coordinate_words(&self.q).0
+    }
+
+    /// Returns the words of the y coordinate of the second point.
+    #[must_use]
+    pub fn q_y_words(&self) -> &[u64] {
+        coordinate_words(&self.q).1
+    }
+
+    /// Returns the first point, decoded for the curve `E`.
+    #[must_use]
+    pub fn p_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.p)
+    }
+
+    /// Returns the second point, decoded for the curve `E`.
+    #[must_use]
+    pub fn q_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.q)
+    }
+}
+
+impl EcEvent for EllipticCurveAddEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.q_memory_records).chain(writes(&self.p_memory_records))
+    }
+}
+
+/// Elliptic Curve Add To Event.
+///
+/// This event is emitted when two elliptic curve points are added and the sum is written to a third
+/// location. See [`create_ec_add_to_event`].
+///
+/// [`create_ec_add_to_event`]: super::create_ec_add_to_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveAddToEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the first point.
+    pub p_ptr: u64,
+    /// The first point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the second point.
+    pub q_ptr: u64,
+    /// The second point as a list of words.
+    pub q: Vec<u64>,
+    /// The pointer to the sum.
+    pub out_ptr: u64,
+    /// The sum as a list of words.
+    pub result: Vec<u64>,
+    /// The memory records for the first point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the second point.
+    pub q_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the sum.
+    pub out_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// Whether the sum is the point at infinity.
+    pub result_is_identity: bool,
+    /// The number of cycles consumed by the operation.
+    pub cycles: u64,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EllipticCurveAddToEvent {
+    /// Returns the first point, decoded for the curve `E`.
+    #[must_use]
+    pub fn p_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.p)
+    }
+
+    /// Returns the second point, decoded for the curve `E`.
+    #[must_use]
+    pub fn q_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.q)
+    }
+
+    /// Returns the sum, decoded for the curve `E`.
+    #[must_use]
+    pub fn result_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.result)
+    }
+}
+
+impl EcEvent for EllipticCurveAddToEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records)
+            .chain(reads(&self.q_memory_records))
+            .chain(writes(&self.out_memory_records))
+    }
+}
+
+/// Elliptic Curve Add Bytes Event.
+///
+/// This event is emitted when two elliptic curve points given by byte pointers are added. See
+/// [`create_ec_add_event_bytes`].
+///
+/// [`create_ec_add_event_bytes`]: super::create_ec_add_event_bytes
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveAddBytesEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The byte pointer to the first point.
+    pub p_ptr: u64,
+    /// The first point as a list of words.
+    pub p: Vec<u64>,
+    /// The byte pointer to the second point.
+    pub q_ptr: u64,
+    /// The second point as a list of words.
+    pub q: Vec<u64>,
+    /// The number of bytes of each point.
+    pub len: u64,
+    /// The memory records for the words covering the first point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the words covering the second point.
+    pub q_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// Whether the sum is the point at infinity.
+    pub result_is_identity: bool,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveAddBytesEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.q_memory_records).chain(writes(&self.p_memory_records))
+    }
+}
+
+/// Elliptic Curve Double Event.
+///
+/// This event is emitted when an elliptic curve doubling operation is performed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveDoubleEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// Whether the result written back to `p` is the point at infinity.
+    pub result_is_identity: bool,
+    /// Whether the result equaled `p`, so that the original words were written back unchanged.
+    /// Only set when [`ExecutorConfig::ELIDE_UNCHANGED_EC_WRITES`] is enabled.
+    pub unchanged_write: bool,
+    /// The number of cycles consumed by the operation.
+    pub cycles: u64,
+    /// The `Z` coordinate of the doubling of `p` from `Z = 1` in Jacobian coordinates, as
+    /// little-endian words of a base field element. It is `2y`, or 0 if the result is the point at
+    /// infinity. Only set for short Weierstrass curves when
+    /// [`ExecutorConfig::RECORD_JACOBIAN_Z`] is enabled.
+    pub jacobian_z_words: Option<Vec<u64>>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EllipticCurveDoubleEvent {
+    /// Returns the point before doubling, decoded for the curve `E`.
+    #[must_use]
+    pub fn p_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.p)
+    }
+}
+
+impl EcEvent for EllipticCurveDoubleEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.p_memory_records)
+    }
+}
+
+/// Elliptic Curve Repeated Double Event.
+///
+/// This event is emitted when a point is doubled `n` times in place, computing `[2^n]P` with a
+/// single syscall.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveRepeatedDoubleEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The number of doublings.
+    pub n: u64,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// Whether the result written back to `p` is the point at infinity.
+    pub result_is_identity: bool,
+    /// The number of cycles consumed by the operation.
+    pub cycles: u64,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveRepeatedDoubleEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.p_memory_records)
+    }
+}
+
+/// Elliptic Curve No-op Event.
+///
+/// This event is emitted instead of an add or double event when the operation reduces to a copy,
+/// so that trace generators can use a cheaper row. It still records every memory access of the
+/// original event. An addition is a copy when either point is the identity, so that the result is
+/// the other point, and a doubling is a copy when the point is the identity.
+///
+/// Events are only routed here when [`ExecutorConfig::EMIT_EC_NOOP_EVENTS`] is enabled.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveNoopEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point that is written.
+    pub p_ptr: u64,
+    /// The point that is written, as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the second point of an addition, or 0 for a doubling.
+    pub q_ptr: u64,
+    /// The second point of an addition as a list of words, empty for a doubling.
+    pub q: Vec<u64>,
+    /// The memory records for the point that is written.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the second point of an addition, empty for a doubling.
+    pub q_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// The number of cycles consumed by the operation.
+    pub cycles: u64,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveNoopEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.q_memory_records).chain(writes(&self.p_memory_records))
+    }
+}
+
+/// Returns the values written by a list of write records.
+pub(super) fn written_values(records: &[MemoryWriteRecord]) -> impl Iterator<Item = u64> + '_ {
+    records.iter().map(|record| record.value)
+}
+
+impl TryFrom<EllipticCurveAddEvent> for EllipticCurveNoopEvent {
+    /// The event is returned unchanged if the sum is neither of the two points.
+    type Error = EllipticCurveAddEvent;
+
+    /// `P + Q` is `P` exactly when `Q` is the identity, and `Q` exactly when `P` is, so the sum is a
+    /// copy when the words written are those of either point.
+    fn try_from(event: EllipticCurveAddEvent) -> Result<Self, Self::Error> {
+        let written = written_values(&event.p_memory_records);
+        if !written.clone().eq(event.p.iter().copied()) && !written.eq(event.q.iter().copied()) {
+            return Err(event);
+        }
+        let noop = Self {
+            clk: event.clk,
+            syscall_id: event.syscall_id,
+            p_ptr: event.p_ptr,
+            p: event.p,
+            q_ptr: event.q_ptr,
+            q: event.q,
+            p_memory_records: event.p_memory_records,
+            q_memory_records: event.q_memory_records,
+            local_mem_access: event.local_mem_access,
+            page_prot_records: event.page_prot_records,
+            local_page_prot_access: event.local_page_prot_access,
+            cycles: event.cycles,
+            checksum: None,
+        };
+        Ok(checksummed(noop, event.checksum.is_some()))
+    }
+}
+
+impl TryFrom<EllipticCurveDoubleEvent> for EllipticCurveNoopEvent {
+    /// The event is returned unchanged if the double is not the point itself.
+    type Error = EllipticCurveDoubleEvent;
+
+    /// `2P` is `P` exactly when `P` is the identity.
+    fn try_from(event: EllipticCurveDoubleEvent) -> Result<Self, Self::Error> {
+        if !written_values(&event.p_memory_records).eq(event.p.iter().copied()) {
+            return Err(event);
+        }
+        let noop = Self {
+            clk: event.clk,
+            syscall_id: event.syscall_id,
+            p_ptr: event.p_ptr,
+            p: event.p,
+            q_ptr: 0,
+            q: Vec::new(),
+            p_memory_records: event.p_memory_records,
+            q_memory_records: Vec::new(),
+            local_mem_access: event.local_mem_access,
+            page_prot_records: EllipticCurvePageProtRecords {
+                read_page_prot_records: Vec::new(),
+                write_page_prot_records: event.write_slice_page_prot_access,
+            },
+            local_page_prot_access: event.local_page_prot_access,
+            cycles: event.cycles,
+            checksum: None,
+        };
+        Ok(checksummed(noop, event.checksum.is_some()))
+    }
+}
+
+/// Elliptic Curve Add Event with the point words stored inline.
+///
+/// This is the same as [`EllipticCurveAddEvent`], where `N` is the number of words in a curve
+/// point, but it avoids a heap allocation per point and per record list. It converts to and from
+/// [`EllipticCurveAddEvent`], which remains the serialization format of the precompile event.
+#[derive(Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveAddEventN<const N: usize> {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the first point.
+    pub p_ptr: u64,
+    /// The first point as a list of words.
+    #[serde(with = "serde_arrays")]
+    pub p: [u64; N],
+    /// The pointer to the second point.
+    pub q_ptr: u64,
+    /// The second point as a list of words.
+    #[serde(with = "serde_arrays")]
+    pub q: [u64; N],
+    /// The memory records for the first point.
+    #[serde(with = "serde_arrays")]
+    pub p_memory_records: [MemoryWriteRecord; N],
+    /// The memory records for the second point.
+    #[serde(with = "serde_arrays")]
+    pub q_memory_records: [MemoryReadRecord; N],
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// Whether the result written back to `p` is the point at infinity.
+    pub result_is_identity: bool,
+    /// The number of cycles consumed by the operation.
+    pub cycles: u64,
+    /// The checksum of the equivalent [`EllipticCurveAddEvent`], which is kept through the conversions.
+    pub checksum: Option<u64>,
+}
+
+impl<const N: usize> Default for EllipticCurveAddEventN<N> {
+    fn default() -> Self {
+        Self {
+            clk: 0,
+            syscall_id: 0,
+            p_ptr: 0,
+            p: [0; N],
+            q_ptr: 0,
+            q: [0; N],
+            p_memory_records: [MemoryWriteRecord::default(); N],
+            q_memory_records: [MemoryReadRecord::default(); N],
+            local_mem_access: Vec::new(),
+            page_prot_records: EllipticCurvePageProtRecords::default(),
+            local_page_prot_access: Vec::new(),
+            result_is_identity: false,
+            cycles: 0,
+            checksum: None,
+        }
+    }
+}
+
+impl<const N: usize> From<EllipticCurveAddEventN<N>> for EllipticCurveAddEvent {
+    fn from(event: EllipticCurveAddEventN<N>) -> Self {
+        Self {
+            clk: event.clk,
+            syscall_id: event.syscall_id,
+            p_ptr: event.p_ptr,
+            p: event.p.to_vec(),
+            q_ptr: event.q_ptr,
+            q: event.q.to_vec(),
+            p_memory_records: event.p_memory_records.to_vec(),
+            q_memory_records: event.q_memory_records.to_vec(),
+            local_mem_access: event.local_mem_access,
+            page_prot_records: event.page_prot_records,
+            local_page_prot_access: event.local_page_prot_access,
+            result_is_identity: event.result_is_identity,
+            cycles: event.cycles,
+            checksum: event.checksum,
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<EllipticCurveAddEvent> for EllipticCurveAddEventN<N> {
+    /// The event is returned unchanged if its points are not `N` words long.
+    type Error = EllipticCurveAddEvent;
+
+    fn try_from(event: EllipticCurveAddEvent) -> Result<Self, Self::Error> {
+        if event.p.len() != N
+            || event.q.len() != N
+            || event.p_memory_records.len() != N
+            || event.q_memory_records.len() != N
+        {
+            return Err(event);
+        }
+        Ok(Self {
+            clk: event.clk,
+            syscall_id: event.syscall_id,
+            p_ptr: event.p_ptr,
+            p: event.p.try_into().expect("length was checked"),
+            q_ptr: event.q_ptr,
+            q: event.q.try_into().expect("length was checked"),
+            p_memory_records: event.p_memory_records.try_into().expect("length was checked"),
+            q_memory_records: event.q_memory_records.try_into().expect("length was checked"),
+            local_mem_access: event.local_mem_access,
+            page_prot_records: event.page_prot_records,
+            local_page_prot_access: event.local_page_prot_access,
+            result_is_identity: event.result_is_identity,
+            cycles: event.cycles,
+            checksum: event.checksum,
+        })
+    }
+}
+
+/// Elliptic Curve Double Event with the point words stored inline.
+///
+/// This is the same as [`EllipticCurveDoubleEvent`], where `N` is the number of words in a curve
+/// point. See [`EllipticCurveAddEventN`].
+#[derive(Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveDoubleEventN<const N: usize> {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    #[serde(with = "serde_arrays")]
+    pub p: [u64; N],
+    /// The memory records for the point.
+    #[serde(with = "serde_arrays")]
+    pub p_memory_records: [MemoryWriteRecord; N],
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// Whether the result written back to `p` is the point at infinity.
+    pub result_is_identity: bool,
+    /// Whether the result equaled `p`, so that the original words were written back unchanged.
+    /// Only set when [`ExecutorConfig::ELIDE_UNCHANGED_EC_WRITES`] is enabled.
+    pub unchanged_write: bool,
+    /// The number of cycles consumed by the operation.
+    pub cycles: u64,
+    /// The Jacobian `Z` coordinate of the equivalent [`EllipticCurveDoubleEvent`].
+    pub jacobian_z_words: Option<Vec<u64>>,
+    /// The checksum of the equivalent [`EllipticCurveDoubleEvent`], which is kept through the conversions.
+    pub checksum: Option<u64>,
+}
+
+impl<const N: usize> Default for EllipticCurveDoubleEventN<N> {
+    fn default() -> Self {
+        Self {
+            clk: 0,
+            syscall_id: 0,
+            p_ptr: 0,
+            p: [0; N],
+            p_memory_records: [MemoryWriteRecord::default(); N],
+            local_mem_access: Vec::new(),
+            write_slice_page_prot_access: Vec::new(),
+            local_page_prot_access: Vec::new(),
+            result_is_identity: false,
+            unchanged_write: false,
+            cycles: 0,
+            jacobian_z_words: None,
+            checksum: None,
+        }
+    }
+}
+
+impl<const N: usize> From<EllipticCurveDoubleEventN<N>> for EllipticCurveDoubleEvent {
+    fn from(event: EllipticCurveDoubleEventN<N>) -> Self {
+        Self {
+            clk: event.clk,
+            syscall_id: event.syscall_id,
+            p_ptr: event.p_ptr,
+            p: event.p.to_vec(),
+            p_memory_records: event.p_memory_records.to_vec(),
+            local_mem_access: event.local_mem_access,
+            write_slice_page_prot_access: event.write_slice_page_prot_access,
+            local_page_prot_access: event.local_page_prot_access,
+            result_is_identity: event.result_is_identity,
+            unchanged_write: event.unchanged_write,
+            cycles: event.cycles,
+            jacobian_z_words: event.jacobian_z_words,
+            checksum: event.checksum,
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<EllipticCurveDoubleEvent> for EllipticCurveDoubleEventN<N> {
+    /// The event is returned unchanged if its point is not `N` words long.
+    type Error = EllipticCurveDoubleEvent;
+
+    fn try_from(event: EllipticCurveDoubleEvent) -> Result<Self, Self::Error> {
+        if event.p.len() != N || event.p_memory_records.len() != N {
+            return Err(event);
+        }
+        Ok(Self {
+            clk: event.clk,
+            syscall_id: event.syscall_id,
+            p_ptr: event.p_ptr,
+            p: event.p.try_into().expect("length was checked"),
+            p_memory_records: event.p_memory_records.try_into().expect("length was checked"),
+            local_mem_access: event.local_mem_access,
+            write_slice_page_prot_access: event.write_slice_page_prot_access,
+            local_page_prot_access: event.local_page_prot_access,
+            result_is_identity: event.result_is_identity,
+            unchanged_write: event.unchanged_write,
+            cycles: event.cycles,
+            jacobian_z_words: event.jacobian_z_words,
+            checksum: event.checksum,
+        })
+    }
+}
+
+/// Elliptic Curve Add Projective Event.
+///
+/// This event is emitted when a point is added to an accumulator held in Jacobian coordinates. See
+/// [`create_ec_add_projective_event`] for the memory layout.
+///
+/// [`create_ec_add_projective_event`]: super::create_ec_add_projective_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveAddProjectiveEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the accumulator.
+    pub acc_ptr: u64,
+    /// The accumulator as a list of words, before the addition.
+    pub acc: Vec<u64>,
+    /// The pointer to the affine point.
+    pub q_ptr: u64,
+    /// The affine point as a list of words.
+    pub q: Vec<u64>,
+    /// The memory records for the accumulator.
+    pub acc_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the affine point.
+    pub q_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveAddProjectiveEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.acc_memory_records).chain(reads(&self.q_memory_records))
+    }
+}
+
+/// Elliptic Curve Normalize Projective Event.
+///
+/// This event is emitted when an accumulator held in Jacobian coordinates is converted to an affine
+/// point. See [`create_ec_normalize_projective_event`] for the memory layout.
+///
+/// [`create_ec_normalize_projective_event`]: super::create_ec_normalize_projective_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveNormalizeProjectiveEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the accumulator.
+    pub acc_ptr: u64,
+    /// The accumulator as a list of words.
+    pub acc: Vec<u64>,
+    /// The pointer to the affine result.
+    pub result_ptr: u64,
+    /// The affine result as a list of words.
+    pub result: Vec<u64>,
+    /// The memory records for the accumulator.
+    pub acc_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the affine result.
+    pub result_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveNormalizeProjectiveEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.acc_memory_records).chain(writes(&self.result_memory_records))
+    }
+}
+
+/// Elliptic Curve To Jacobian Event.
+///
+/// This event is emitted when an affine point is converted to Jacobian coordinates. See
+/// [`create_ec_to_jacobian_event`] for the memory layout.
+///
+/// [`create_ec_to_jacobian_event`]: super::create_ec_to_jacobian_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveToJacobianEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the affine point.
+    pub p_ptr: u64,
+    /// The affine point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the Jacobian result.
+    pub result_ptr: u64,
+    /// The Jacobian result as a list of words.
+    pub result: Vec<u64>,
+    /// The memory records for the affine point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the Jacobian result.
+    pub result_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveToJacobianEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records).chain(writes(&self.result_memory_records))
+    }
+}
+
+/// Elliptic Curve Negate Event.
+///
+/// This event is emitted when a point is negated in place.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveNegateEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EllipticCurveNegateEvent {
+    /// Returns the point before negation, decoded for the curve `E`.
+    #[must_use]
+    pub fn p_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.p)
+    }
+}
+
+impl EcEvent for EllipticCurveNegateEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.p_memory_records)
+    }
+}
+
+/// Elliptic Curve Point And Negate Event.
+///
+/// This event is emitted when a point is read and its negation is written to a second location,
+/// leaving the point in place. See [`create_ec_point_and_negate_event`].
+///
+/// [`create_ec_point_and_negate_event`]: super::create_ec_point_and_negate_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurvePointAndNegateEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the negation.
+    pub neg_ptr: u64,
+    /// The negation as a list of words.
+    pub neg: Vec<u64>,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the negation.
+    pub neg_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EllipticCurvePointAndNegateEvent {
+    /// Returns the point, decoded for the curve `E`.
+    #[must_use]
+    pub fn p_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.p)
+    }
+
+    /// Returns the negation, decoded for the curve `E`.
+    #[must_use]
+    pub fn neg_affine<E: EllipticCurve>(&self) -> AffinePoint<E> {
+        AffinePoint::from_memory_words(&self.neg)
+    }
+}
+
+impl EcEvent for EllipticCurvePointAndNegateEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records).chain(writes(&self.neg_memory_records))
+    }
+}
+
+/// Elliptic Curve Ladder Step Event.
+///
+/// This event is emitted when a step of the Montgomery ladder is applied to a pair of points in
+/// place. See [`create_ec_ladder_step_event`] for the memory layout.
+///
+/// [`create_ec_ladder_step_event`]: super::create_ec_ladder_step_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveLadderStepEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the pair of points.
+    pub ptr: u64,
+    /// The ladder bit, selecting the point that is doubled.
+    pub bit: bool,
+    /// The pair of points `r0, r1` as a list of words.
+    pub points: Vec<u64>,
+    /// The updated pair of points as a list of words.
+    pub result: Vec<u64>,
+    /// The memory records for the pair of points.
+    pub memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveLadderStepEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.memory_records)
+    }
+}
+
+/// G2 Psi Event.
+///
+/// This event is emitted when the endomorphism `psi` of the BLS12-381 G2 group is applied to a point
+/// in place. See [`create_g2_psi_event`].
+///
+/// [`create_g2_psi_event`]: super::create_g2_psi_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct G2PsiEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for G2PsiEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.p_memory_records)
+    }
+}
+
+/// Elliptic Curve Clear Cofactor Event.
+///
+/// This event is emitted when a point is multiplied in place by the effective cofactor of its curve.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveClearCofactorEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveClearCofactorEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.p_memory_records)
+    }
+}
+
+/// Elliptic Curve Hash To Curve Event.
+///
+/// This event is emitted when a base field element is mapped to a curve point, the `map_to_curve`
+/// step of hash-to-curve. See [`create_hash_to_curve_event`].
+///
+/// [`create_hash_to_curve_event`]: super::create_hash_to_curve_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveHashToCurveEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The resulting point as a list of words, all zero for the point at infinity.
+    pub p: Vec<u64>,
+    /// The pointer to the field element.
+    pub u_ptr: u64,
+    /// The field element as a list of little-endian words.
+    pub u: Vec<u64>,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the field element.
+    pub u_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveHashToCurveEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.u_memory_records).chain(writes(&self.p_memory_records))
+    }
+}
+
+/// Elliptic Curve Point Decompress Event.
+///
+/// This event is emitted when an elliptic curve point decompression operation is performed.
+///
+/// The field names follow the short Weierstrass convention, which recovers `y` from `x`. Twisted
+/// Edwards curves compress to `y` and the parity of `x` instead, so for them `x_bytes` holds the
+/// compressed `y` coordinate and `decompressed_y_bytes` holds the recovered `x` coordinate.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveDecompressEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub ptr: u64,
+    /// The sign bit of the point.
+    pub sign_bit: bool,
+    /// The x coordinate as a list of little-endian bytes, in the order they are read from memory.
+    pub x_bytes: Vec<u8>,
+    /// The decompressed y coordinate as a list of little-endian bytes.
+    pub decompressed_y_bytes: Vec<u8>,
+    /// Whether the parity of the canonical square root differed from `sign_bit`, so that the
+    /// decompressed y coordinate is its negation. This is informational only.
+    pub sign_corrected: bool,
+    /// The memory records for the x coordinate.
+    pub x_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the y coordinate.
+    pub y_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// The number of cycles consumed by the operation.
+    pub cycles: u64,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveDecompressEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.x_memory_records).chain(writes(&self.y_memory_records))
+    }
+}
+
+/// Elliptic Curve Decompress Mem Sign Event.
+///
+/// This event is emitted when a compressed point is decompressed with its sign bit read from a
+/// memory word. See [`create_ec_decompress_mem_sign_event`].
+///
+/// [`create_ec_decompress_mem_sign_event`]: super::create_ec_decompress_mem_sign_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveDecompressMemSignEvent {
+    /// The pointer to the sign bit.
+    pub sign_ptr: u64,
+    /// The memory record for the sign bit.
+    pub sign_memory_record: MemoryReadRecord,
+    /// The decompression of the point.
+    pub decompress: EllipticCurveDecompressEvent,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveDecompressMemSignEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.decompress.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.decompress.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(std::slice::from_ref(&self.sign_memory_record))
+            .chain(self.decompress.memory_records())
+    }
+}
+
+/// Elliptic Curve Decompress Batch Event.
+///
+/// This event is emitted when a list of compressed elliptic curve points is decompressed. See
+/// [`create_ec_decompress_batch_event`] for the memory layout.
+///
+/// [`create_ec_decompress_batch_event`]: super::create_ec_decompress_batch_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveDecompressBatchEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the input buffer.
+    pub input_ptr: u64,
+    /// The pointer to the output buffer.
+    pub output_ptr: u64,
+    /// The number of compressed points.
+    pub n: u64,
+    /// The packed sign bits, one bit per point.
+    pub sign_bits: Vec<u64>,
+    /// The x coordinates as a flat list of little-endian words, one coordinate after another.
+    pub x_words: Vec<u64>,
+    /// The status word written to the output buffer.
+    pub status: u64,
+    /// The decompressed points as a flat list of words, one point after another.
+    pub points: Vec<u64>,
+    /// The memory record for the header word holding `n`.
+    pub n_memory_record: MemoryReadRecord,
+    /// The memory records for the sign bits.
+    pub sign_bits_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the x coordinates, one coordinate after another.
+    pub x_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the status word.
+    pub status_memory_record: MemoryWriteRecord,
+    /// The memory records for the points, one point after another.
+    pub points_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records of every read and every write.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveDecompressBatchEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(std::slice::from_ref(&self.n_memory_record))
+            .chain(reads(&self.sign_bits_memory_records))
+            .chain(reads(&self.x_memory_records))
+            .chain(writes(std::slice::from_ref(&self.status_memory_record)))
+            .chain(writes(&self.points_memory_records))
+    }
+}
+
+/// Elliptic Curve SEC1 Decompress Event.
+///
+/// This event is emitted when a point of a short Weierstrass curve is decoded from its SEC1
+/// compressed encoding. See [`create_ec_decompress_sec1_event`] for the memory layout.
+///
+/// [`create_ec_decompress_sec1_event`]: super::create_ec_decompress_sec1_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveSec1DecompressEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The byte pointer to the compressed encoding.
+    pub compressed_ptr: u64,
+    /// The compressed encoding, the prefix byte followed by the big-endian `x`.
+    pub compressed: Vec<u8>,
+    /// The byte pointer to the uncompressed encoding.
+    pub output_ptr: u64,
+    /// The uncompressed encoding, the prefix byte `0x04` followed by the big-endian `x` and `y`.
+    pub uncompressed: Vec<u8>,
+    /// The memory records for the words covering the compressed encoding.
+    pub compressed_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the words covering the uncompressed encoding.
+    pub uncompressed_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveSec1DecompressEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.compressed_memory_records).chain(writes(&self.uncompressed_memory_records))
+    }
+}
+
+/// Edwards Decompress Event.
+///
+/// This event is emitted when a point of a twisted Edwards curve is decoded from its 32-byte
+/// compressed encoding. See [`create_edwards_decompress_event`] for the memory layout.
+///
+/// [`create_edwards_decompress_event`]: super::create_edwards_decompress_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EdwardsDecompressEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the compressed encoding.
+    pub compressed_ptr: u64,
+    /// The compressed encoding as a list of words.
+    pub compressed: Vec<u64>,
+    /// The pointer to the output buffer.
+    pub output_ptr: u64,
+    /// The status word written to the output buffer, 0 if the encoding was valid.
+    pub status: u64,
+    /// The decompressed point as a list of words, all zero if the encoding was invalid.
+    pub point: Vec<u64>,
+    /// The memory records for the compressed encoding.
+    pub compressed_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the status word.
+    pub status_memory_record: MemoryWriteRecord,
+    /// The memory records for the decompressed point.
+    pub point_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EdwardsDecompressEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.compressed_memory_records)
+            .chain(writes(std::slice::from_ref(&self.status_memory_record)))
+            .chain(writes(&self.point_memory_records))
+    }
+}
+
+/// G2 Decompress Event.
+///
+/// This event is emitted when a point of the BLS12-381 G2 group is decoded from its 96-byte
+/// compressed serialization. See [`create_g2_decompress_event`] for the memory layout.
+///
+/// [`create_g2_decompress_event`]: super::create_g2_decompress_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct G2DecompressEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the compressed serialization.
+    pub compressed_ptr: u64,
+    /// The compressed serialization as a list of words.
+    pub compressed: Vec<u64>,
+    /// The pointer to the output buffer.
+    pub output_ptr: u64,
+    /// The status word written to the output buffer, 0 if the serialization was valid.
+    pub status: u64,
+    /// The decompressed point as a list of words, all zero for the point at infinity or if the
+    /// serialization was invalid.
+    pub point: Vec<u64>,
+    /// The memory records for the compressed serialization.
+    pub compressed_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the status word.
+    pub status_memory_record: MemoryWriteRecord,
+    /// The memory records for the decompressed point.
+    pub point_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for G2DecompressEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.compressed_memory_records)
+            .chain(writes(std::slice::from_ref(&self.status_memory_record)))
+            .chain(writes(&self.point_memory_records))
+    }
+}
+
+/// BLS Deserialize Event.
+///
+/// This event is emitted when a BLS12-381 G1 point and G2 point are decoded from their compressed
+/// serializations and validated together. See [`create_bls_deserialize_event`] for the memory
+/// layout.
+///
+/// [`create_bls_deserialize_event`]: super::create_bls_deserialize_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct BlsDeserializeEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the compressed G1 point.
+    pub g1_ptr: u64,
+    /// The compressed G1 point as a list of words.
+    pub g1_compressed: Vec<u64>,
+    /// The pointer to the compressed G2 point.
+    pub g2_ptr: u64,
+    /// The compressed G2 point as a list of words.
+    pub g2_compressed: Vec<u64>,
+    /// The pointer to the output buffer.
+    pub output_ptr: u64,
+    /// The status of the G1 point.
+    pub g1_status: EcValidationStatus,
+    /// The status of the G2 point.
+    pub g2_status: EcValidationStatus,
+    /// The decompressed G1 point as a list of words, all zero unless it was valid and finite.
+    pub g1_point: Vec<u64>,
+    /// The decompressed G2 point as a list of words, all zero unless it was valid and finite.
+    pub g2_point: Vec<u64>,
+    /// The memory records for the compressed G1 point.
+    pub g1_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the compressed G2 point.
+    pub g2_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the status word.
+    pub status_memory_record: MemoryWriteRecord,
+    /// The memory records for both decompressed points.
+    pub point_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl BlsDeserializeEvent {
+    /// Returns the combined status word, the G1 status in the low half and the G2 status in the
+    /// high half. It is 0 if and only if both points are valid.
+    #[must_use]
+    pub fn status(&self) -> u64 {
+        bls_deserialize_status(self.g1_status, self.g2_status)
+    }
+}
+
+impl EcEvent for BlsDeserializeEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.g1_memory_records)
+            .chain(reads(&self.g2_memory_records))
+            .chain(writes(std::slice::from_ref(&self.status_memory_record)))
+            .chain(writes(&self.point_memory_records))
+    }
+}
+
+/// Elliptic Curve Point Compress Event.
+///
+/// This event is emitted when a point is compressed to its serialized form.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveCompressEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the compressed point.
+    pub compressed_ptr: u64,
+    /// The compressed point, in the byte order it is written to memory.
+    pub compressed: Vec<u8>,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the compressed point.
+    pub compressed_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveCompressEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records).chain(writes(&self.compressed_memory_records))
+    }
+}
+
+/// Elliptic Curve Scalar Multiplication Event.
+///
+/// This event is emitted when an elliptic curve scalar multiplication operation is performed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveScalarMulEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the scalar.
+    pub scalar_ptr: u64,
+    /// The scalar as a list of little-endian words.
+    pub scalar: Vec<u64>,
+    /// Whether the scalar is in Montgomery form, see [`create_ec_montgomery_scalar_mul_event`].
+    ///
+    /// [`create_ec_montgomery_scalar_mul_event`]: super::create_ec_montgomery_scalar_mul_event
+    pub scalar_montgomery: bool,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the scalar.
+    pub scalar_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveScalarMulEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.scalar_memory_records).chain(writes(&self.p_memory_records))
+    }
+}
+
+/// Elliptic Curve Multi-Scalar Multiplication Event.
+///
+/// This event is emitted when an elliptic curve multi-scalar multiplication is performed. See
+/// [`create_ec_msm_event`] for the memory layout.
+///
+/// [`create_ec_msm_event`]: super::create_ec_msm_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveMsmEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the result point.
+    pub result_ptr: u64,
+    /// The pointer to the input buffer.
+    pub input_ptr: u64,
+    /// The number of point and scalar pairs.
+    pub n: u64,
+    /// The points as a flat list of words, one point after another.
+    pub points: Vec<u64>,
+    /// The scalars as a flat list of little-endian words, one scalar after another.
+    pub scalars: Vec<u64>,
+    /// The memory record for the header word holding `n`.
+    pub n_memory_record: MemoryReadRecord,
+    /// The memory records for the points, one point after another.
+    pub points_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the scalars, one scalar after another.
+    pub scalars_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the result point.
+    pub result_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveMsmEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(std::slice::from_ref(&self.n_memory_record))
+            .chain(reads(&self.points_memory_records))
+            .chain(reads(&self.scalars_memory_records))
+            .chain(writes(&self.result_memory_records))
+    }
+}
+
+/// Elliptic Curve Batched Add Event.
+///
+/// This event is emitted when a contiguous list of elliptic curve points is summed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveAddBatchEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the first point.
+    pub base_ptr: u64,
+    /// The pointer to the result point.
+    pub result_ptr: u64,
+    /// The number of points.
+    pub count: u64,
+    /// The points as a flat list of words, one point after another.
+    pub points: Vec<u64>,
+    /// The sum of the points as a list of words.
+    pub result: Vec<u64>,
+    /// The memory records for the points, one point after another.
+    pub points_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the result point.
+    pub result_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveAddBatchEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.points_memory_records).chain(writes(&self.result_memory_records))
+    }
+}
+
+/// Elliptic Curve Subgroup Check Event.
+///
+/// This event is emitted when an elliptic curve point is checked for membership in the prime-order
+/// subgroup.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveSubgroupCheckEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the result word.
+    pub result_ptr: u64,
+    /// Whether the point is in the prime-order subgroup.
+    pub in_subgroup: bool,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result word.
+    pub result_memory_record: MemoryWriteRecord,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveSubgroupCheckEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records)
+            .chain(writes(std::slice::from_ref(&self.result_memory_record)))
+    }
+}
+
+/// The status word written by [`create_ec_load_validate_event`].
+///
+/// [`create_ec_load_validate_event`]: super::create_ec_load_validate_event
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, DeepSizeOf)]
+#[repr(u64)]
+pub enum EcValidationStatus {
+    /// The point is on the curve and, if checked, in the prime-order subgroup. Written as 0.
+    #[default]
+    Valid = 0,
+    /// The point does not lie on the curve. Written as 1.
+    NotOnCurve = 1,
+    /// The point lies on the curve but not in the prime-order subgroup. Written as 2.
+    NotInSubgroup = 2,
+}
+
+/// Elliptic Curve Load Validate Event.
+///
+/// This event is emitted when an elliptic curve point is read and validated.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveLoadValidateEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the point.
+    pub p_ptr: u64,
+    /// The point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the status word.
+    pub result_ptr: u64,
+    /// Whether membership in the prime-order subgroup was checked.
+    pub check_subgroup: bool,
+    /// The status written to the result word.
+    pub status: EcValidationStatus,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the status word.
+    pub result_memory_record: MemoryWriteRecord,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveLoadValidateEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records)
+            .chain(writes(std::slice::from_ref(&self.result_memory_record)))
+    }
+}
+
+/// Elliptic Curve Equals Event.
+///
+/// This event is emitted when two elliptic curve points are compared for equality.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveEqualsEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the first point.
+    pub p_ptr: u64,
+    /// The first point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the second point.
+    pub q_ptr: u64,
+    /// The second point as a list of words.
+    pub q: Vec<u64>,
+    /// The pointer to the result word.
+    pub result_ptr: u64,
+    /// Whether the points are equal.
+    pub equal: bool,
+    /// The memory records for the first point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the second point.
+    pub q_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result word.
+    pub result_memory_record: MemoryWriteRecord,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveEqualsEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records)
+            .chain(reads(&self.q_memory_records))
+            .chain(writes(std::slice::from_ref(&self.result_memory_record)))
+    }
+}
+
+/// Elliptic Curve Is Negation Event.
+///
+/// This event is emitted when two elliptic curve points are checked to be negatives of each other.
+/// See [`create_ec_is_negation_event`].
+///
+/// [`create_ec_is_negation_event`]: super::create_ec_is_negation_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EllipticCurveIsNegationEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the first point.
+    pub p_ptr: u64,
+    /// The first point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the second point.
+    pub q_ptr: u64,
+    /// The second point as a list of words.
+    pub q: Vec<u64>,
+    /// The pointer to the result word.
+    pub result_ptr: u64,
+    /// Whether the second point is the negation of the first.
+    pub negation: bool,
+    /// The memory records for the first point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the second point.
+    pub q_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the result word.
+    pub result_memory_record: MemoryWriteRecord,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EllipticCurveIsNegationEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records)
+            .chain(reads(&self.q_memory_records))
+            .chain(writes(std::slice::from_ref(&self.result_memory_record)))
+    }
+}
+
+/// Field Inverse Event.
+///
+/// This event is emitted when an element of an elliptic curve base field is inverted.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct FieldInverseEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the field element.
+    pub ptr: u64,
+    /// The field element as a list of little-endian words.
+    pub x: Vec<u64>,
+    /// The inverse as a list of little-endian words.
+    pub inverse: Vec<u64>,
+    /// The memory records for the field element, which is overwritten in place with its inverse:
+    /// each record reads a word of `x` and writes the word of `inverse`.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for FieldInverseEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.x_memory_records)
+    }
+}
+
+/// Scalar Reduce Event.
+///
+/// This event is emitted when a scalar of any width is reduced modulo the order of the prime-order
+/// group of a curve in place. See [`create_scalar_reduce_event`].
+///
+/// [`create_scalar_reduce_event`]: super::create_scalar_reduce_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct ScalarReduceEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the scalar.
+    pub ptr: u64,
+    /// The scalar as a list of little-endian words.
+    pub scalar: Vec<u64>,
+    /// The reduced scalar as a list of little-endian words, as wide as the scalar.
+    pub reduced: Vec<u64>,
+    /// The memory records for the scalar.
+    pub scalar_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// Write slice page prot access records.
+    pub write_slice_page_prot_access: Vec<PageProtRecord>,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for ScalarReduceEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        writes(&self.scalar_memory_records)
+    }
+}
+
+/// Ecrecover Event.
+///
+/// This event is emitted when a secp256k1 public key is recovered from an ECDSA signature. See
+/// [`create_ecrecover_event`] for the memory layout.
+///
+/// [`create_ecrecover_event`]: super::create_ecrecover_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct EcrecoverEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the recovered public key.
+    pub result_ptr: u64,
+    /// The pointer to the input buffer.
+    pub input_ptr: u64,
+    /// The message hash as a list of words.
+    pub hash: Vec<u64>,
+    /// The `r` component of the signature as a list of words.
+    pub r: Vec<u64>,
+    /// The `s` component of the signature as a list of words.
+    pub s: Vec<u64>,
+    /// The recovery id.
+    pub recovery_id: u64,
+    /// The recovered public key as a list of words, all zero if the signature is invalid.
+    pub public_key: Vec<u64>,
+    /// Whether the signature is valid.
+    pub valid: bool,
+    /// The memory records for the input buffer.
+    pub input_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the public key.
+    pub result_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for EcrecoverEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.input_memory_records).chain(writes(&self.result_memory_records))
+    }
+}
+
+/// Miller Step Event.
+///
+/// This event is emitted when a BLS12-381 Miller loop doubling step is performed. See
+/// [`create_miller_step_event`] for the memory layout.
+///
+/// [`create_miller_step_event`]: super::create_miller_step_event
+#[derive(Default, Debug, Clone, Serialize, Deserialize, DeepSizeOf)]
+pub struct MillerStepEvent {
+    /// The clock cycle.
+    pub clk: u64,
+    /// The id of the syscall that emitted the event.
+    pub syscall_id: u32,
+    /// The pointer to the accumulator point.
+    pub t_ptr: u64,
+    /// The accumulator point as a list of words.
+    pub t: Vec<u64>,
+    /// The pointer to the G1 point the line is evaluated at.
+    pub p_ptr: u64,
+    /// The G1 point as a list of words.
+    pub p: Vec<u64>,
+    /// The pointer to the line.
+    pub line_ptr: u64,
+    /// The line as a list of words.
+    pub line: Vec<u64>,
+    /// The memory records for the accumulator point.
+    pub t_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the G1 point.
+    pub p_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the line.
+    pub line_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// The page prot records.
+    pub page_prot_records: EllipticCurvePageProtRecords,
+    /// The local page prot access records.
+    pub local_page_prot_access: Vec<PageProtLocalEvent>,
+    /// A checksum of the rest of the event, set when [`ExecutorConfig::EC_EVENT_CHECKSUMS`] is
+    /// enabled. See [`EcEventChecksum`].
+    pub checksum: Option<u64>,
+}
+
+impl EcEvent for MillerStepEvent {
+    fn local_mem_access(&self) -> &[MemoryLocalEvent] {
+        &self.local_mem_access
+    }
+
+    fn local_page_prot_access(&self) -> &[PageProtLocalEvent] {
+        &self.local_page_prot_access
+    }
+
+    fn memory_records(&self) -> impl Iterator<Item = MemoryRecordRef<'_>> {
+        reads(&self.p_memory_records)
+            .chain(writes(&self.t_memory_records))
+            .chain(writes(&self.line_memory_records))
+    }
+}
+
+impl_event_sizes! {
+    EllipticCurveAddEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.q_ptr, e.q.len())],
+        heap: [
+            p, q, p_memory_records, q_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveAddToEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.q_ptr, e.q.len()), (e.out_ptr, e.result.len())],
+        heap: [
+            p, q, result, p_memory_records, q_memory_records, out_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveAddBytesEvent {
+        pages: |e| [
+            (covering_words(e.p_ptr, e.len).0, e.p_memory_records.len()),
+            (covering_words(e.q_ptr, e.len).0, e.q_memory_records.len()),
+        ],
+        heap: [
+            p, q, p_memory_records, q_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveDoubleEvent {
+        pages: |e| [(e.p_ptr, e.p.len())],
+        heap: [
+            p, p_memory_records, local_mem_access, write_slice_page_prot_access,
+            local_page_prot_access, jacobian_z_words,
+        ],
+    }
+
+    EllipticCurveRepeatedDoubleEvent {
+        pages: |e| [(e.p_ptr, e.p.len())],
+        heap: [
+            p, p_memory_records, local_mem_access, write_slice_page_prot_access,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveNoopEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.q_ptr, e.q.len())],
+        heap: [
+            p, q, p_memory_records, q_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveAddProjectiveEvent {
+        pages: |e| [(e.acc_ptr, e.acc.len()), (e.q_ptr, e.q.len())],
+        heap: [
+            acc, q, acc_memory_records, q_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveNormalizeProjectiveEvent {
+        pages: |e| [(e.acc_ptr, e.acc.len()), (e.result_ptr, e.result.len())],
+        heap: [
+            acc, result, acc_memory_records, result_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveToJacobianEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.result_ptr, e.result.len())],
+        heap: [
+            p, result, p_memory_records, result_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveNegateEvent {
+        pages: |e| [(e.p_ptr, e.p.len())],
+        heap: [
+            p, p_memory_records, local_mem_access, write_slice_page_prot_access,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurvePointAndNegateEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.neg_ptr, e.neg.len())],
+        heap: [
+            p, neg, p_memory_records, neg_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveLadderStepEvent {
+        pages: |e| [(e.ptr, e.points.len())],
+        heap: [
+            points, result, memory_records, local_mem_access, write_slice_page_prot_access,
+            local_page_prot_access,
+        ],
+    }
+
+    G2PsiEvent {
+        pages: |e| [(e.p_ptr, e.p.len())],
+        heap: [
+            p, p_memory_records, local_mem_access, write_slice_page_prot_access,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveClearCofactorEvent {
+        pages: |e| [(e.p_ptr, e.p.len())],
+        heap: [
+            p, p_memory_records, local_mem_access, write_slice_page_prot_access,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveHashToCurveEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.u_ptr, e.u.len())],
+        heap: [
+            p, u, p_memory_records, u_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveDecompressEvent {
+        pages: |e| [
+            (e.ptr, e.y_memory_records.len()),
+            (e.ptr + e.x_bytes.len() as u64, e.x_memory_records.len()),
+        ],
+        heap: [
+            x_bytes, decompressed_y_bytes, x_memory_records, y_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveDecompressMemSignEvent {
+        pages: |e| [
+            (e.decompress.ptr, e.decompress.y_memory_records.len()),
+            (
+                e.decompress.ptr + e.decompress.x_bytes.len() as u64,
+                e.decompress.x_memory_records.len(),
+            ),
+            (e.sign_ptr, 1),
+        ],
+        heap: [decompress],
+    }
+
+    EllipticCurveDecompressBatchEvent {
+        pages: |e| [
+            (e.input_ptr, 1 + e.sign_bits.len() + e.x_words.len()),
+            (e.output_ptr, 1 + e.points.len()),
+        ],
+        heap: [
+            sign_bits, x_words, points, sign_bits_memory_records, x_memory_records,
+            points_memory_records, local_mem_access, page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveSec1DecompressEvent {
+        pages: |e| [
+            (
+                covering_words(e.compressed_ptr, e.compressed.len() as u64).0,
+                e.compressed_memory_records.len(),
+            ),
+            (
+                covering_words(e.output_ptr, e.uncompressed.len() as u64).0,
+                e.uncompressed_memory_records.len(),
+            ),
+        ],
+        heap: [
+            compressed, uncompressed, compressed_memory_records, uncompressed_memory_records,
+            local_mem_access, page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EdwardsDecompressEvent {
+        pages: |e| [(e.compressed_ptr, e.compressed.len()), (e.output_ptr, 1 + e.point.len())],
+        heap: [
+            compressed, point, compressed_memory_records, point_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    G2DecompressEvent {
+        pages: |e| [(e.compressed_ptr, e.compressed.len()), (e.output_ptr, 1 + e.point.len())],
+        heap: [
+            compressed, point, compressed_memory_records, point_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    BlsDeserializeEvent {
+        pages: |e| [
+            (e.g1_ptr, e.g1_compressed.len()),
+            (e.g2_ptr, e.g2_compressed.len()),
+            (e.output_ptr, 1 + e.g1_point.len() + e.g2_point.len()),
+        ],
+        heap: [
+            g1_compressed, g2_compressed, g1_point, g2_point, g1_memory_records, g2_memory_records,
+            point_memory_records, local_mem_access, page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveCompressEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.compressed_ptr, e.compressed_memory_records.len())],
+        heap: [
+            p, compressed, p_memory_records, compressed_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveScalarMulEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.scalar_ptr, e.scalar.len())],
+        heap: [
+            p, scalar, p_memory_records, scalar_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveMsmEvent {
+        // The input buffer holds `n`, then the points, then the scalars.
+        pages: |e| [
+            (e.input_ptr, 1 + e.points.len() + e.scalars.len()),
+            (e.result_ptr, e.result_memory_records.len()),
+        ],
+        heap: [
+            points, scalars, points_memory_records, scalars_memory_records, result_memory_records,
+            local_mem_access, page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveAddBatchEvent {
+        pages: |e| [(e.base_ptr, e.points.len()), (e.result_ptr, e.result_memory_records.len())],
+        heap: [
+            points, result, points_memory_records, result_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveSubgroupCheckEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.result_ptr, 1)],
+        heap: [p, p_memory_records, local_mem_access, page_prot_records, local_page_prot_access],
+    }
+
+    EllipticCurveLoadValidateEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.result_ptr, 1)],
+        heap: [p, p_memory_records, local_mem_access, page_prot_records, local_page_prot_access],
+    }
+
+    EllipticCurveEqualsEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.q_ptr, e.q.len()), (e.result_ptr, 1)],
+        heap: [
+            p, q, p_memory_records, q_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    EllipticCurveIsNegationEvent {
+        pages: |e| [(e.p_ptr, e.p.len()), (e.q_ptr, e.q.len()), (e.result_ptr, 1)],
+        heap: [
+            p, q, p_memory_records, q_memory_records, local_mem_access, page_prot_records,
+            local_page_prot_access,
+        ],
+    }
+
+    FieldInverseEvent {
+        pages: |e| [(e.ptr, e.x.len())],
+        heap: [
+            x, inverse, x_memory_records, local_mem_access, write_slice_page_prot_access,
+            local_page_prot_access,
+        ],
+    }
+
+    ScalarReduceEvent {
+        pages: |e| [(e.ptr, e.scalar.len())],
+        heap: [
+            scalar, reduced, scalar_memory_records, local_mem_access, write_slice_page_prot_access,
+            local_page_prot_access,
+        ],
+    }
+
+    EcrecoverEvent {
+        pages: |e| [
+            (e.input_ptr, e.input_memory_records.len()),
+            (e.result_ptr, e.result_memory_records.len()),
+        ],
+        heap: [
+            hash, r, s, public_key, input_memory_records, result_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+
+    MillerStepEvent {
+        pages: |e| [(e.t_ptr, e.t.len()), (e.p_ptr, e.p.len()), (e.line_ptr, e.line.len())],
+        heap: [
+            t, p, line, t_memory_records, p_memory_records, line_memory_records, local_mem_access,
+            page_prot_records, local_page_prot_access,
+        ],
+    }
+}
@@ -0,0 +1,867 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::Serialize;
+
+use sp1_curves::{
+    edwards::{
+        ed25519::{ed25519_sqrt, Ed25519Parameters},
+        jubjub::{jubjub_decompress, jubjub_sqrt},
+        EdwardsParameters,
+    },
+    params::{FieldParameters, NumWords},
+    registry::CustomCurve,
+    utils::sqrt_3mod4,
+    weierstrass::{
+        bls12_381::{
+            bls12381_decompress, bls12381_g2_decompress, Bls12381, Bls12381BaseField,
+            Bls12381G2Parameters, COMPRESSION_FLAG, INFINITY_FLAG, Y_IS_ODD_FLAG,
+        },
+        bn254::bn254_decompress,
+        g2::{Fp2, G2AffinePoint, G2Parameters},
+        secp256k1::{
+            secp256k1_decompress, secp256k1_glv_beta, secp256k1_glv_decompose, Secp256k1,
+            Secp256k1BaseField, Secp256k1Parameters,
+        },
+        secp256r1::secp256r1_decompress,
+        sswu::bls12381_map_to_curve,
+        WeierstrassParameters,
+    },
+    AffinePoint, BigUint, CurveType, EcOp, EllipticCurve, IdentityEncoding,
+};
+use sp1_primitives::consts::{bytes_to_words_le_vec, PAGE_SIZE};
+use typenum::Unsigned;
+
+use super::events::EcValidationStatus;
+use crate::{
+    events::{
+        memory::{MemoryReadRecord, MemoryWriteRecord},
+        EcSyscallError, PrecompileEvent,
+    },
+    syscalls::SyscallCode,
+};
+
+/// Counters of notable elliptic curve precompile outcomes, for observability when handling
+/// adversarial inputs. They never affect the events or the memory written.
+#[derive(Debug, Default)]
+pub struct EcStats {
+    decompress_non_residues: AtomicU64,
+}
+
+impl EcStats {
+    /// Returns the number of decompressed coordinates that failed the square root test, whether
+    /// the decompression returned an error or wrote a failure status.
+    #[must_use]
+    pub fn decompress_non_residues(&self) -> u64 {
+        self.decompress_non_residues.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record_decompress_non_residue(&self) {
+        self.decompress_non_residues.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// A pool of reusable vectors for the memory records and words of syscall slice accesses, kept on
+/// the [`Executor`] to spare the allocator under heavy elliptic curve precompile load.
+///
+/// When [`ExecutorConfig::POOL_EC_RECORDS`] is set, [`SyscallContext::mr_slice`],
+/// [`SyscallContext::mw_slice`] and [`SyscallContext::slice_unsafe`] draw their vectors from the
+/// pool instead of allocating them. The vectors of the add, double and decompress events go back
+/// to the pool with [`Self::recycle`]: as soon as the event is created in modes that do not keep
+/// events, and otherwise when the events of a shard are handed back with
+/// [`Executor::recycle_ec_events`]. The events still own plain `Vec`s, so nothing changes for their
+/// readers.
+///
+/// [`Executor`]: crate::Executor
+/// [`Executor::recycle_ec_events`]: crate::Executor::recycle_ec_events
+/// [`ExecutorConfig::POOL_EC_RECORDS`]: crate::ExecutorConfig::POOL_EC_RECORDS
+/// [`SyscallContext::mr_slice`]: crate::syscalls::SyscallContext::mr_slice
+/// [`SyscallContext::mw_slice`]: crate::syscalls::SyscallContext::mw_slice
+/// [`SyscallContext::slice_unsafe`]: crate::syscalls::SyscallContext::slice_unsafe
+#[derive(Debug, Default)]
+pub struct EcRecordPool {
+    reads: Vec<Vec<MemoryReadRecord>>,
+    writes: Vec<Vec<MemoryWriteRecord>>,
+    words: Vec<Vec<u64>>,
+    takes: u64,
+    allocations: u64,
+}
+
+impl EcRecordPool {
+    /// Returns the number of vectors drawn from the pool, each of which would be an allocation
+    /// without it.
+    #[must_use]
+    pub fn takes(&self) -> u64 {
+        self.takes
+    }
+
+    /// Returns the number of vectors drawn from the pool that had to be allocated or grown because
+    /// no pooled vector was large enough.
+    #[must_use]
+    pub fn allocations(&self) -> u64 {
+        self.allocations
+    }
+
+    /// Returns the number of vectors held by the pool.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.reads.len() + self.writes.len() + self.words.len()
+    }
+
+    /// Returns whether the pool holds no vectors.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops the pooled vectors, keeping the counters.
+    pub fn clear(&mut self) {
+        self.reads.clear();
+        self.writes.clear();
+        self.words.clear();
+    }
+
+    pub(crate) fn take_reads(&mut self, len: usize) -> Vec<MemoryReadRecord> {
+        take_pooled(&mut self.reads, len, &mut self.takes, &mut self.allocations)
+    }
+
+    pub(crate) fn take_writes(&mut self, len: usize) -> Vec<MemoryWriteRecord> {
+        take_pooled(&mut self.writes, len, &mut self.takes, &mut self.allocations)
+    }
+
+    pub(crate) fn take_words(&mut self, len: usize) -> Vec<u64> {
+        take_pooled(&mut self.words, len, &mut self.takes, &mut self.allocations)
+    }
+
+    /// Returns the vectors of an event to the pool. Only the add, double and decompress events of
+    /// the curves are recycled; other events are dropped.
+    pub fn recycle(&mut self, event: PrecompileEvent) {
+        match event {
+            PrecompileEvent::EdAdd(event)
+            | PrecompileEvent::Secp256k1Add(event)
+            | PrecompileEvent::Secp256r1Add(event)
+            | PrecompileEvent::Bn254Add(event)
+            | PrecompileEvent::Bls12381Add(event) => {
+                self.words.extend([event.p, event.q]);
+                self.writes.push(event.p_memory_records);
+                self.reads.push(event.q_memory_records);
+            }
+            PrecompileEvent::Secp256k1Double(event)
+            | PrecompileEvent::Secp256r1Double(event)
+            | PrecompileEvent::Bn254Double(event)
+            | PrecompileEvent::Bls12381Double(event) => {
+                self.words.push(event.p);
+                self.writes.push(event.p_memory_records);
+            }
+            PrecompileEvent::Secp256k1Decompress(event)
+            | PrecompileEvent::Secp256r1Decompress(event)
+            | PrecompileEvent::K256Decompress(event)
+            | PrecompileEvent::Bls12381Decompress(event) => {
+                self.reads.push(event.x_memory_records);
+                self.writes.push(event.y_memory_records);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Pops a vector of at least `len` elements of capacity from `pool`, cleared, allocating one if the
+/// pool is empty.
+fn take_pooled<T>(
+    pool: &mut Vec<Vec<T>>,
+    len: usize,
+    takes: &mut u64,
+    allocations: &mut u64,
+) -> Vec<T> {
+    *takes += 1;
+    let mut vec = pool.pop().unwrap_or_default();
+    vec.clear();
+    if vec.capacity() < len {
+        *allocations += 1;
+        vec.reserve_exact(len);
+    }
+    vec
+}
+
+/// A buffered sink that writes each elliptic curve precompile event to a file as one JSON line, for
+/// debugging divergences between the executor and a reference implementation.
+///
+/// Each line is an object with the `curve` and `op` of the syscall, the `syscall` code, and the
+/// serialized `event`. Lines are written out when the buffer fills, and when the sink is flushed or
+/// dropped. See [`ExecutorConfig::EC_EVENT_TRACE_PATH`].
+///
+/// [`ExecutorConfig::EC_EVENT_TRACE_PATH`]: crate::ExecutorConfig::EC_EVENT_TRACE_PATH
+#[derive(Debug)]
+pub struct EcEventSink {
+    writer: BufWriter<File>,
+}
+
+/// A line of an [`EcEventSink`].
+#[derive(Serialize)]
+struct EcEventLine<'a> {
+    curve: String,
+    op: String,
+    syscall: String,
+    event: &'a PrecompileEvent,
+}
+
+impl EcEventSink {
+    /// Creates the file at `path`, truncating it if it exists.
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self { writer: BufWriter::new(File::create(path)?) })
+    }
+
+    /// Writes an event as one line. Events of syscalls that are not elliptic curve operations are
+    /// skipped.
+    pub fn write_event(
+        &mut self,
+        syscall_code: SyscallCode,
+        event: &PrecompileEvent,
+    ) -> std::io::Result<()> {
+        let code = syscall_code as u32;
+        let (Some(curve), Some(op)) =
+            (CurveType::from_syscall_code(code), EcOp::from_syscall_code(code))
+        else {
+            return Ok(());
+        };
+        let line = EcEventLine {
+            curve: curve.to_string(),
+            op: format!("{op:?}"),
+            syscall: format!("{syscall_code:?}"),
+            event,
+        };
+        serde_json::to_writer(&mut self.writer, &line)?;
+        self.writer.write_all(b"\n")
+    }
+
+    /// Writes out the buffered lines.
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Splits the words of a point into those of its x and y coordinates, which are laid out x first.
+pub(super) fn coordinate_words(words: &[u64]) -> (&[u64], &[u64]) {
+    words.split_at(words.len() / 2)
+}
+
+/// Counts the distinct pages spanned by a set of `(ptr, num_words)` memory ranges.
+///
+/// [`PageProtRecord`]s do not record their page, so the pages are derived from the accessed
+/// ranges. This also works when page protection is disabled and no records are produced.
+///
+/// [`PageProtRecord`]: crate::events::PageProtRecord
+pub(super) fn count_pages(ranges: &[(u64, usize)]) -> usize {
+    let page_size = PAGE_SIZE as u64;
+    let mut pages: Vec<u64> = ranges
+        .iter()
+        .filter(|(_, num_words)| *num_words > 0)
+        .flat_map(|&(ptr, num_words)| {
+            ptr / page_size..=(ptr + 8 * num_words as u64 - 1) / page_size
+        })
+        .collect();
+    pages.sort_unstable();
+    pages.dedup();
+    pages.len()
+}
+
+/// Returns an [`EcSyscallError::ExceptionalOperands`] error unless the points encoded by `p` and
+/// `q` are finite and have different `x` coordinates modulo the base field, the operands for which
+/// the chord formula is defined.
+pub(super) fn check_chord_operands<E: EllipticCurve>(
+    p: &[u64],
+    q: &[u64],
+) -> Result<(), EcSyscallError> {
+    let modulus = E::BaseField::modulus();
+    match (decode_point::<E>(p), decode_point::<E>(q)) {
+        (Some(p), Some(q)) if &p.x % &modulus != &q.x % &modulus => Ok(()),
+        _ => Err(EcSyscallError::ExceptionalOperands),
+    }
+}
+
+/// Returns an [`EcSyscallError::ExceptionalOperands`] error unless the point encoded by `p` is
+/// finite and has a nonzero `y` coordinate modulo the base field, the operands for which the
+/// tangent formula is defined.
+pub(super) fn check_tangent_operand<E: EllipticCurve>(p: &[u64]) -> Result<(), EcSyscallError> {
+    match decode_point::<E>(p) {
+        Some(p) if &p.y % E::BaseField::modulus() != BigUint::ZERO => Ok(()),
+        _ => Err(EcSyscallError::ExceptionalOperands),
+    }
+}
+
+/// Returns the 8-byte aligned pointer and the number of words of the shortest run of words
+/// covering the `len` bytes at `ptr`.
+pub(super) fn covering_words(ptr: u64, len: u64) -> (u64, usize) {
+    let start = ptr & !7;
+    let end = (ptr + len).next_multiple_of(8);
+    (start, ((end - start) / 8) as usize)
+}
+
+/// Returns whether two ranges of `num_words` words starting at `a` and `b` share a word.
+pub(super) fn ranges_overlap(a: u64, b: u64, num_words: usize) -> bool {
+    a.abs_diff(b) < 8 * num_words as u64
+}
+
+/// A function mapping a base field element to a curve point, returning `None` for the point at
+/// infinity.
+pub type MapToCurveFn<E> = fn(&BigUint) -> Option<AffinePoint<E>>;
+
+/// Returns the map used by [`create_hash_to_curve_event`] for the curve, or `None` if the event
+/// does not support it.
+///
+/// [`create_hash_to_curve_event`]: super::create_hash_to_curve_event
+#[must_use]
+pub fn map_to_curve_for_curve<E: EllipticCurve>() -> Option<MapToCurveFn<E>> {
+    match E::CURVE_TYPE {
+        CurveType::Bls12381 => Some(bls12381_map_to_curve::<E>),
+        _ => None,
+    }
+}
+
+/// Converts a scalar from the Montgomery form of [`create_ec_montgomery_scalar_mul_event`], modulo
+/// the group order `n`.
+///
+/// [`create_ec_montgomery_scalar_mul_event`]: super::create_ec_montgomery_scalar_mul_event
+pub(super) fn from_montgomery(scalar: &BigUint, n: &BigUint) -> BigUint {
+    let r = BigUint::from(1u32) << (64 * n.bits().div_ceil(64));
+    let r_inv = (r % n).modpow(&(n - 2u32), n);
+    (scalar * r_inv) % n
+}
+
+/// Decodes a point from its words, returning `None` for the point at infinity.
+///
+/// With [`IdentityEncoding::Zero`], the point at infinity is encoded as all-zero words. With
+/// [`IdentityEncoding::Neutral`], as on twisted Edwards curves, the identity is an ordinary affine
+/// point and every encoding decodes to `Some`.
+pub(super) fn decode_point<E: EllipticCurve>(words: &[u64]) -> Option<AffinePoint<E>> {
+    finite_point(AffinePoint::from_memory_words(words))
+}
+
+/// Maps a point read from memory to `None` if it encodes the point at infinity, as in
+/// [`decode_point`].
+pub(super) fn finite_point<E: EllipticCurve>(point: AffinePoint<E>) -> Option<AffinePoint<E>> {
+    if E::IDENTITY_ENCODING == IdentityEncoding::Zero
+        && point.x == BigUint::ZERO
+        && point.y == BigUint::ZERO
+    {
+        None
+    } else {
+        Some(point)
+    }
+}
+
+/// Returns whether an affine point is the encoding of the identity given by
+/// [`EllipticCurve::IDENTITY_ENCODING`].
+pub(super) fn is_identity<E: EllipticCurve>(point: &AffinePoint<E>) -> bool {
+    let identity = identity_point::<E>();
+    point.x == identity.x && point.y == identity.y
+}
+
+/// Returns the encoding of the identity given by [`EllipticCurve::IDENTITY_ENCODING`].
+fn identity_point<E: EllipticCurve>() -> AffinePoint<E> {
+    match E::IDENTITY_ENCODING {
+        IdentityEncoding::Zero => AffinePoint::new(BigUint::ZERO, BigUint::ZERO),
+        IdentityEncoding::Neutral => {
+            E::ec_neutral().expect("a curve with an affine identity encoding has a neutral point")
+        }
+    }
+}
+
+/// Asserts, in debug builds, that a result computed from points on the curve still decodes to a
+/// point on the curve, or to the identity, once encoded as words.
+///
+/// Results of inputs off the curve are meaningless and are not checked.
+pub(super) fn debug_assert_result_on_curve<E: EllipticCurve>(
+    inputs_on_curve: bool,
+    result: &AffinePoint<E>,
+) {
+    if cfg!(debug_assertions) && inputs_on_curve {
+        let decoded = AffinePoint::<E>::from_memory_words(&result.to_memory_words());
+        debug_assert!(
+            is_identity(&decoded) || E::ec_is_on_curve(&decoded),
+            "result of an operation on points on the curve is not on the curve"
+        );
+    }
+}
+
+/// Maps `None` to the encoding of the identity, the inverse of [`finite_point`].
+pub(super) fn encode_point<E: EllipticCurve>(point: Option<AffinePoint<E>>) -> AffinePoint<E> {
+    point.unwrap_or_else(identity_point)
+}
+
+/// Decodes a point over `Fp2` from its words, returning `None` for the all-zero point at infinity.
+pub(super) fn decode_g2_point<G: G2Parameters>(words: &[u64]) -> Option<G2AffinePoint<G>> {
+    if words.iter().all(|&word| word == 0) {
+        None
+    } else {
+        Some(G2AffinePoint::from_words_le(words))
+    }
+}
+
+/// Encodes a point over `Fp2`, the inverse of [`decode_g2_point`].
+pub(super) fn encode_g2_point<G: G2Parameters>(point: Option<&G2AffinePoint<G>>) -> Vec<u64> {
+    point.map_or_else(|| vec![0; G2AffinePoint::<G>::num_words()], G2AffinePoint::to_words_le)
+}
+
+/// Returns an [`EcSyscallError::NotOnCurve`] error if the point is not on the curve. The point at
+/// infinity, given as `None`, is always valid.
+pub(super) fn check_on_curve<E: EllipticCurve>(
+    point: Option<&AffinePoint<E>>,
+) -> Result<(), EcSyscallError> {
+    match point {
+        Some(point) if !E::ec_is_on_curve(point) => Err(EcSyscallError::NotOnCurve),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the square root of `y^2` that the decompression of the curve starts from, which is
+/// either `y` or `-y`, or `None` if a custom curve finds no root.
+///
+/// A [`CurveType::Custom`] curve computes it with [`CustomCurve::sqrt`]. Every short Weierstrass
+/// curve of this crate in [`decompress_for_curve`] has a base field modulus `p = 3 mod 4`, whose
+/// root is [`sqrt_3mod4`].
+pub(super) fn canonical_root<E: EllipticCurve>(
+    custom: Option<&dyn CustomCurve>,
+    y: &BigUint,
+) -> Option<BigUint> {
+    let modulus = E::BaseField::modulus();
+    let square = (y * y) % &modulus;
+    match custom {
+        Some(curve) => curve.sqrt(&square),
+        None => sqrt_3mod4(&square, &modulus),
+    }
+}
+
+/// Returns an [`EcSyscallError::EmptyPoint`] error if a curve is configured with points or field
+/// elements of `num_words = 0` words, which would read nothing from memory.
+pub(super) fn check_num_words(num_words: usize) -> Result<(), EcSyscallError> {
+    if num_words > 0 {
+        Ok(())
+    } else {
+        Err(EcSyscallError::EmptyPoint)
+    }
+}
+
+/// Returns an [`EcSyscallError::Unaligned`] error if `ptr` is not 8-byte aligned.
+pub(super) fn check_aligned(name: &'static str, ptr: u64) -> Result<(), EcSyscallError> {
+    if ptr.is_multiple_of(8) {
+        Ok(())
+    } else {
+        Err(EcSyscallError::Unaligned { name, ptr })
+    }
+}
+
+/// Returns whether a point read from memory is in the prime-order subgroup. The point at infinity
+/// is in the subgroup.
+pub(super) fn in_prime_subgroup<E: EllipticCurve + WeierstrassParameters>(
+    point: AffinePoint<E>,
+) -> bool {
+    finite_point(point)
+        .and_then(|p_affine| ec_scalar_mul(&p_affine, &E::prime_group_order()))
+        .is_none()
+}
+
+/// Recovers the public key `Q = r^-1 * (sR - zG)` from an ECDSA signature over secp256k1, returning
+/// `None` if the signature is invalid.
+///
+/// The signature is invalid if `r` or `s` is not in `[1, n)`, the recovery id is greater than 3,
+/// there is no point `R` with the encoded `x` coordinate, or `Q` is the point at infinity. The hash
+/// is reduced modulo `n`.
+pub(super) fn ecrecover(
+    hash: &BigUint,
+    r: &BigUint,
+    s: &BigUint,
+    recovery_id: u64,
+) -> Option<AffinePoint<Secp256k1>> {
+    let n = Secp256k1Parameters::prime_group_order();
+    let p = Secp256k1BaseField::modulus();
+    let zero = BigUint::ZERO;
+    if recovery_id > 3 || *r == zero || *s == zero || *r >= n || *s >= n {
+        return None;
+    }
+
+    let x = if recovery_id & 2 == 0 { r.clone() } else { r + &n };
+    if x >= p {
+        return None;
+    }
+    let y_squared = (&x * &x * &x + Secp256k1Parameters::b_int()) % &p;
+    let mut y = sqrt_3mod4(&y_squared, &p)?;
+    if y.bit(0) != (recovery_id & 1 == 1) {
+        y = &p - y;
+    }
+    let big_r = AffinePoint::<Secp256k1>::new(x, y);
+
+    let r_inv = r.modpow(&(&n - 2u32), &n);
+    let u1 = ((&n - hash % &n) * &r_inv) % &n;
+    let u2 = (s * &r_inv) % &n;
+    let u1_g = secp256k1_glv_scalar_mul(&Secp256k1::ec_generator(), &u1);
+    let u2_r = secp256k1_glv_scalar_mul(&big_r, &u2);
+    match (u1_g, u2_r) {
+        (Some(u1_g), Some(u2_r)) => ec_add_or_identity(&u1_g, &u2_r),
+        (u1_g, None) => u1_g,
+        (None, u2_r) => u2_r,
+    }
+}
+
+/// Computes `[k]P` by double-and-add, returning `None` for the point at infinity.
+pub(super) fn ec_scalar_mul<E: EllipticCurve>(
+    p: &AffinePoint<E>,
+    k: &BigUint,
+) -> Option<AffinePoint<E>> {
+    let mut result = E::ec_neutral();
+    for i in (0..k.bits()).rev() {
+        result = result.and_then(|r| ec_double_or_identity(&r));
+        if k.bit(i) {
+            result = match result {
+                Some(r) => ec_add_or_identity(&r, p),
+                None => Some(p.clone()),
+            };
+        }
+    }
+    result
+}
+
+/// Computes `[k]P` on secp256k1 using the GLV endomorphism `(x, y) -> (beta * x, y)`.
+///
+/// The scalar is split as `k = k1 + k2 * lambda`, and `[k1]P + [k2](beta * x, y)` is computed with a
+/// single chain of about 128 doublings. For points on the curve this agrees with
+/// [`ec_scalar_mul`].
+pub(super) fn secp256k1_glv_scalar_mul<E: EllipticCurve>(
+    p: &AffinePoint<E>,
+    k: &BigUint,
+) -> Option<AffinePoint<E>> {
+    let modulus = E::BaseField::modulus();
+    let negate = |point: AffinePoint<E>, negative: bool| {
+        if negative {
+            AffinePoint::new(point.x, (&modulus - &point.y) % &modulus)
+        } else {
+            point
+        }
+    };
+
+    let [(k1, k1_negative), (k2, k2_negative)] = secp256k1_glv_decompose(k);
+    let p1 = negate(p.clone(), k1_negative);
+    let p2 = negate(
+        AffinePoint::new((&p.x * secp256k1_glv_beta()) % &modulus, p.y.clone()),
+        k2_negative,
+    );
+    let p1_plus_p2 = ec_add_or_identity(&p1, &p2);
+
+    let mut result = None;
+    for i in (0..k1.bits().max(k2.bits())).rev() {
+        result = result.and_then(|r| ec_double_or_identity(&r));
+        let term = match (k1.bit(i), k2.bit(i)) {
+            (true, true) => p1_plus_p2.clone(),
+            (true, false) => Some(p1.clone()),
+            (false, true) => Some(p2.clone()),
+            (false, false) => continue,
+        };
+        result = match (result, term) {
+            (Some(r), Some(term)) => ec_add_or_identity(&r, &term),
+            (r, None) => r,
+            (None, term) => term,
+        };
+    }
+    result
+}
+
+/// Adds two affine points, returning `None` if the sum is the point at infinity.
+///
+/// Unlike [`EllipticCurve::ec_add`], this handles equal and opposite operands on curves without a
+/// complete addition law.
+pub(super) fn ec_add_or_identity<E: EllipticCurve>(
+    p: &AffinePoint<E>,
+    q: &AffinePoint<E>,
+) -> Option<AffinePoint<E>> {
+    ec_add_or_identity_with(None, p, q)
+}
+
+/// Adds two affine points like [`ec_add_or_identity`], with the implementation `custom` of a
+/// [`CurveType::Custom`] curve if it is given.
+pub(super) fn ec_add_or_identity_with<E: EllipticCurve>(
+    custom: Option<&dyn CustomCurve>,
+    p: &AffinePoint<E>,
+    q: &AffinePoint<E>,
+) -> Option<AffinePoint<E>> {
+    // Curves with an affine identity have a complete addition law.
+    if E::IDENTITY_ENCODING == IdentityEncoding::Neutral {
+        return Some(ec_add_points(custom, p, q));
+    }
+    if p.x == q.x {
+        return if p.y == q.y { ec_double_or_identity_with(custom, p) } else { None };
+    }
+    Some(ec_add_points(custom, p, q))
+}
+
+/// Adds two different points with the implementation `custom` of a [`CurveType::Custom`] curve, or
+/// with [`EllipticCurve::ec_add`] if none is given.
+fn ec_add_points<E: EllipticCurve>(
+    custom: Option<&dyn CustomCurve>,
+    p: &AffinePoint<E>,
+    q: &AffinePoint<E>,
+) -> AffinePoint<E> {
+    match custom {
+        Some(curve) => {
+            let (x, y) = curve.add(&(p.x.clone(), p.y.clone()), &(q.x.clone(), q.y.clone()));
+            AffinePoint::new(x, y)
+        }
+        None => p + q,
+    }
+}
+
+/// Doubles a point with the implementation `custom` of a [`CurveType::Custom`] curve, or with
+/// [`EllipticCurve::ec_double`] if none is given.
+fn ec_double_point<E: EllipticCurve>(
+    custom: Option<&dyn CustomCurve>,
+    p: &AffinePoint<E>,
+) -> AffinePoint<E> {
+    match custom {
+        Some(curve) => {
+            let (x, y) = curve.double(&(p.x.clone(), p.y.clone()));
+            AffinePoint::new(x, y)
+        }
+        None => E::ec_double(p),
+    }
+}
+
+/// Negates an affine point, reducing the negated coordinate modulo the base field.
+pub(super) fn ec_negate<E: EllipticCurve>(p: &AffinePoint<E>) -> AffinePoint<E> {
+    let modulus = E::BaseField::modulus();
+    let negate = |c: &BigUint| (&modulus - c % &modulus) % &modulus;
+    if E::ec_neutral().is_some() {
+        AffinePoint::new(negate(&p.x), p.y.clone())
+    } else {
+        AffinePoint::new(p.x.clone(), negate(&p.y))
+    }
+}
+
+/// Doubles an affine point, returning `None` if the result is the point at infinity.
+pub(super) fn ec_double_or_identity<E: EllipticCurve>(
+    p: &AffinePoint<E>,
+) -> Option<AffinePoint<E>> {
+    ec_double_or_identity_with(None, p)
+}
+
+/// Doubles an affine point like [`ec_double_or_identity`], with the implementation `custom` of a
+/// [`CurveType::Custom`] curve if it is given.
+pub(super) fn ec_double_or_identity_with<E: EllipticCurve>(
+    custom: Option<&dyn CustomCurve>,
+    p: &AffinePoint<E>,
+) -> Option<AffinePoint<E>> {
+    if E::IDENTITY_ENCODING == IdentityEncoding::Zero && p.y == BigUint::ZERO {
+        return None;
+    }
+    Some(ec_double_point(custom, p))
+}
+
+/// Returns the words of the `Z` coordinate of the Jacobian doubling of `p` from `Z = 1`, which is
+/// `2y`, or 0 for the point at infinity.
+pub(super) fn jacobian_double_z_words<E: EllipticCurve>(p: Option<&AffinePoint<E>>) -> Vec<u64> {
+    let modulus = E::BaseField::modulus();
+    let z = p.map_or(BigUint::ZERO, |p| (&p.y * 2u32) % &modulus);
+    let mut bytes = z.to_bytes_le();
+    bytes.resize(<E::BaseField as NumWords>::WordsFieldElement::USIZE * 8, 0);
+    bytes_to_words_le_vec(&bytes)
+}
+
+/// A function recovering a point from its big-endian compressed coordinate and sign bit, returning
+/// `None` if no point has that coordinate.
+///
+/// The compressed coordinate is `x` for short Weierstrass curves and `y` for twisted Edwards curves.
+pub type DecompressFn<E> = fn(&[u8], u32) -> Option<AffinePoint<E>>;
+
+/// Returns the decompress function used by [`create_ec_decompress_event`] for the curve, or `None`
+/// if the curve is not decompressed through this event.
+///
+/// This is the single place where curves of this crate register their decompression. A
+/// [`CurveType::Custom`] curve is decompressed by [`create_ec_decompress_event`] with the
+/// implementation registered with the executor instead, so it has no function here.
+///
+/// [`create_ec_decompress_event`]: super::create_ec_decompress_event
+#[must_use]
+pub fn decompress_for_curve<E: EllipticCurve>() -> Option<DecompressFn<E>> {
+    match E::CURVE_TYPE {
+        CurveType::Secp256k1 => Some(|x, sign| Some(secp256k1_decompress::<E>(x, sign))),
+        CurveType::Secp256r1 => Some(|x, sign| Some(secp256r1_decompress::<E>(x, sign))),
+        CurveType::Bn254 => Some(bn254_decompress::<E>),
+        CurveType::Bls12381 => Some(|x, sign| Some(bls12381_decompress::<E>(x, sign))),
+        CurveType::Jubjub => Some(jubjub_decompress::<E>),
+        CurveType::Ed25519 => Some(|y, sign| {
+            let y = BigUint::from_bytes_be(y);
+            let x = edwards_recover_x::<Ed25519Parameters>(&y, sign == 1).ok()?;
+            Some(AffinePoint::new(x, y))
+        }),
+        // The Pasta curves have no compressed point format in this event.
+        CurveType::Pallas | CurveType::Vesta => None,
+        CurveType::Custom(_) => None,
+    }
+}
+
+/// The curves of this crate that the elliptic curve events support in this build.
+///
+/// No curve is behind a feature flag, so every built-in curve is compiled in. A curve gated by a
+/// feature must be gated here as well as in the arms of [`decompress_for_curve`] and the syscall
+/// dispatch.
+const SUPPORTED_CURVES: &[CurveType] = &[
+    CurveType::Secp256k1,
+    CurveType::Secp256r1,
+    CurveType::Bn254,
+    CurveType::Bls12381,
+    CurveType::Ed25519,
+    CurveType::Jubjub,
+    CurveType::Pallas,
+    CurveType::Vesta,
+];
+
+/// Returns the built-in curves compiled into this build, for a host to advertise to guests.
+///
+/// [`CurveType::Custom`] curves are not listed, as they are registered with the executor at runtime
+/// rather than compiled in.
+#[must_use]
+pub fn supported_curves() -> &'static [CurveType] {
+    SUPPORTED_CURVES
+}
+
+/// Returns the number of words holding a field element of `num_limbs` bytes.
+///
+/// This rounds up, so that a field whose limb count is not a multiple of 8 has its elements padded
+/// to a whole number of words rather than their last bytes silently dropped.
+pub(super) const fn limbs_to_words(num_limbs: usize) -> usize {
+    num_limbs.div_ceil(8)
+}
+
+/// Returns whether `x` is reduced and is the x coordinate of a point on the curve.
+///
+/// The decompress functions may panic on a non-residue, so it is ruled out beforehand with Euler's
+/// criterion.
+pub(super) fn lifts_to_point<E: EllipticCurve + WeierstrassParameters>(x: &BigUint) -> bool {
+    let modulus = E::BaseField::modulus();
+    if *x >= modulus {
+        return false;
+    }
+    let rhs = (x * x * x + E::a_int() * x + E::b_int()) % &modulus;
+    rhs == BigUint::ZERO || rhs.modpow(&((&modulus - 1u32) >> 1), &modulus) == BigUint::from(1u32)
+}
+
+/// Decodes an RFC 8032 compressed point, returning an error if the encoding is invalid.
+pub(super) fn edwards_decompress<E: EllipticCurve + EdwardsParameters>(
+    bytes_le: &[u8],
+) -> Result<AffinePoint<E>, EcSyscallError> {
+    let mut y_bytes = bytes_le.to_vec();
+    let last = y_bytes.len() - 1;
+    let sign = y_bytes[last] >> 7 == 1;
+    y_bytes[last] &= 0x7f;
+    let y = BigUint::from_bytes_le(&y_bytes);
+    let x = edwards_recover_x::<E>(&y, sign)?;
+    Ok(AffinePoint::new(x, y))
+}
+
+/// Recovers the `x` coordinate of the point of a twisted Edwards curve with the given `y`, `sign`
+/// being the parity of `x`, as in RFC 8032, section 5.1.3.
+///
+/// It returns an error for the encodings the RFC rejects, a `y` that is not less than the modulus
+/// and `sign` set when `x` is zero, and if no point has that `y`.
+pub(super) fn edwards_recover_x<P: EdwardsParameters>(
+    y: &BigUint,
+    sign: bool,
+) -> Result<BigUint, EcSyscallError> {
+    let modulus = P::BaseField::modulus();
+    if y >= &modulus {
+        return Err(EcSyscallError::NonCanonical);
+    }
+
+    let yy = (y * y) % &modulus;
+    let u = (&yy + &modulus - 1u32) % &modulus;
+    let v = (&yy * P::d_biguint() + 1u32) % &modulus;
+    if v == BigUint::ZERO {
+        return Err(EcSyscallError::NonResidue);
+    }
+    let v_inv = v.modpow(&(&modulus - 2u32), &modulus);
+    let sqrt = match P::CURVE_TYPE {
+        CurveType::Ed25519 => ed25519_sqrt,
+        CurveType::Jubjub => jubjub_sqrt,
+        _ => panic!("Unsupported curve"),
+    };
+    // Both square roots are normalized to be even.
+    let x = sqrt(&((u * v_inv) % &modulus)).ok_or(EcSyscallError::NonResidue)?;
+    if !sign {
+        Ok(x)
+    } else if x == BigUint::ZERO {
+        Err(EcSyscallError::NegativeZero)
+    } else {
+        Ok(&modulus - x)
+    }
+}
+
+/// Packs the statuses of a [`BlsDeserializeEvent`] into its status word.
+///
+/// [`BlsDeserializeEvent`]: super::BlsDeserializeEvent
+pub(super) fn bls_deserialize_status(
+    g1_status: EcValidationStatus,
+    g2_status: EcValidationStatus,
+) -> u64 {
+    g1_status as u64 | ((g2_status as u64) << 32)
+}
+
+/// Strips the flags from a compressed BLS12-381 serialization, returning `None` for the point at
+/// infinity, or the big-endian `x` coordinate and whether `y` is lexicographically largest.
+fn bls12381_strip_flags(bytes: &[u8]) -> Result<Option<(Vec<u8>, bool)>, EcSyscallError> {
+    let flags = bytes[0] & (COMPRESSION_FLAG | INFINITY_FLAG | Y_IS_ODD_FLAG);
+    let mut x_bytes = bytes.to_vec();
+    x_bytes[0] &= !flags;
+    if flags & COMPRESSION_FLAG == 0 {
+        return Err(EcSyscallError::InvalidFlags(flags));
+    }
+    if flags & INFINITY_FLAG != 0 {
+        if flags & Y_IS_ODD_FLAG != 0 {
+            return Err(EcSyscallError::InvalidFlags(flags));
+        }
+        if x_bytes.iter().any(|&byte| byte != 0) {
+            return Err(EcSyscallError::NonCanonical);
+        }
+        return Ok(None);
+    }
+    Ok(Some((x_bytes, flags & Y_IS_ODD_FLAG != 0)))
+}
+
+/// Decodes a compressed BLS12-381 G1 point, returning `None` for the point at infinity and an
+/// error if the serialization is invalid.
+pub(super) fn g1_decompress(bytes: &[u8]) -> Result<Option<AffinePoint<Bls12381>>, EcSyscallError> {
+    let Some((x_bytes, y_is_largest)) = bls12381_strip_flags(bytes)? else {
+        return Ok(None);
+    };
+
+    let modulus = Bls12381BaseField::modulus();
+    let x = BigUint::from_bytes_be(&x_bytes);
+    if x >= modulus {
+        return Err(EcSyscallError::NonCanonical);
+    }
+    if !lifts_to_point::<Bls12381>(&x) {
+        return Err(EcSyscallError::NonResidue);
+    }
+    Ok(Some(bls12381_decompress::<Bls12381>(&x_bytes, u32::from(y_is_largest))))
+}
+
+/// Decodes a compressed BLS12-381 G2 point, returning `None` for the point at infinity and an
+/// error if the serialization is invalid.
+pub(super) fn g2_decompress(
+    bytes: &[u8],
+) -> Result<Option<G2AffinePoint<Bls12381G2Parameters>>, EcSyscallError> {
+    let Some((x_bytes, y_is_largest)) = bls12381_strip_flags(bytes)? else {
+        return Ok(None);
+    };
+
+    let modulus = Bls12381BaseField::modulus();
+    let (c1_bytes, c0_bytes) = x_bytes.split_at(x_bytes.len() / 2);
+    let (c0, c1) = (BigUint::from_bytes_be(c0_bytes), BigUint::from_bytes_be(c1_bytes));
+    if c0 >= modulus || c1 >= modulus {
+        return Err(EcSyscallError::NonCanonical);
+    }
+    bls12381_g2_decompress(&Fp2::new(c0, c1), y_is_largest)
+        .map(Some)
+        .ok_or(EcSyscallError::NonResidue)
+}
@@ -0,0 +1,14 @@
+//! Elliptic curve precompile events, the constructors that execute the syscalls creating them, and
+//! their compact encoding.
+
+mod compact;
+mod constructors;
+mod events;
+mod helpers;
+#[cfg(test)]
+mod tests;
+
+pub use compact::*;
+pub use constructors::*;
+pub use events::*;
+pub use helpers::*;
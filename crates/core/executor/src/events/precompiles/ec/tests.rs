@@ -0,0 +1,3978 @@
+use std::sync::Arc;
+
+use deepsize2::DeepSizeOf;
+use serde::{Deserialize, Serialize};
+
+use sp1_curves::{
+    edwards::{
+        ed25519::{Ed25519, Ed25519BaseField},
+        jubjub::{Jubjub, JubjubBaseField},
+    },
+    params::{FieldParameters, NumLimbs, NumWords},
+    registry::{CustomCurve, CustomPoint},
+    utils::sqrt_3mod4,
+    weierstrass::{
+        bls12_381::{Bls12381, Bls12381BaseField, Bls12381G2Parameters},
+        bn254::{Bn254, Bn254BaseField},
+        g2::{Fp2, G2AffinePoint, G2Parameters},
+        jacobian::JacobianPoint,
+        pasta::{Pallas, Vesta},
+        secp256k1::{secp256k1_decompress, Secp256k1, Secp256k1BaseField, Secp256k1Parameters},
+        secp256r1::{Secp256r1, Secp256r1BaseField},
+        WeierstrassParameters,
+    },
+    AffinePoint, BigUint, CurveType, EcOp, EllipticCurve, EllipticCurveParameters,
+    IdentityEncoding,
+};
+use sp1_primitives::consts::{
+    bytes_be_to_words_le_vec, bytes_to_words_le_vec, words_to_bytes_le_vec, DEFAULT_PAGE_PROT,
+    PAGE_SIZE, PROT_READ, PROT_WRITE,
+};
+use typenum::Unsigned;
+
+use super::*;
+use crate::{
+    events::{
+        AssertionPolicy, EcSyscallError, MemoryEntry, MemoryLocalEvent, MemoryRecord,
+        PageProtLocalEvent, PageProtRecord, PrecompileEvent, EC_SYSCALL_FAILED,
+        EC_SYSCALL_FAILED_EXIT_CODE,
+    },
+    syscalls::{get_syscall, mock::MockSyscallContext, SyscallCode, SyscallContext},
+    ExecutionError, Executor, ExecutorConfig, ExecutorMode, Program, SP1Context, SP1CoreOpts,
+    Trace, HALT_PC,
+};
+
+/// A trace config that validates elliptic curve inputs.
+struct ValidatingTrace;
+
+impl ExecutorConfig for ValidatingTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const VALIDATE_EC_INPUTS: bool = true;
+}
+
+/// The file written by [`NoopEvents`].
+const EC_NOOP_EVENT_TEST_PATH: &str = "/tmp/sp1_ec_noop_event_test.jsonl";
+
+/// A config that writes additions reducing to a copy to a JSONL file as no-op events.
+struct NoopEvents;
+
+impl ExecutorConfig for NoopEvents {
+    const MODE: ExecutorMode = ExecutorMode::Simple;
+    const UNCONSTRAINED: bool = false;
+    const EMIT_EC_NOOP_EVENTS: bool = true;
+    const EC_EVENT_TRACE_PATH: Option<&'static str> = Some(EC_NOOP_EVENT_TEST_PATH);
+}
+
+/// A trace config that records additions reducing to a copy as no-op events.
+struct NoopTrace;
+
+impl ExecutorConfig for NoopTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const EMIT_EC_NOOP_EVENTS: bool = true;
+}
+
+/// The file written by [`EcEventTrace`].
+const EC_EVENT_TRACE_TEST_PATH: &str = "/tmp/sp1_ec_event_trace_test.jsonl";
+
+/// A trace config that writes every elliptic curve event to a JSONL file.
+struct EcEventTrace;
+
+impl ExecutorConfig for EcEventTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const EC_EVENT_TRACE_PATH: Option<&'static str> = Some(EC_EVENT_TRACE_TEST_PATH);
+}
+
+struct PageProtFreeTrace;
+
+impl ExecutorConfig for PageProtFreeTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const RECORD_PAGE_PROT: bool = false;
+}
+
+struct ElidingTrace;
+
+impl ExecutorConfig for ElidingTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const ELIDE_UNCHANGED_EC_WRITES: bool = true;
+}
+
+/// A trace config that allows two elliptic curve events per shard.
+struct QuotaTrace;
+
+impl ExecutorConfig for QuotaTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const MAX_EC_OPS_PER_SHARD: Option<u64> = Some(2);
+}
+
+/// A trace config that creates events with a checksum.
+struct ChecksumTrace;
+
+impl ExecutorConfig for ChecksumTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const EC_EVENT_CHECKSUMS: bool = true;
+}
+
+/// A trace config that records the Jacobian `Z` coordinate of doublings.
+struct RecordJacobianTrace;
+
+impl ExecutorConfig for RecordJacobianTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const RECORD_JACOBIAN_Z: bool = true;
+}
+
+/// A trace config that draws the memory records of syscalls from the record pool.
+struct PooledTrace;
+
+impl ExecutorConfig for PooledTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const POOL_EC_RECORDS: bool = true;
+}
+
+pub(super) fn executor() -> Executor<'static> {
+    let mut rt = Executor::new(Arc::new(Program::new(vec![], 0, 0)), SP1CoreOpts::default());
+    // Start past the initial timestamp so that every access has a strictly larger timestamp
+    // than the memory initialization.
+    rt.state.clk = 8;
+    rt
+}
+
+pub(super) fn write_words(rt: &mut Executor, ptr: u64, words: &[u64]) {
+    for (i, word) in words.iter().enumerate() {
+        rt.state.memory.page_table.insert(ptr + 8 * i as u64, MemoryEntry::init(*word));
+    }
+}
+
+pub(super) fn read_words(rt: &mut Executor, ptr: u64, len: usize) -> Vec<u64> {
+    (0..len).map(|i| rt.double_word::<Trace>(ptr + 8 * i as u64)).collect()
+}
+
+/// Returns an [`executor`] whose memory holds each slice of words at its pointer.
+fn executor_with(words: &[(u64, &[u64])]) -> Executor<'static> {
+    let mut rt = executor();
+    for (ptr, words) in words {
+        write_words(&mut rt, *ptr, words);
+    }
+    rt
+}
+
+/// Runs `f` with a trace context over [`executor_with`] the given memory, returning its result
+/// and the executor to read the memory back from.
+fn with_ctx<R>(
+    words: &[(u64, &[u64])],
+    f: impl FnOnce(&mut SyscallContext<'_, '_, Trace>) -> R,
+) -> (R, Executor<'static>) {
+    let mut rt = executor_with(words);
+    let result = f(&mut SyscallContext::new(&mut rt, true));
+    (result, rt)
+}
+
+/// Defines a test running a generic check on each of the given curves.
+macro_rules! curve_test {
+    ($test:ident, $check:ident: $($curve:ty),+) => {
+        #[test]
+        fn $test() {
+            $($check::<$curve>();)+
+        }
+    };
+}
+
+fn biguint(s: &str) -> BigUint {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_ed25519_words_round_trip() {
+    let base = Ed25519::ec_generator();
+    let words = base.to_words_le();
+    assert_eq!(words.len(), <Ed25519BaseField as NumWords>::WordsCurvePoint::USIZE);
+    assert_eq!(words.len(), 8);
+    assert_eq!(AffinePoint::<Ed25519>::from_words_le(&words), base);
+}
+
+#[test]
+fn test_ed25519_add_base_to_itself() {
+    let expected = AffinePoint::<Ed25519>::new(
+        biguint("24727413235106541002554574571675588834622768167397638456726423682521233608206"),
+        biguint("15549675580280190176352668710449542251549572066445060580507079593062643049417"),
+    );
+
+    let base = Ed25519::ec_generator().to_words_le();
+    let (event, mut rt) = with_ctx(&[(0x1000, &base), (0x2000, &base)], |ctx| {
+        create_ec_add_event::<Ed25519, Trace>(ctx, 0x1000, 0x2000)
+    });
+    assert_eq!(event.p, base);
+    assert_eq!(event.q, base);
+
+    let result = read_words(&mut rt, 0x1000, base.len());
+    assert_eq!(AffinePoint::<Ed25519>::from_words_le(&result), expected);
+}
+
+#[test]
+fn test_ed25519_double_matches_add() {
+    let base = Ed25519::ec_generator().to_words_le();
+    let (event, mut rt) = with_ctx(&[(0x1000, &base)], |ctx| {
+        create_ec_double_event::<Ed25519, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(event.p, base);
+
+    let result = read_words(&mut rt, 0x1000, base.len());
+    let base = Ed25519::ec_generator();
+    assert_eq!(AffinePoint::<Ed25519>::from_words_le(&result), &base + &base);
+}
+
+#[test]
+fn test_bn254_double_generator() {
+    let expected = AffinePoint::<Bn254>::new(
+        biguint("1368015179489954701390400359078579693043519447331113978918064868415326638035"),
+        biguint("9918110051302171585080402603319702774565515993150576347155970296011118125764"),
+    );
+
+    let generator = Bn254::ec_generator().to_words_le();
+    assert_eq!(generator.len(), 8);
+    let (event, mut rt) = with_ctx(&[(0x1000, &generator)], |ctx| {
+        create_ec_double_event::<Bn254, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(event.p_memory_records.len(), 8);
+
+    let result = read_words(&mut rt, 0x1000, generator.len());
+    assert_eq!(AffinePoint::<Bn254>::from_words_le(&result), expected);
+}
+
+#[test]
+fn test_bn254_add() {
+    let double = AffinePoint::<Bn254>::new(
+        biguint("1368015179489954701390400359078579693043519447331113978918064868415326638035"),
+        biguint("9918110051302171585080402603319702774565515993150576347155970296011118125764"),
+    );
+    let expected = AffinePoint::<Bn254>::new(
+        biguint("3353031288059533942658390886683067124040920775575537747144343083137631628272"),
+        biguint("19321533766552368860946552437480515441416830039777911637913418824951667761761"),
+    );
+
+    let (event, mut rt) = with_ctx(
+        &[(0x1000, &Bn254::ec_generator().to_words_le()), (0x2000, &double.to_words_le())],
+        |ctx| create_ec_add_event::<Bn254, Trace>(ctx, 0x1000, 0x2000),
+    );
+    assert_eq!(event.p_memory_records.len(), 8);
+    assert_eq!(event.q_memory_records.len(), 8);
+
+    let result = read_words(&mut rt, 0x1000, 8);
+    assert_eq!(AffinePoint::<Bn254>::from_words_le(&result), expected);
+}
+
+#[test]
+#[should_panic(expected = "p_ptr must be 8-byte aligned")]
+fn test_bn254_add_misaligned() {
+    with_ctx(&[], |ctx| create_ec_add_event::<Bn254, Trace>(ctx, 0x1004, 0x2000));
+}
+
+/// A trace config under which a failed elliptic curve syscall returns an error code. The executor
+/// refuses to run it, so it is only used with the syscall handlers directly.
+struct ReturnErrorTrace;
+
+impl ExecutorConfig for ReturnErrorTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const ASSERTION_POLICY: AssertionPolicy = AssertionPolicy::ReturnError;
+}
+
+/// A trace config under which a failed elliptic curve syscall halts the program.
+struct LogAndHaltTrace;
+
+impl ExecutorConfig for LogAndHaltTrace {
+    const MODE: ExecutorMode = ExecutorMode::Trace;
+    const UNCONSTRAINED: bool = false;
+    const ASSERTION_POLICY: AssertionPolicy = AssertionPolicy::LogAndHalt;
+}
+
+/// Handles the error of an add with a misaligned `p`, returning the syscall result, the next pc
+/// and the exit code.
+fn misaligned_add_syscall<Ex: ExecutorConfig>() -> (Option<u64>, u64, u32) {
+    let mut rt = executor();
+    let mut ctx = SyscallContext::<Ex>::new(&mut rt, true);
+    let err = try_create_ec_add_event::<Bn254, Ex>(&mut ctx, 0x1004, 0x2000).unwrap_err();
+    let result = handle_ec_syscall_error(&mut ctx, &err);
+    (result, ctx.next_pc, ctx.exit_code)
+}
+
+#[test]
+fn test_assertion_policy() {
+    let (result, next_pc, exit_code) = misaligned_add_syscall::<ReturnErrorTrace>();
+    assert_eq!(result, Some(EC_SYSCALL_FAILED));
+    assert_ne!(next_pc, HALT_PC);
+    assert_eq!(exit_code, 0);
+
+    let (result, next_pc, exit_code) = misaligned_add_syscall::<LogAndHaltTrace>();
+    assert_eq!(result, None);
+    assert_eq!(next_pc, HALT_PC);
+    assert_eq!(exit_code, EC_SYSCALL_FAILED_EXIT_CODE);
+}
+
+#[test]
+#[should_panic(expected = "p_ptr must be 8-byte aligned")]
+fn test_assertion_policy_panic() {
+    misaligned_add_syscall::<Trace>();
+}
+
+/// Runs a fallible constructor on the given memory, checking that it fails with `expected`
+/// without recording any access or advancing the clock.
+fn check_fails_without_access<Ex: ExecutorConfig, T>(
+    words: &[(u64, &[u64])],
+    create: impl FnOnce(&mut SyscallContext<'_, '_, Ex>) -> Result<T, EcSyscallError>,
+    expected: EcSyscallError,
+) {
+    let mut rt = executor_with(words);
+    let mut ctx = SyscallContext::<Ex>::new(&mut rt, true);
+    let start_clk = ctx.clk;
+    assert_eq!(create(&mut ctx).err(), Some(expected));
+    assert_eq!(ctx.clk, start_clk);
+    assert!(ctx.local_memory_access.as_ref().unwrap().is_empty());
+}
+
+#[test]
+fn test_value_errors_leave_no_access() {
+    // Under `ReturnError` and `LogAndHalt` the program goes on after a failed syscall, so
+    // errors found in the values read must come before the first record.
+    let generator = Bn254::ec_generator().to_words_le();
+    let mut off_curve = generator.clone();
+    off_curve[4] ^= 1;
+    let points: &[(u64, &[u64])] = &[(0x1000, &generator), (0x2000, &off_curve)];
+    check_fails_without_access::<ValidatingTrace, _>(
+        points,
+        |ctx| try_create_ec_add_event::<Bn254, ValidatingTrace>(ctx, 0x1000, 0x2000),
+        EcSyscallError::NotOnCurve,
+    );
+    check_fails_without_access::<ValidatingTrace, _>(
+        points,
+        |ctx| try_create_ec_add_event_bytes::<Bn254, ValidatingTrace>(ctx, 0x1000, 0x2000, 64),
+        EcSyscallError::NotOnCurve,
+    );
+    check_fails_without_access::<ValidatingTrace, _>(
+        points,
+        |ctx| try_create_ec_add_to_event::<Bn254, ValidatingTrace>(ctx, 0x1000, 0x2000, 0x3000),
+        EcSyscallError::NotOnCurve,
+    );
+
+    let p = Ed25519BaseField::modulus();
+    for (y, sign_bit, expected) in [
+        (p, 0, EcSyscallError::NonCanonical),
+        (BigUint::from(1u32), 1, EcSyscallError::NegativeZero),
+        (BigUint::from(2u32), 0, EcSyscallError::NonResidue),
+    ] {
+        check_fails_without_access::<Trace, _>(
+            &[(0x1020, &scalar_words(&y))],
+            |ctx| try_create_ec_decompress_event::<Ed25519, Trace>(ctx, 0x1000, sign_bit),
+            expected,
+        );
+    }
+}
+
+#[test]
+fn test_clk_checked_add() {
+    let mut rt = executor();
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    ctx.clk = u64::MAX - 1;
+    assert_eq!(ctx.clk_checked_add(1), Ok(()));
+    assert_eq!(ctx.clk, u64::MAX);
+    assert_eq!(ctx.clk_checked_add(1), Err(ExecutionError::ClockOverflow(u64::MAX)));
+    assert_eq!(ctx.clk, u64::MAX);
+
+    let mut mock = MockSyscallContext::new(u64::MAX - 8, 0);
+    assert_eq!(mock.clk_checked_add(9), Err(ExecutionError::ClockOverflow(u64::MAX - 8)));
+    assert_eq!(mock.clk, u64::MAX - 8);
+}
+
+#[test]
+fn test_add_clk_overflow() {
+    // The write of the sum is ordered after the reads, which cannot happen at the last cycle, so
+    // the syscall ends the execution whatever the assertion policy.
+    let generator = Bn254::ec_generator();
+    let mut rt = executor_with(&[
+        (0x1000, &generator.to_words_le()),
+        (0x2000, &Bn254::ec_double(&generator).to_words_le()),
+    ]);
+    let mut ctx = SyscallContext::<ReturnErrorTrace>::new(&mut rt, true);
+    ctx.syscall_code = SyscallCode::BN254_ADD;
+    ctx.clk = u64::MAX;
+    let handler = get_syscall::<ReturnErrorTrace>(SyscallCode::BN254_ADD).unwrap().handler;
+    assert_eq!(handler(&mut ctx, SyscallCode::BN254_ADD, 0x1000, 0x2000), None);
+    assert_eq!(ctx.execution_error, Some(ExecutionError::ClockOverflow(u64::MAX)));
+    assert_eq!(ctx.clk, u64::MAX);
+    assert!(rt.record.precompile_events.is_empty());
+}
+
+#[test]
+fn test_add_identity_emits_noop() {
+    let generator = Ed25519::ec_generator().to_words_le();
+    let identity = Ed25519::ec_neutral().unwrap().to_words_le();
+    let run = |p: &[u64], q: &[u64]| {
+        let mut rt = executor_with(&[(0x1000, p), (0x2000, q)]);
+        let mut ctx = SyscallContext::<NoopEvents>::new(&mut rt, true);
+        ctx.syscall_code = SyscallCode::ED_ADD;
+        let handler = get_syscall::<NoopEvents>(SyscallCode::ED_ADD).unwrap().handler;
+        handler(&mut ctx, SyscallCode::ED_ADD, 0x1000, 0x2000);
+        let written = read_words(&mut rt, 0x1000, p.len());
+        rt.ec_event_sink.as_mut().unwrap().flush().unwrap();
+        let trace = std::fs::read_to_string(EC_NOOP_EVENT_TEST_PATH).unwrap();
+        std::fs::remove_file(EC_NOOP_EVENT_TEST_PATH).unwrap();
+        let line: serde_json::Value = serde_json::from_str(trace.trim_end()).unwrap();
+        (serde_json::from_value::<PrecompileEvent>(line["event"].clone()).unwrap(), written)
+    };
+
+    // `P + O` and `O + Q` are copies, and every memory access is still recorded.
+    for (p, q) in [(&generator, &identity), (&identity, &generator)] {
+        let (event, written) = run(p, q);
+        let PrecompileEvent::EcNoop(event) = event else { panic!("expected a no-op event") };
+        assert_eq!(written, generator);
+        assert_eq!(event.p, *p);
+        assert_eq!(event.q, *q);
+        assert_eq!(event.p_memory_records.len(), generator.len());
+        assert_eq!(event.q_memory_records.len(), generator.len());
+    }
+    let (event, _) = run(&generator, &generator);
+    assert!(matches!(event, PrecompileEvent::EdAdd(_)));
+
+    // The short Weierstrass syscalls refuse the identity, but the event constructors take it.
+    let generator = Secp256k1::ec_generator().to_words_le();
+    let identity = vec![0; generator.len()];
+    let (event, mut rt) = with_ctx(&[(0x1000, &identity)], |ctx| {
+        create_ec_double_event::<Secp256k1, Trace>(ctx, 0x1000, 0)
+    });
+    let event = EllipticCurveNoopEvent::try_from(event).unwrap();
+    assert_eq!(written_values(&event.p_memory_records).collect::<Vec<_>>(), identity);
+    assert!(event.q.is_empty() && event.q_memory_records.is_empty());
+    write_words(&mut rt, 0x1000, &generator);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_double_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0);
+    assert!(EllipticCurveNoopEvent::try_from(event).is_err());
+}
+
+#[test]
+fn test_noop_events_refused_in_trace() {
+    // A trace holding no-op events could not be proven, so the executor refuses to start one.
+    let mut rt = executor();
+    assert_eq!(rt.execute::<NoopTrace>(), Err(ExecutionError::EcNoopEventsInTrace()));
+    assert!(rt.record.precompile_events.events.is_empty());
+}
+
+#[test]
+fn test_non_panic_policies_refused_in_trace() {
+    // A failed syscall sends to the precompile chips with no event to receive it.
+    let mut rt = executor();
+    assert_eq!(rt.execute::<ReturnErrorTrace>(), Err(ExecutionError::EcSyscallErrorsInTrace()));
+    assert_eq!(rt.execute::<LogAndHaltTrace>(), Err(ExecutionError::EcSyscallErrorsInTrace()));
+    assert!(rt.record.precompile_events.events.is_empty());
+}
+
+#[test]
+fn test_page_prot_free_refused_for_untrusted_programs() {
+    let mut program = Program::new(vec![], 0, 0);
+    program.enable_untrusted_programs = true;
+    let mut rt = Executor::new(Arc::new(program), SP1CoreOpts::default());
+    assert_eq!(rt.execute::<PageProtFreeTrace>(), Err(ExecutionError::PageProtRecordingDisabled()));
+}
+
+/// Checks that the event has a valid checksum, and that flipping any byte of its compact
+/// encoding makes it either fail to decode or fail verification.
+fn check_checksum_detects_flips<T: EcEventChecksum>(event: &T) {
+    assert!(event.verify_checksum());
+    let bytes = event.to_compact_bytes();
+    for i in 0..bytes.len() {
+        let mut flipped = bytes.clone();
+        flipped[i] ^= 0xff;
+        if let Ok(decoded) = T::from_compact_bytes(&flipped) {
+            assert!(!decoded.verify_checksum(), "flipping byte {i} was not detected");
+        }
+    }
+}
+
+#[test]
+fn test_event_checksum() {
+    let generator = Secp256k1::ec_generator();
+    let (event, mut rt) = with_ctx(
+        &[
+            (0x1000, &generator.to_words_le()),
+            (0x2000, &Secp256k1::ec_double(&generator).to_words_le()),
+            (0x3020, &generator.to_words_le()[..4]),
+        ],
+        |ctx| create_ec_add_event::<Secp256k1, Trace>(ctx, 0x1000, 0x2000),
+    );
+    assert_eq!(event.checksum, None);
+    assert!(!event.verify_checksum());
+
+    let mut ctx = SyscallContext::<ChecksumTrace>::new(&mut rt, true);
+    let add = create_ec_add_event::<Secp256k1, ChecksumTrace>(&mut ctx, 0x1000, 0x2000);
+    let double = create_ec_double_event::<Secp256k1, ChecksumTrace>(&mut ctx, 0x2000, 0);
+    let sign_bit = u64::from(generator.y.bit(0));
+    let decompress =
+        create_ec_decompress_event::<Secp256k1, ChecksumTrace>(&mut ctx, 0x3000, sign_bit);
+    check_checksum_detects_flips(&add);
+    check_checksum_detects_flips(&double);
+    check_checksum_detects_flips(&decompress);
+
+    // Every other event has one too, and a no-op event computes its own.
+    let negate = create_ec_negate_event::<Secp256k1, ChecksumTrace>(&mut ctx, 0x1000, 0);
+    check_checksum_detects_flips(&negate);
+    let double = create_ec_double_event::<Secp256k1, ChecksumTrace>(&mut ctx, 0x4000, 0);
+    check_checksum_detects_flips(&EllipticCurveNoopEvent::try_from(double).unwrap());
+
+    // The checksum survives the conversion to an inline event and back.
+    let inline = EllipticCurveAddEventN::<8>::try_from(add).unwrap();
+    assert!(EllipticCurveAddEvent::from(inline).verify_checksum());
+}
+
+fn check_jacobian_z<E: EllipticCurve + WeierstrassParameters>() {
+    let generator = E::ec_generator();
+    let num_words = AffinePoint::<E>::num_memory_words();
+    let mut rt =
+        executor_with(&[(0x1000, &generator.to_memory_words()), (0x2000, &vec![0; num_words])]);
+
+    let mut ctx = SyscallContext::<RecordJacobianTrace>::new(&mut rt, true);
+    let event = create_ec_double_event::<E, RecordJacobianTrace>(&mut ctx, 0x1000, 0);
+    let identity = create_ec_double_event::<E, RecordJacobianTrace>(&mut ctx, 0x2000, 0);
+
+    // Normalizing the Jacobian doubling with the recorded `Z` gives the affine result.
+    let jacobian = JacobianPoint::<E>::from_affine(&generator).double();
+    let z_words = event.jacobian_z_words.unwrap();
+    let z = BigUint::from_bytes_le(&words_to_bytes_le_vec(&z_words));
+    assert_eq!(z, jacobian.z);
+    let normalized = JacobianPoint::<E>::new(jacobian.x, jacobian.y, z).to_affine().unwrap();
+    assert_eq!(normalized.to_memory_words(), read_words(&mut rt, 0x1000, num_words));
+    assert_eq!(z_words.len(), num_words / 2);
+
+    assert_eq!(identity.jacobian_z_words.unwrap(), vec![0; num_words / 2]);
+}
+
+#[test]
+fn test_record_jacobian_z() {
+    check_jacobian_z::<Secp256k1>();
+    check_jacobian_z::<Bn254>();
+
+    // The flag is off by default, and twisted Edwards curves have no Jacobian coordinates.
+    let (event, mut rt) = with_ctx(
+        &[
+            (0x1000, &Secp256k1::ec_generator().to_words_le()),
+            (0x2000, &Ed25519::ec_generator().to_words_le()),
+        ],
+        |ctx| create_ec_double_event::<Secp256k1, Trace>(ctx, 0x1000, 0),
+    );
+    assert_eq!(event.jacobian_z_words, None);
+    let mut ctx = SyscallContext::<RecordJacobianTrace>::new(&mut rt, true);
+    let event = create_ec_double_event::<Ed25519, RecordJacobianTrace>(&mut ctx, 0x2000, 0);
+    assert_eq!(event.jacobian_z_words, None);
+}
+
+#[test]
+fn test_ec_event_trace() {
+    let secp256k1 = Secp256k1::ec_generator().to_words_le();
+    let bn254 = Bn254::ec_generator().to_words_le();
+    let mut rt = executor();
+    let doubled = Secp256k1::ec_double(&Secp256k1::ec_generator()).to_words_le();
+    write_words(&mut rt, 0x1000, &secp256k1);
+    write_words(&mut rt, 0x2000, &doubled);
+    write_words(&mut rt, 0x3000, &bn254);
+    let calls = [
+        (SyscallCode::SECP256K1_ADD, 0x1000, 0x2000),
+        (SyscallCode::BN254_DOUBLE, 0x3000, 0),
+        (SyscallCode::SECP256K1_DOUBLE, 0x1000, 0),
+    ];
+    for (code, arg1, arg2) in calls {
+        let mut ctx = SyscallContext::<EcEventTrace>::new(&mut rt, true);
+        ctx.syscall_code = code;
+        (get_syscall::<EcEventTrace>(code).unwrap().handler)(&mut ctx, code, arg1, arg2);
+    }
+    rt.ec_event_sink.as_mut().unwrap().flush().unwrap();
+
+    let trace = std::fs::read_to_string(EC_EVENT_TRACE_TEST_PATH).unwrap();
+    let lines = trace.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), calls.len());
+    let expected = [
+        ("Secp256k1", "Add", "SECP256K1_ADD"),
+        ("Bn254", "Double", "BN254_DOUBLE"),
+        ("Secp256k1", "Double", "SECP256K1_DOUBLE"),
+    ];
+    for ((line, (code, ..)), (curve, op, syscall)) in lines.iter().zip(calls).zip(expected) {
+        let line: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert_eq!(line["curve"], curve);
+        assert_eq!(line["op"], op);
+        assert_eq!(line["syscall"], syscall);
+
+        // The event is the one recorded by the executor.
+        let (_, event) = &rt.record.precompile_events.events[&code][0];
+        assert_eq!(line["event"], serde_json::to_value(event).unwrap());
+    }
+    std::fs::remove_file(EC_EVENT_TRACE_TEST_PATH).unwrap();
+}
+
+#[test]
+fn test_bn254_decompress_generator() {
+    let generator = Bn254::ec_generator();
+    let neg_y = Bn254BaseField::modulus() - &generator.y;
+    let words = generator.to_words_le();
+
+    for (sign_bit, y) in [(0, generator.y.clone()), (1, neg_y)] {
+        // The compressed layout stores x in the upper half of the slice.
+        let (event, mut rt) = with_ctx(&[(0x1020, &words[..4])], |ctx| {
+            create_ec_decompress_event::<Bn254, Trace>(ctx, 0x1000, sign_bit)
+        });
+        assert_eq!(event.decompressed_y_bytes.len(), 32);
+        assert_eq!(BigUint::from_bytes_le(&event.decompressed_y_bytes), y);
+
+        let result = read_words(&mut rt, 0x1000, 4);
+        assert_eq!(result, AffinePoint::<Bn254>::new(BigUint::ZERO, y).to_words_le()[4..]);
+    }
+}
+
+#[test]
+fn test_create_ec_event() {
+    let generator = Bn254::ec_generator();
+    let doubled = Bn254::ec_double(&generator);
+    let run = |op: EcOp, arg2: u64| {
+        let (event, mut rt) = with_ctx(
+            &[(0x1000, &generator.to_words_le()), (0x2000, &doubled.to_words_le())],
+            |ctx| create_ec_event::<Bn254, Trace>(ctx, op, 0x1000, arg2),
+        );
+        assert_eq!(event.op(), op);
+        (event, read_words(&mut rt, 0x1000, 8))
+    };
+
+    let (event, result) = run(EcOp::Add, 0x2000);
+    let EllipticCurveEvent::Add(event) = event else { panic!("expected an add event") };
+    assert_eq!(event.q, doubled.to_words_le());
+    assert_eq!(result, Bn254::ec_add(&generator, &doubled).to_words_le());
+
+    let (event, result) = run(EcOp::Double, 0);
+    let EllipticCurveEvent::Double(event) = event else { panic!("expected a double event") };
+    assert_eq!(event.p, generator.to_words_le());
+    assert_eq!(result, doubled.to_words_le());
+
+    // The upper half of the slice holds the y coordinate of the generator, 2, which is also the
+    // x coordinate of a point.
+    let (event, result) = run(EcOp::Decompress, 0);
+    let EllipticCurveEvent::Decompress(event) = event else {
+        panic!("expected a decompress event")
+    };
+    assert_eq!(event.x_bytes, words_to_bytes_le_vec(&generator.to_words_le()[4..]));
+    assert_eq!(result[..4], bytes_to_words_le_vec(&event.decompressed_y_bytes));
+}
+
+#[test]
+fn test_bn254_decompress_non_residue() {
+    // 4^3 + 3 is not a quadratic residue modulo the Bn254 base field.
+    let (result, _) = with_ctx(&[(0x1020, &[4, 0, 0, 0])], |ctx| {
+        try_create_ec_decompress_event::<Bn254, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(result.unwrap_err(), EcSyscallError::NonResidue);
+}
+
+fn scalar_words(k: &BigUint) -> Vec<u64> {
+    let mut scalar_bytes = k.to_bytes_le();
+    scalar_bytes.resize(32, 0);
+    bytes_to_words_le_vec(&scalar_bytes)
+}
+
+fn secp256k1_scalar_mul(p: &AffinePoint<Secp256k1>, k: &BigUint) -> Vec<u64> {
+    let (event, mut rt) =
+        with_ctx(&[(0x1000, &p.to_words_le()), (0x2000, &scalar_words(k))], |ctx| {
+            create_ec_scalar_mul_event::<Secp256k1, Trace>(ctx, 0x1000, 0x2000)
+        });
+    assert_eq!(event.scalar_memory_records.len(), 4);
+    assert_eq!(event.p_memory_records.len(), 8);
+    assert_eq!(BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.scalar)), *k);
+
+    read_words(&mut rt, 0x1000, 8)
+}
+
+#[test]
+fn test_secp256k1_scalar_mul_one() {
+    let result = secp256k1_scalar_mul(&Secp256k1::ec_generator(), &BigUint::from(1u32));
+    assert_eq!(result, Secp256k1::ec_generator().to_words_le());
+}
+
+#[test]
+fn test_secp256k1_scalar_mul_two_matches_double() {
+    let (_, mut rt) = with_ctx(&[(0x1000, &Secp256k1::ec_generator().to_words_le())], |ctx| {
+        create_ec_double_event::<Secp256k1, Trace>(ctx, 0x1000, 0)
+    });
+    let doubled = read_words(&mut rt, 0x1000, 8);
+
+    assert_eq!(secp256k1_scalar_mul(&Secp256k1::ec_generator(), &BigUint::from(2u32)), doubled);
+}
+
+#[test]
+fn test_secp256k1_scalar_mul_matches_repeated_add() {
+    let generator = Secp256k1::ec_generator();
+    let k = 47u32;
+    let mut expected = Secp256k1::ec_double(&generator);
+    for _ in 2..k {
+        expected = Secp256k1::ec_add(&expected, &generator);
+    }
+
+    assert_eq!(secp256k1_scalar_mul(&generator, &BigUint::from(k)), expected.to_words_le());
+}
+
+#[test]
+fn test_secp256k1_scalar_mul_identity() {
+    for k in [BigUint::ZERO, Secp256k1Parameters::prime_group_order()] {
+        assert_eq!(secp256k1_scalar_mul(&Secp256k1::ec_generator(), &k), vec![0; 8]);
+    }
+}
+
+fn check_montgomery_scalar_mul<E: EllipticCurve>() {
+    let num_words = <E::BaseField as NumWords>::WordsCurvePoint::USIZE;
+    let scalar_mul = |scalar: &BigUint, montgomery: bool| {
+        let mut scalar_bytes = scalar.to_bytes_le();
+        scalar_bytes.resize(num_words * 4, 0);
+        let (event, mut rt) = with_ctx(
+            &[
+                (0x1000, &E::ec_generator().to_words_le()),
+                (0x2000, &bytes_to_words_le_vec(&scalar_bytes)),
+            ],
+            |ctx| {
+                if montgomery {
+                    create_ec_montgomery_scalar_mul_event::<E, Trace>(ctx, 0x1000, 0x2000)
+                } else {
+                    create_ec_scalar_mul_event::<E, Trace>(ctx, 0x1000, 0x2000)
+                }
+            },
+        );
+        assert_eq!(event.scalar_montgomery, montgomery);
+        read_words(&mut rt, 0x1000, num_words)
+    };
+
+    let n = E::CURVE_TYPE.prime_group_order();
+    let r = BigUint::from(1u32) << 256;
+    for k in [BigUint::ZERO, BigUint::from(1u32), BigUint::from(47u32), &n - 1u32] {
+        let montgomery = (&k * &r) % &n;
+        assert_eq!(scalar_mul(&montgomery, true), scalar_mul(&k, false));
+    }
+    assert_eq!(from_montgomery(&(&r % &n), &n), BigUint::from(1u32));
+}
+
+curve_test!(
+    test_montgomery_scalar_mul,
+    check_montgomery_scalar_mul: Secp256k1, Bn254, Bls12381, Ed25519
+);
+
+fn secp256k1_msm(points: &[AffinePoint<Secp256k1>], scalars: &[BigUint]) -> Vec<u64> {
+    let mut rt = executor();
+    let mut input = vec![points.len() as u64];
+    input.extend(points.iter().flat_map(AffinePoint::to_words_le));
+    input.extend(scalars.iter().flat_map(scalar_words));
+    write_words(&mut rt, 0x2000, &input);
+
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_msm_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x2000);
+    assert_eq!(event.n, points.len() as u64);
+    assert_eq!(event.points_memory_records.len(), 8 * points.len());
+    assert_eq!(event.scalars_memory_records.len(), 4 * scalars.len());
+    assert_eq!(event.result_memory_records.len(), 8);
+
+    // Every slice access is at a cycle of its own.
+    let n = points.len() as u64;
+    assert_eq!(event.n_memory_record.timestamp, 8);
+    for i in 0..points.len() {
+        assert_eq!(event.points_memory_records[8 * i].timestamp, 9 + i as u64);
+        assert_eq!(event.scalars_memory_records[4 * i].timestamp, 9 + n + i as u64);
+    }
+    assert_eq!(event.result_memory_records[0].timestamp, 9 + 2 * n);
+
+    read_words(&mut rt, 0x1000, 8)
+}
+
+#[test]
+fn test_secp256k1_msm_matches_scalar_mul() {
+    let generator = Secp256k1::ec_generator();
+    let points = vec![
+        generator.clone(),
+        Secp256k1::ec_double(&generator),
+        AffinePoint::from_words_le(&secp256k1_scalar_mul(&generator, &BigUint::from(5u32))),
+    ];
+    let scalars = vec![
+        biguint("98765432109876543210987654321"),
+        BigUint::from(3u32),
+        Secp256k1Parameters::prime_group_order() - 1u32,
+    ];
+
+    let expected = points
+        .iter()
+        .zip(&scalars)
+        .map(|(p, k)| AffinePoint::from_words_le(&secp256k1_scalar_mul(p, k)))
+        .reduce(|acc, term| Secp256k1::ec_add(&acc, &term))
+        .unwrap();
+
+    assert_eq!(secp256k1_msm(&points, &scalars), expected.to_words_le());
+}
+
+#[test]
+fn test_secp256k1_msm_identity() {
+    assert_eq!(secp256k1_msm(&[], &[]), vec![0; 8]);
+
+    // [k]G + [k](-G) cancels out.
+    let generator = Secp256k1::ec_generator();
+    let k = BigUint::from(7u32);
+    let result = secp256k1_msm(&[generator.clone(), -generator], &[k.clone(), k]);
+    assert_eq!(result, vec![0; 8]);
+}
+
+#[test]
+#[should_panic(expected = "n must be at most 4096")]
+fn test_decompress_batch_rejects_unbounded_n() {
+    with_ctx(&[(0x1000, &[u64::MAX])], |ctx| {
+        create_ec_decompress_batch_event::<Secp256k1, Trace>(ctx, 0x1000, 0x8000)
+    });
+}
+
+#[test]
+#[should_panic(expected = "quota of 2 elliptic curve operations")]
+fn test_decompress_batch_charges_every_point() {
+    let mut rt = executor();
+    // Three points with the x coordinate of the generator and even sign bits.
+    let x = &Secp256k1::ec_generator().to_words_le()[..4];
+    let mut input = vec![3, 0];
+    input.extend(x.repeat(3));
+    write_words(&mut rt, 0x1000, &input);
+    let mut ctx = SyscallContext::<QuotaTrace>::new(&mut rt, true);
+    create_ec_decompress_batch_event::<Secp256k1, QuotaTrace>(&mut ctx, 0x1000, 0x8000);
+}
+
+#[test]
+#[should_panic(expected = "n must be at most 4096")]
+fn test_msm_rejects_unbounded_n() {
+    with_ctx(&[(0x2000, &[u64::MAX])], |ctx| {
+        create_ec_msm_event::<Secp256k1, Trace>(ctx, 0x1000, 0x2000)
+    });
+}
+
+#[test]
+#[should_panic(expected = "quota of 2 elliptic curve operations")]
+fn test_msm_charges_every_point() {
+    let generator = Secp256k1::ec_generator();
+    let mut rt = executor();
+    let mut input = vec![3];
+    input.extend([&generator, &generator, &generator].into_iter().flat_map(|p| p.to_words_le()));
+    input.extend([1u32, 2, 3].map(BigUint::from).iter().flat_map(scalar_words));
+    write_words(&mut rt, 0x2000, &input);
+    let mut ctx = SyscallContext::<QuotaTrace>::new(&mut rt, true);
+    create_ec_msm_event::<Secp256k1, QuotaTrace>(&mut ctx, 0x1000, 0x2000);
+}
+
+#[test]
+fn test_try_create_misaligned() {
+    let mut rt = executor();
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+
+    let err = try_create_ec_add_event::<Bn254, Trace>(&mut ctx, 0x1000, 0x2004).unwrap_err();
+    assert_eq!(err, EcSyscallError::Unaligned { name: "q_ptr", ptr: 0x2004 });
+
+    let err = try_create_ec_double_event::<Bn254, Trace>(&mut ctx, 0x1002, 0).unwrap_err();
+    assert_eq!(err, EcSyscallError::Unaligned { name: "p_ptr", ptr: 0x1002 });
+
+    let err = try_create_ec_decompress_event::<Bn254, Trace>(&mut ctx, 0x1001, 0).unwrap_err();
+    assert_eq!(err, EcSyscallError::Unaligned { name: "slice_ptr", ptr: 0x1001 });
+}
+
+#[test]
+fn test_add_partial_overlap() {
+    let mut rt = executor();
+    let generator = Secp256k1::ec_generator();
+    let double = Secp256k1::ec_double(&generator);
+    write_words(&mut rt, 0x1000, &generator.to_words_le());
+    write_words(&mut rt, 0x1040, &double.to_words_le());
+    let before = read_words(&mut rt, 0x1000, 16);
+
+    // `q` starts inside `p` and `p` starts inside `q`; neither is an exact alias.
+    for (p_ptr, q_ptr) in [(0x1000, 0x1008), (0x1008, 0x1000), (0x1000, 0x1038)] {
+        let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+        let err = try_create_ec_add_event::<Secp256k1, Trace>(&mut ctx, p_ptr, q_ptr);
+        assert_eq!(err.unwrap_err(), EcSyscallError::PartialOverlap { p_ptr, q_ptr });
+        assert!(ctx.postprocess().0.is_empty());
+    }
+    assert_eq!(read_words(&mut rt, 0x1000, 16), before);
+
+    // Adjacent points do not overlap, and exact aliasing still doubles.
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    try_create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x1040).unwrap();
+    try_create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 0x1040, 0x1040).unwrap();
+    let triple = Secp256k1::ec_add(&generator, &double);
+    assert_eq!(read_words(&mut rt, 0x1000, 8), triple.to_words_le());
+    assert_eq!(read_words(&mut rt, 0x1040, 8), Secp256k1::ec_double(&double).to_words_le());
+}
+
+/// A base field with no limbs, so that its elements and points take no words.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct EmptyField;
+
+impl NumLimbs for EmptyField {
+    type Limbs = typenum::U0;
+    type Witness = typenum::U0;
+}
+
+impl FieldParameters for EmptyField {
+    const WITNESS_OFFSET: usize = 0;
+    const MODULUS: &'static [u8] = &[];
+}
+
+/// A misconfigured curve over [`EmptyField`]. Its operations are unreachable, so that a test
+/// fails unless the event constructors reject it first.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct DegenerateCurve;
+
+impl EllipticCurveParameters for DegenerateCurve {
+    type BaseField = EmptyField;
+
+    const CURVE_TYPE: CurveType = CurveType::Secp256k1;
+}
+
+impl EllipticCurve for DegenerateCurve {
+    const IDENTITY_ENCODING: IdentityEncoding = IdentityEncoding::Zero;
+
+    fn ec_add(_: &AffinePoint<Self>, _: &AffinePoint<Self>) -> AffinePoint<Self> {
+        unreachable!("a curve with empty points is rejected")
+    }
+
+    fn ec_double(_: &AffinePoint<Self>) -> AffinePoint<Self> {
+        unreachable!("a curve with empty points is rejected")
+    }
+
+    fn ec_generator() -> AffinePoint<Self> {
+        unreachable!("a curve with empty points is rejected")
+    }
+
+    fn ec_neutral() -> Option<AffinePoint<Self>> {
+        None
+    }
+
+    fn ec_neg(_: &AffinePoint<Self>) -> AffinePoint<Self> {
+        unreachable!("a curve with empty points is rejected")
+    }
+
+    fn ec_is_on_curve(_: &AffinePoint<Self>) -> bool {
+        unreachable!("a curve with empty points is rejected")
+    }
+}
+
+#[test]
+fn test_zero_word_curve() {
+    assert_eq!(AffinePoint::<DegenerateCurve>::num_memory_words(), 0);
+    let mut rt = executor();
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let start_clk = ctx.clk;
+
+    let err = try_create_ec_add_event::<DegenerateCurve, Trace>(&mut ctx, 0x1000, 0x2000);
+    assert_eq!(err.unwrap_err(), EcSyscallError::EmptyPoint);
+    let err = try_create_ec_double_event::<DegenerateCurve, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(err.unwrap_err(), EcSyscallError::EmptyPoint);
+    let err = try_create_ec_decompress_event::<DegenerateCurve, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(err.unwrap_err(), EcSyscallError::EmptyPoint);
+
+    // Nothing was accessed, so no event is left to emit.
+    assert_eq!(ctx.clk, start_clk);
+    let (local_mem_access, _) = ctx.postprocess();
+    assert!(local_mem_access.is_empty());
+}
+
+fn add_words<E: EllipticCurve>(p: &[u64], q: &[u64]) -> Vec<u64> {
+    let (event, mut rt) = with_ctx(&[(0x1000, p), (0x2000, q)], |ctx| {
+        create_ec_add_event::<E, Trace>(ctx, 0x1000, 0x2000)
+    });
+    // The result is always written, so the trace shape does not depend on the identity.
+    assert_eq!(event.p_memory_records.len(), p.len());
+
+    read_words(&mut rt, 0x1000, p.len())
+}
+
+fn check_add_identity<E: EllipticCurve>() {
+    let generator = E::ec_generator().to_words_le();
+    let neg_generator = E::ec_neg(&E::ec_generator()).to_words_le();
+    let identity = vec![0; generator.len()];
+
+    assert_eq!(add_words::<E>(&identity, &generator), generator);
+    assert_eq!(add_words::<E>(&generator, &identity), generator);
+    assert_eq!(add_words::<E>(&identity, &identity), identity);
+    assert_eq!(add_words::<E>(&generator, &neg_generator), identity);
+}
+
+curve_test!(test_add_identity, check_add_identity: Secp256k1, Bls12381);
+
+/// Runs an elliptic curve syscall on the points written at `0x1000` and `0x2000` under
+/// [`ReturnErrorTrace`], returning its result and whether it accessed memory.
+fn ec_syscall(code: SyscallCode, p: &[u64], q: &[u64], q_ptr: u64) -> (Option<u64>, bool) {
+    let mut rt = executor_with(&[(0x1000, p), (0x2000, q)]);
+    let mut ctx = SyscallContext::<ReturnErrorTrace>::new(&mut rt, true);
+    ctx.syscall_code = code;
+    let handler = get_syscall::<ReturnErrorTrace>(code).unwrap().handler;
+    let result = handler(&mut ctx, code, 0x1000, q_ptr);
+    (result, !ctx.local_memory_access.as_ref().unwrap().is_empty())
+}
+
+fn check_add_syscall_rejects_identity<E: EllipticCurve>(code: SyscallCode) {
+    let generator = E::ec_generator().to_words_le();
+    let neg_generator = E::ec_neg(&E::ec_generator()).to_words_le();
+    let identity = vec![0; generator.len()];
+
+    // The add chip only constrains the chord formula, so the syscall refuses the cases the
+    // event constructor handles, before touching memory.
+    for (p, q) in [(&identity, &generator), (&generator, &identity), (&generator, &neg_generator)] {
+        assert_eq!(ec_syscall(code, p, q, 0x2000), (Some(EC_SYSCALL_FAILED), false));
+    }
+    let doubled = E::ec_double(&E::ec_generator()).to_words_le();
+    assert_eq!(ec_syscall(code, &generator, &doubled, 0x2000), (None, true));
+}
+
+#[test]
+fn test_add_syscall_rejects_identity() {
+    check_add_syscall_rejects_identity::<Secp256k1>(SyscallCode::SECP256K1_ADD);
+    check_add_syscall_rejects_identity::<Bls12381>(SyscallCode::BLS12381_ADD);
+}
+
+#[test]
+fn test_bn254_add_equal_points_matches_double() {
+    let generator = Bn254::ec_generator().to_words_le();
+
+    let (_, mut rt) = with_ctx(&[(0x1000, &generator)], |ctx| {
+        create_ec_double_event::<Bn254, Trace>(ctx, 0x1000, 0)
+    });
+    let doubled = read_words(&mut rt, 0x1000, 8);
+
+    // Two copies of the same point.
+    assert_eq!(add_words::<Bn254>(&generator, &generator), doubled);
+
+    // The same pointer passed twice.
+    let (event, mut rt) = with_ctx(&[(0x1000, &generator)], |ctx| {
+        create_ec_add_event::<Bn254, Trace>(ctx, 0x1000, 0x1000)
+    });
+    assert_eq!(event.p, event.q);
+    assert_eq!(read_words(&mut rt, 0x1000, 8), doubled);
+}
+
+#[test]
+fn test_add_syscall_rejects_equal_points() {
+    // The chord formula divides by zero on equal points, so the syscall refuses them whether
+    // they are two copies or the same pointer passed twice.
+    let generator = Bn254::ec_generator().to_words_le();
+    for q_ptr in [0x2000, 0x1000] {
+        let result = ec_syscall(SyscallCode::BN254_ADD, &generator, &generator, q_ptr);
+        assert_eq!(result, (Some(EC_SYSCALL_FAILED), false));
+    }
+}
+
+fn try_add_double<Ex: ExecutorConfig>(
+    p: &AffinePoint<Bn254>,
+    q: &AffinePoint<Bn254>,
+) -> (Result<(), EcSyscallError>, Result<(), EcSyscallError>) {
+    let mut rt = executor_with(&[(0x1000, &p.to_words_le()), (0x2000, &q.to_words_le())]);
+    let mut ctx = SyscallContext::<Ex>::new(&mut rt, true);
+    let add = try_create_ec_add_event::<Bn254, Ex>(&mut ctx, 0x1000, 0x2000).map(|_| ());
+
+    let mut rt = executor_with(&[(0x1000, &q.to_words_le())]);
+    let mut ctx = SyscallContext::<Ex>::new(&mut rt, true);
+    let double = try_create_ec_double_event::<Bn254, Ex>(&mut ctx, 0x1000, 0).map(|_| ());
+
+    (add, double)
+}
+
+#[test]
+fn test_validate_ec_inputs() {
+    let generator = Bn254::ec_generator();
+    let off_curve = AffinePoint::<Bn254>::new(generator.x.clone(), &generator.y + 1u32);
+
+    let (add, double) = try_add_double::<ValidatingTrace>(&generator, &off_curve);
+    assert_eq!(add, Err(EcSyscallError::NotOnCurve));
+    assert_eq!(double, Err(EcSyscallError::NotOnCurve));
+
+    // Without validation, the off-curve point goes through unchecked.
+    let (add, double) = try_add_double::<Trace>(&generator, &off_curve);
+    assert_eq!(add, Ok(()));
+    assert_eq!(double, Ok(()));
+}
+
+#[test]
+fn test_validate_ec_inputs_accepts_valid_points() {
+    let generator = Bn254::ec_generator();
+    let (add, double) =
+        try_add_double::<ValidatingTrace>(&generator, &Bn254::ec_double(&generator));
+    assert_eq!(add, Ok(()));
+    assert_eq!(double, Ok(()));
+
+    // The point at infinity is accepted.
+    let mut rt = executor_with(&[(0x1000, &generator.to_words_le()), (0x2000, &[0; 8])]);
+    let mut ctx = SyscallContext::<ValidatingTrace>::new(&mut rt, true);
+    assert!(try_create_ec_add_event::<Bn254, ValidatingTrace>(&mut ctx, 0x1000, 0x2000).is_ok());
+
+    let mut rt = executor_with(&[(0x1020, &generator.to_words_le()[..4])]);
+    let mut ctx = SyscallContext::<ValidatingTrace>::new(&mut rt, true);
+    assert!(try_create_ec_decompress_event::<Bn254, ValidatingTrace>(&mut ctx, 0x1000, 0).is_ok());
+}
+
+fn bls12381_subgroup_check(p: &[u64]) -> u64 {
+    let (event, mut rt) = with_ctx(&[(0x1000, p)], |ctx| {
+        create_ec_subgroup_check_event::<Bls12381, Trace>(ctx, 0x1000, 0x2000)
+    });
+    assert_eq!(event.p_memory_records.len(), 12);
+
+    let result = read_words(&mut rt, 0x2000, 1)[0];
+    assert_eq!(result, u64::from(event.in_subgroup));
+    result
+}
+
+#[test]
+fn test_bls12381_subgroup_check() {
+    let generator = Bls12381::ec_generator();
+    assert_eq!(bls12381_subgroup_check(&generator.to_words_le()), 1);
+    assert_eq!(bls12381_subgroup_check(&Bls12381::ec_double(&generator).to_words_le()), 1);
+    assert_eq!(bls12381_subgroup_check(&[0; 12]), 1);
+
+    // A point on the curve outside of the prime-order subgroup.
+    let point = AffinePoint::<Bls12381>::new(
+        BigUint::from(4u32),
+        biguint(
+            "1630892974828014537729259858097113969650871260980656934049590190201941782487224876496582135785777461178964897591404",
+        ),
+    );
+    assert!(Bls12381::ec_is_on_curve(&point));
+    assert_eq!(bls12381_subgroup_check(&point.to_words_le()), 0);
+}
+
+#[test]
+fn test_secp256r1_double_generator() {
+    // P-256 uses a = -3.
+    assert_eq!(Secp256r1::a_int(), Secp256r1BaseField::modulus() - 3u32);
+
+    let (event, mut rt) = with_ctx(&[(0x1000, &Secp256r1::ec_generator().to_words_le())], |ctx| {
+        create_ec_double_event::<Secp256r1, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(event.p_memory_records.len(), 8);
+
+    let expected = AffinePoint::<Secp256r1>::new(
+        biguint("56515219790691171413109057904011688695424810155802929973526481321309856242040"),
+        biguint("3377031843712258259223711451491452598088675519751548567112458094635497583569"),
+    );
+    assert_eq!(read_words(&mut rt, 0x1000, 8), expected.to_words_le());
+}
+
+#[test]
+fn test_secp256r1_add() {
+    let generator = Secp256r1::ec_generator();
+    let expected = AffinePoint::<Secp256r1>::new(
+        biguint("42877656971275811310262564894490210024759287182177196162425349131675946712428"),
+        biguint("61154801112014214504178281461992570017247172004704277041681093927569603776562"),
+    );
+    let result =
+        add_words::<Secp256r1>(&generator.to_words_le(), &generator.sw_double().to_words_le());
+    assert_eq!(result, expected.to_words_le());
+}
+
+#[test]
+fn test_decompress_for_curve() {
+    fn registered<E: EllipticCurve>() -> bool {
+        decompress_for_curve::<E>().is_some()
+    }
+
+    // This match is exhaustive, so a new curve type does not compile until it is listed here.
+    for curve in [
+        CurveType::Secp256k1,
+        CurveType::Secp256r1,
+        CurveType::Bn254,
+        CurveType::Bls12381,
+        CurveType::Ed25519,
+        CurveType::Jubjub,
+        CurveType::Pallas,
+        CurveType::Vesta,
+        CurveType::Custom(TOY_CURVE_ID),
+    ] {
+        let expected = match curve {
+            CurveType::Secp256k1 => registered::<Secp256k1>(),
+            CurveType::Secp256r1 => registered::<Secp256r1>(),
+            CurveType::Bn254 => registered::<Bn254>(),
+            CurveType::Bls12381 => registered::<Bls12381>(),
+            CurveType::Ed25519 => registered::<Ed25519>(),
+            CurveType::Jubjub => registered::<Jubjub>(),
+            CurveType::Pallas => !registered::<Pallas>(),
+            CurveType::Vesta => !registered::<Vesta>(),
+            // Custom curves are decompressed by the implementation registered with the executor.
+            CurveType::Custom(_) => !registered::<ToyCurve>(),
+        };
+        assert!(expected, "unexpected decompress registration for {curve}");
+    }
+}
+
+#[test]
+fn test_supported_curves() {
+    fn check<E: EllipticCurve>(curve: CurveType) {
+        assert_eq!(E::CURVE_TYPE, curve);
+        assert!(E::ec_is_on_curve(&E::ec_generator()));
+        assert_eq!(curve.words_per_point(), AffinePoint::<E>::num_memory_words());
+    }
+
+    // Every supported curve reaches its own arm. This match is exhaustive, so a new curve type
+    // does not compile until it is listed here.
+    for &curve in supported_curves() {
+        match curve {
+            CurveType::Secp256k1 => check::<Secp256k1>(curve),
+            CurveType::Secp256r1 => check::<Secp256r1>(curve),
+            CurveType::Bn254 => check::<Bn254>(curve),
+            CurveType::Bls12381 => check::<Bls12381>(curve),
+            CurveType::Ed25519 => check::<Ed25519>(curve),
+            CurveType::Jubjub => check::<Jubjub>(curve),
+            CurveType::Pallas => check::<Pallas>(curve),
+            CurveType::Vesta => check::<Vesta>(curve),
+            CurveType::Custom(_) => panic!("{curve} is registered at runtime"),
+        }
+    }
+    let curves = supported_curves();
+    assert!(curves.iter().enumerate().all(|(i, curve)| !curves[..i].contains(curve)));
+    assert_eq!(curves.len(), 8);
+}
+
+/// The id [`ToyCurve`] is registered under.
+const TOY_CURVE_ID: u32 = 0x70;
+
+/// A custom curve that is secp256k1 under another name. Its own additions and doublings are
+/// unreachable, so that a test fails unless the registered implementation is used.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct ToyCurve;
+
+impl EllipticCurveParameters for ToyCurve {
+    type BaseField = Secp256k1BaseField;
+
+    const CURVE_TYPE: CurveType = CurveType::Custom(TOY_CURVE_ID);
+}
+
+fn to_toy(point: &AffinePoint<Secp256k1>) -> AffinePoint<ToyCurve> {
+    AffinePoint::new(point.x.clone(), point.y.clone())
+}
+
+fn to_secp256k1(point: &AffinePoint<ToyCurve>) -> AffinePoint<Secp256k1> {
+    AffinePoint::new(point.x.clone(), point.y.clone())
+}
+
+impl EllipticCurve for ToyCurve {
+    const IDENTITY_ENCODING: IdentityEncoding = IdentityEncoding::Zero;
+
+    fn ec_add(_: &AffinePoint<Self>, _: &AffinePoint<Self>) -> AffinePoint<Self> {
+        unreachable!("custom curves are added by their registered implementation")
+    }
+
+    fn ec_double(_: &AffinePoint<Self>) -> AffinePoint<Self> {
+        unreachable!("custom curves are doubled by their registered implementation")
+    }
+
+    fn ec_generator() -> AffinePoint<Self> {
+        to_toy(&Secp256k1::ec_generator())
+    }
+
+    fn ec_neutral() -> Option<AffinePoint<Self>> {
+        None
+    }
+
+    fn ec_neg(p: &AffinePoint<Self>) -> AffinePoint<Self> {
+        to_toy(&Secp256k1::ec_neg(&to_secp256k1(p)))
+    }
+
+    fn ec_is_on_curve(p: &AffinePoint<Self>) -> bool {
+        Secp256k1::ec_is_on_curve(&to_secp256k1(p))
+    }
+}
+
+/// The registered implementation of [`ToyCurve`].
+struct ToyCurveImpl;
+
+impl CustomCurve for ToyCurveImpl {
+    fn prime_group_order(&self) -> BigUint {
+        CurveType::Secp256k1.prime_group_order()
+    }
+
+    fn add(&self, p: &CustomPoint, q: &CustomPoint) -> CustomPoint {
+        let p = AffinePoint::<Secp256k1>::new(p.0.clone(), p.1.clone());
+        let q = AffinePoint::<Secp256k1>::new(q.0.clone(), q.1.clone());
+        let sum = Secp256k1::ec_add(&p, &q);
+        (sum.x, sum.y)
+    }
+
+    fn double(&self, p: &CustomPoint) -> CustomPoint {
+        let double = Secp256k1::ec_double(&AffinePoint::new(p.0.clone(), p.1.clone()));
+        (double.x, double.y)
+    }
+
+    fn decompress(&self, bytes_be: &[u8], sign_bit: u32) -> Option<CustomPoint> {
+        let point = secp256k1_decompress::<Secp256k1>(bytes_be, sign_bit);
+        Some((point.x, point.y))
+    }
+
+    fn sqrt(&self, square: &BigUint) -> Option<BigUint> {
+        sqrt_3mod4(square, &Secp256k1BaseField::modulus())
+    }
+}
+
+#[test]
+fn test_custom_curve() {
+    assert_eq!(CurveType::Custom(TOY_CURVE_ID).to_string(), "Custom(112)");
+
+    let generator = Secp256k1::ec_generator();
+    let double = Secp256k1::ec_double(&generator);
+    let context = SP1Context::builder().custom_curve(TOY_CURVE_ID, Arc::new(ToyCurveImpl)).build();
+    let program = Arc::new(Program::new(vec![], 0, 0));
+    let mut rt = Executor::with_context(program, SP1CoreOpts::default(), context);
+    rt.state.clk = 8;
+    write_words(&mut rt, 0x1000, &generator.to_words_le());
+    write_words(&mut rt, 0x2000, &double.to_words_le());
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    create_ec_add_event::<ToyCurve, Trace>(&mut ctx, 0x1000, 0x2000);
+    create_ec_double_event::<ToyCurve, Trace>(&mut ctx, 0x2000, 0);
+    let triple = Secp256k1::ec_add(&generator, &double);
+    assert_eq!(read_words(&mut rt, 0x1000, 8), triple.to_words_le());
+    assert_eq!(read_words(&mut rt, 0x2000, 8), Secp256k1::ec_double(&double).to_words_le());
+
+    // `P + P` and `P + (-P)` are still handled by the executor.
+    write_words(&mut rt, 0x2000, &generator.to_words_le());
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    create_ec_add_event::<ToyCurve, Trace>(&mut ctx, 0x2000, 0x2000);
+    assert_eq!(read_words(&mut rt, 0x2000, 8), double.to_words_le());
+
+    let words = generator.to_words_le();
+    write_words(&mut rt, 0x3020, &words[..4]);
+    let sign_bit = u64::from(generator.y.bit(0));
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_decompress_event::<ToyCurve, Trace>(&mut ctx, 0x3000, sign_bit);
+    assert_eq!(read_words(&mut rt, 0x3000, 4), words[4..]);
+
+    // The sign correction is taken from the root of the registered implementation.
+    write_words(&mut rt, 0x3020, &words[..4]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let expected = create_ec_decompress_event::<Secp256k1, Trace>(&mut ctx, 0x3000, sign_bit);
+    assert_eq!(event.sign_corrected, expected.sign_corrected);
+}
+
+#[test]
+fn test_unregistered_custom_curve() {
+    let generator = Secp256k1::ec_generator();
+    let mut rt = executor_with(&[
+        (0x1000, &generator.to_words_le()),
+        (0x2000, &Secp256k1::ec_double(&generator).to_words_le()),
+    ]);
+    let expected = EcSyscallError::UnregisteredCurve(TOY_CURVE_ID);
+
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let add = try_create_ec_add_event::<ToyCurve, Trace>(&mut ctx, 0x1000, 0x2000);
+    assert_eq!(add.unwrap_err(), expected);
+    let double = try_create_ec_double_event::<ToyCurve, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(double.unwrap_err(), expected);
+    let decompress = try_create_ec_decompress_event::<ToyCurve, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(decompress.unwrap_err(), expected);
+    assert!(ctx.postprocess().0.is_empty());
+}
+
+#[test]
+fn test_secp256k1_decompress_byte_order() {
+    let generator = Secp256k1::ec_generator();
+    let words = generator.to_words_le();
+    let sign_bit = u64::from(generator.y.bit(0));
+
+    let (event, mut rt) = with_ctx(&[(0x1020, &words[..4])], |ctx| {
+        create_ec_decompress_event::<Secp256k1, Trace>(ctx, 0x1000, sign_bit)
+    });
+
+    // Both coordinates are kept little-endian, exactly as they appear in memory.
+    assert_eq!(event.x_bytes, words_to_bytes_le_vec(&words[..4]));
+    assert_eq!(event.decompressed_y_bytes, words_to_bytes_le_vec(&words[4..]));
+    assert_eq!(read_words(&mut rt, 0x1000, 4), words[4..]);
+}
+
+#[test]
+fn test_merge_local_accesses() {
+    let mem_access = |addr, initial: u64, last: u64| MemoryLocalEvent {
+        addr,
+        initial_mem_access: MemoryRecord { timestamp: initial, value: initial },
+        final_mem_access: MemoryRecord { timestamp: last, value: last },
+    };
+    let page_prot_access = |page_idx, initial, last| PageProtLocalEvent {
+        page_idx,
+        initial_page_prot_access: PageProtRecord { timestamp: initial, ..Default::default() },
+        final_page_prot_access: PageProtRecord { timestamp: last, ..Default::default() },
+    };
+    let first = EllipticCurveAddEvent {
+        local_mem_access: vec![mem_access(0x1000, 1, 4), mem_access(0x2000, 1, 4)],
+        local_page_prot_access: vec![page_prot_access(1, 1, 4)],
+        ..Default::default()
+    };
+    let second = EllipticCurveAddEvent {
+        local_mem_access: vec![mem_access(0x3000, 5, 8), mem_access(0x1000, 5, 8)],
+        local_page_prot_access: vec![page_prot_access(3, 5, 8), page_prot_access(1, 5, 8)],
+        ..Default::default()
+    };
+
+    let (mem, page_prot) = merge_local_accesses(&[&second, &first]);
+
+    // `0x1000` and page 1 are touched by both events and merged, the others are kept as is.
+    let mem = mem
+        .iter()
+        .map(|a| (a.addr, a.initial_mem_access.value, a.final_mem_access.value))
+        .collect::<Vec<_>>();
+    assert_eq!(mem, [(0x1000, 1, 8), (0x2000, 1, 4), (0x3000, 5, 8)]);
+    let page_prot = page_prot
+        .iter()
+        .map(|a| {
+            let (initial, last) = (a.initial_page_prot_access, a.final_page_prot_access);
+            (a.page_idx, initial.timestamp, last.timestamp)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(page_prot, [(1, 1, 8), (3, 5, 8)]);
+
+    let (mem, page_prot) = merge_local_accesses(&[]);
+    assert!(mem.is_empty() && page_prot.is_empty());
+}
+
+#[test]
+fn test_repeated_double() {
+    let generator = Secp256k1::ec_generator().to_words_le();
+    let mut rt = executor_with(&[(0x1000, &generator), (0x2000, &generator)]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    for _ in 0..3 {
+        create_ec_double_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0);
+    }
+    let event = create_ec_repeated_double_event::<Secp256k1, Trace>(&mut ctx, 0x2000, 3);
+    assert_eq!(read_words(&mut rt, 0x2000, 8), read_words(&mut rt, 0x1000, 8));
+    assert_eq!(event.p, generator);
+    assert_eq!(event.p_memory_records.len(), generator.len());
+    assert!(!event.result_is_identity);
+
+    // The quota counts every doubling.
+    let mut rt = executor_with(&[(0x1000, &generator)]);
+    let mut ctx = SyscallContext::<QuotaTrace>::new(&mut rt, true);
+    assert_eq!(
+        try_create_ec_repeated_double_event::<Secp256k1, QuotaTrace>(&mut ctx, 0x1000, 3)
+            .unwrap_err(),
+        EcSyscallError::QuotaExceeded { max: 2 }
+    );
+    create_ec_repeated_double_event::<Secp256k1, QuotaTrace>(&mut ctx, 0x1000, 2);
+    assert_eq!(ctx.rt.local_counts.ec_ops, 2);
+
+    // Without a quota, the number of doublings is still capped.
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    assert_eq!(
+        try_create_ec_repeated_double_event::<Secp256k1, Trace>(&mut ctx, 0x1000, u64::MAX)
+            .unwrap_err(),
+        EcSyscallError::TooManyOperations { count: u64::MAX, max: MAX_EC_REPEATED_DOUBLINGS }
+    );
+}
+
+#[test]
+fn test_decompress_writes_only_y() {
+    let generator = Secp256k1::ec_generator();
+    let words = generator.to_words_le();
+    let mut rt = executor_with(&[(0x1000, &[u64::MAX; 4]), (0x1020, &words[..4])]);
+
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let sign_bit = u64::from(generator.y.bit(0));
+    let event = create_ec_decompress_event::<Secp256k1, Trace>(&mut ctx, 0x1000, sign_bit);
+
+    assert_eq!(read_words(&mut rt, 0x1020, 4), words[..4]);
+    assert_eq!(read_words(&mut rt, 0x1000, 4), words[4..]);
+    assert_eq!(event.y_memory_records.len(), 4);
+    for (record, word) in event.y_memory_records.iter().zip(&words[4..]) {
+        assert_eq!(record.prev_value, u64::MAX);
+        assert_eq!(record.value, *word);
+    }
+}
+
+#[test]
+fn test_inline_events_round_trip() {
+    let generator = Secp256k1::ec_generator();
+    let (event, _) = with_ctx(
+        &[(0x1000, &generator.to_words_le()), (0x2000, &generator.sw_double().to_words_le())],
+        |ctx| create_ec_add_event::<Secp256k1, Trace>(ctx, 0x1000, 0x2000),
+    );
+
+    let inline = EllipticCurveAddEventN::<8>::try_from(event.clone()).unwrap();
+    assert_eq!(inline.p.as_slice(), event.p.as_slice());
+    assert_eq!(inline.q.as_slice(), event.q.as_slice());
+    // The inline event does not allocate for the points and their records.
+    assert!(inline.deep_size_of() < event.deep_size_of());
+
+    let round_trip = EllipticCurveAddEvent::from(inline);
+    assert_eq!(round_trip.p, event.p);
+    assert_eq!(round_trip.q, event.q);
+    assert_eq!(round_trip.p_memory_records.len(), 8);
+    assert_eq!(round_trip.q_memory_records.len(), 8);
+
+    let err = EllipticCurveAddEventN::<12>::try_from(event.clone()).unwrap_err();
+    assert_eq!(err.p, event.p);
+
+    let (event, _) = with_ctx(&[(0x1000, &generator.to_words_le())], |ctx| {
+        create_ec_double_event::<Secp256k1, Trace>(ctx, 0x1000, 0)
+    });
+
+    let inline = EllipticCurveDoubleEventN::<8>::try_from(event.clone()).unwrap();
+    assert!(inline.deep_size_of() < event.deep_size_of());
+    assert_eq!(EllipticCurveDoubleEvent::from(inline).p, event.p);
+    assert!(EllipticCurveDoubleEventN::<4>::try_from(event).is_err());
+}
+
+fn check_field_inverse<E: EllipticCurve>() {
+    let modulus = E::BaseField::modulus();
+    let num_words = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+    let num_bytes = num_words * 8;
+
+    // Spread pseudo-random elements over the field, plus the edge cases one and `p - 1`.
+    let seed = BigUint::from(0x9e37_79b9_7f4a_7c15u64);
+    let mut elements: Vec<BigUint> = (1u32..8).map(|i| (&seed * i).pow(13) % &modulus).collect();
+    elements.extend([BigUint::from(1u32), &modulus - 1u32]);
+
+    for x in elements {
+        let mut x_bytes = x.to_bytes_le();
+        x_bytes.resize(num_bytes, 0);
+        let (event, mut rt) = with_ctx(&[(0x1000, &bytes_to_words_le_vec(&x_bytes))], |ctx| {
+            create_field_inverse_event::<E, Trace>(ctx, 0x1000, 0)
+        });
+        assert_eq!(event.x_memory_records.len(), num_words);
+        // The element is read and overwritten with its inverse by the same records.
+        assert!(event
+            .x_memory_records
+            .iter()
+            .zip(event.x.iter().zip(&event.inverse))
+            .all(|(record, (&x, &inverse))| record.prev_value == x && record.value == inverse));
+
+        let inverse = read_words(&mut rt, 0x1000, num_words);
+        assert_eq!(inverse, event.inverse);
+        let inverse = BigUint::from_bytes_le(&words_to_bytes_le_vec(&inverse));
+        assert_eq!((inverse * &x) % &modulus, BigUint::from(1u32));
+    }
+}
+
+curve_test!(test_field_inverse, check_field_inverse: Secp256k1, Bn254, Bls12381, Ed25519);
+
+#[test]
+fn test_field_inverse_zero() {
+    let (_, mut rt) = with_ctx(&[(0x1000, &[0; 4])], |ctx| {
+        create_field_inverse_event::<Bn254, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(read_words(&mut rt, 0x1000, 4), vec![0; 4]);
+}
+
+fn reduce_scalar<E: EllipticCurve>(k: &BigUint, num_words: usize) -> BigUint {
+    let mut bytes = k.to_bytes_le();
+    bytes.resize(num_words * 8, 0);
+    let words = bytes_to_words_le_vec(&bytes);
+
+    let (event, mut rt) = with_ctx(&[(0x1000, &words)], |ctx| {
+        create_scalar_reduce_event::<E, Trace>(ctx, 0x1000, num_words as u64)
+    });
+    assert_eq!(event.scalar, words);
+    assert_eq!(event.scalar_memory_records.len(), num_words);
+    assert_eq!(read_words(&mut rt, 0x1000, num_words), event.reduced);
+    BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.reduced))
+}
+
+fn check_scalar_reduce<E: EllipticCurve>() {
+    let n = E::CURVE_TYPE.prime_group_order();
+
+    // Just above the order, and 512-bit values well above it.
+    assert_eq!(reduce_scalar::<E>(&n, 4), BigUint::ZERO);
+    assert_eq!(reduce_scalar::<E>(&(&n + 5u32), 4), BigUint::from(5u32));
+    assert_eq!(reduce_scalar::<E>(&(&n * 3u32 + 7u32), 8), BigUint::from(7u32));
+    let wide = (BigUint::from(1u32) << 512) - 1u32;
+    assert_eq!(reduce_scalar::<E>(&wide, 8), &wide % &n);
+
+    // Reduced scalars and scalars narrower than the order are unchanged.
+    assert_eq!(reduce_scalar::<E>(&(&n - 1u32), 4), &n - 1u32);
+    assert_eq!(reduce_scalar::<E>(&BigUint::from(u64::MAX), 1), BigUint::from(u64::MAX));
+}
+
+curve_test!(test_scalar_reduce, check_scalar_reduce: Secp256k1, Bn254, Ed25519);
+
+#[test]
+fn test_scalar_reduce_bounds_length() {
+    for num_words in [0, MAX_SCALAR_REDUCE_WORDS + 1, u64::MAX] {
+        check_fails_without_access::<QuotaTrace, _>(
+            &[],
+            |ctx| try_create_scalar_reduce_event::<Secp256k1, QuotaTrace>(ctx, 0x1000, num_words),
+            EcSyscallError::InvalidNumWords { num_words, max: MAX_SCALAR_REDUCE_WORDS },
+        );
+    }
+
+    // The length is checked before the operation is counted against the quota.
+    let mut rt = executor();
+    let mut ctx = SyscallContext::<QuotaTrace>::new(&mut rt, true);
+    assert!(try_create_scalar_reduce_event::<Secp256k1, QuotaTrace>(&mut ctx, 0x1000, 0).is_err());
+    assert_eq!(ctx.rt.local_counts.ec_ops, 0);
+}
+
+#[test]
+fn test_secp256k1_add_batch_matches_repeated_add() {
+    let generator = Secp256k1::ec_generator();
+    let mut points = vec![generator.clone(), Secp256k1::ec_double(&generator)];
+    for _ in 2..10 {
+        points.push(Secp256k1::ec_add(points.last().unwrap(), &generator));
+    }
+
+    for count in [0, 1, 10] {
+        let words: Vec<u64> = points[..count].iter().flat_map(AffinePoint::to_words_le).collect();
+
+        // Sum the points with one add event per point, starting from the identity.
+        let mut rt = executor_with(&[(0x1000, &[0; 8]), (0x2000, &words)]);
+        for i in 0..count as u64 {
+            let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+            create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x2000 + i * 64);
+            rt.state.clk += 8;
+        }
+        let expected = read_words(&mut rt, 0x1000, 8);
+
+        let (event, mut rt) = with_ctx(&[(0x2000, &words)], |ctx| {
+            create_ec_add_batch_event::<Secp256k1, Trace>(ctx, 0x2000, 0x1000, count as u64)
+        });
+        assert_eq!(event.points_memory_records.len(), 8 * count);
+        assert_eq!(event.result, expected);
+        assert_eq!(event.result_memory_records[0].timestamp, 8 + count as u64);
+        assert_eq!(read_words(&mut rt, 0x1000, 8), expected);
+    }
+}
+
+#[test]
+fn test_add_batch_bounds_count() {
+    check_fails_without_access::<Trace, _>(
+        &[],
+        |ctx| try_create_ec_add_batch_event::<Secp256k1, Trace>(ctx, 0x2000, 0x1000, u64::MAX),
+        EcSyscallError::TooManyOperations { count: u64::MAX, max: MAX_EC_ADD_BATCH_POINTS },
+    );
+    check_fails_without_access::<Trace, _>(
+        &[],
+        |ctx| try_create_ec_add_batch_event::<Secp256k1, Trace>(ctx, u64::MAX - 7, 0x1000, 2),
+        EcSyscallError::AddressOverflow { name: "base_ptr", ptr: u64::MAX - 7 },
+    );
+
+    // Three points are three operations, which the quota of two does not allow.
+    let words = Secp256k1::ec_generator().to_words_le().repeat(3);
+    check_fails_without_access::<QuotaTrace, _>(
+        &[(0x2000, &words)],
+        |ctx| try_create_ec_add_batch_event::<Secp256k1, QuotaTrace>(ctx, 0x2000, 0x1000, 3),
+        EcSyscallError::QuotaExceeded { max: 2 },
+    );
+}
+
+#[test]
+fn test_decompress_invalid_sign_bit() {
+    fn assert_error<T: std::error::Error + Clone + Send + Sync + 'static>() {}
+    assert_error::<EcSyscallError>();
+
+    let (err, _) = with_ctx(&[(0x1020, &Secp256k1::ec_generator().to_words_le()[..4])], |ctx| {
+        try_create_ec_decompress_event::<Secp256k1, Trace>(ctx, 0x1000, 2).unwrap_err()
+    });
+    assert_eq!(err, EcSyscallError::InvalidSignBit(2));
+    assert_eq!(err.to_string(), "sign bit must be 0 or 1, got 2");
+}
+
+#[test]
+fn test_decompress_mem_sign() {
+    let generator = Secp256k1::ec_generator();
+    let neg_y = Secp256k1BaseField::modulus() - &generator.y;
+    for (sign_bit, y) in [(0, &generator.y), (1, &neg_y)] {
+        let (event, mut rt) =
+            with_ctx(&[(0x1020, &generator.to_words_le()[..4]), (0x3000, &[sign_bit])], |ctx| {
+                create_ec_decompress_mem_sign_event::<Secp256k1, Trace>(ctx, 0x1000, 0x3000)
+            });
+        assert_eq!(event.sign_memory_record.value, sign_bit);
+        // The sign bit is read between the compressed coordinate and the write.
+        assert_eq!(event.decompress.x_memory_records[0].timestamp, 8);
+        assert_eq!(event.sign_memory_record.timestamp, 9);
+        assert_eq!(event.decompress.y_memory_records[0].timestamp, 10);
+        assert_eq!(event.decompress.sign_bit, sign_bit == 1);
+        assert_eq!(event.memory_records().count(), 9);
+        assert!(event.local_mem_access().iter().any(|local| local.addr == 0x3000));
+        let y_words = read_words(&mut rt, 0x1000, 4);
+        assert_eq!(&BigUint::from_bytes_le(&words_to_bytes_le_vec(&y_words)), y);
+    }
+
+    let mut rt = executor_with(&[(0x1020, &generator.to_words_le()[..4]), (0x3000, &[2])]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let err = try_create_ec_decompress_mem_sign_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x3000)
+        .unwrap_err();
+    assert_eq!(err, EcSyscallError::InvalidSignBit(2));
+    assert_eq!(read_words(&mut rt, 0x1000, 4), [0; 4]);
+
+    // A valid sign bit is not read either when the decompression fails, or when it lies in the
+    // slice.
+    check_fails_without_access::<Trace, _>(
+        &[(0x1020, &[4, 0, 0, 0]), (0x3000, &[0])],
+        |ctx| try_create_ec_decompress_mem_sign_event::<Bn254, Trace>(ctx, 0x1000, 0x3000),
+        EcSyscallError::NonResidue,
+    );
+    check_fails_without_access::<Trace, _>(
+        &[(0x1020, &generator.to_words_le()[..4])],
+        |ctx| try_create_ec_decompress_mem_sign_event::<Secp256k1, Trace>(ctx, 0x1000, 0x1038),
+        EcSyscallError::Overlap { name: "sign_ptr", ptr: 0x1038 },
+    );
+}
+
+#[test]
+fn test_event_cycles() {
+    let generator = Bn254::ec_generator();
+    let mut rt = executor_with(&[
+        (0x1000, &generator.to_words_le()),
+        (0x2000, &Bn254::ec_double(&generator).to_words_le()),
+    ]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_add_event::<Bn254, Trace>(&mut ctx, 0x1000, 0x2000);
+    // The add advances the clock once between reading q and writing p.
+    assert_eq!(event.cycles, 1);
+    assert_eq!(ctx.clk, event.clk + event.cycles);
+
+    let (event, _) = with_ctx(&[(0x1000, &generator.to_words_le())], |ctx| {
+        create_ec_double_event::<Bn254, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(event.cycles, 0);
+
+    let (event, _) = with_ctx(&[(0x1020, &generator.to_words_le()[..4])], |ctx| {
+        create_ec_decompress_event::<Bn254, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(event.cycles, 1);
+}
+
+type Bls12381G2 = G2AffinePoint<Bls12381G2Parameters>;
+
+fn bls12381_g2_point(coeffs: [&str; 4]) -> Bls12381G2 {
+    let [x0, x1, y0, y1] = coeffs.map(biguint);
+    Bls12381G2::new(Fp2::<Bls12381BaseField>::new(x0, x1), Fp2::new(y0, y1))
+}
+
+#[test]
+fn test_bls12381_g2_double_generator() {
+    let generator = Bls12381G2Parameters::generator();
+    let (event, mut rt) = with_ctx(&[(0x1000, &generator.to_words_le())], |ctx| {
+        create_g2_double_event::<Bls12381G2Parameters, Trace>(ctx, 0x1000, 0)
+    });
+    // Each coordinate is 96 bytes, two 48-byte base field elements.
+    assert_eq!(event.p_memory_records.len(), 24);
+
+    let expected = bls12381_g2_point([
+        "3419974069068927546093595533691935972093267703063689549934039433172037728172434967174817854768758291501458544631891",
+        "1586560233067062236092888871453626466803933380746149805590083683748120990227823365075019078675272292060187343402359",
+        "678774053046495337979740195232911687527971909891867263302465188023833943429943242788645503130663197220262587963545",
+        "2374407843478705782611042739236452317510200146460567463070514850492917978226342495167066333366894448569891658583283",
+    ]);
+    assert_eq!(read_words(&mut rt, 0x1000, 24), expected.to_words_le());
+}
+
+#[test]
+fn test_bls12381_g2_add() {
+    let generator = Bls12381G2Parameters::generator();
+    let doubled = generator.g2_double().unwrap();
+    let (event, mut rt) =
+        with_ctx(&[(0x1000, &generator.to_words_le()), (0x2000, &doubled.to_words_le())], |ctx| {
+            create_g2_add_event::<Bls12381G2Parameters, Trace>(ctx, 0x1000, 0x2000)
+        });
+    assert_eq!(event.q_memory_records.len(), 24);
+
+    let expected = bls12381_g2_point([
+        "2795155019138475430256695697248607867022196082692926850257941893956680503583886174445899854256891620515274933186478",
+        "1418901263980595683832511076652430035654903023556505873032297534993731256453342997202098832403658787934376638965468",
+        "1713408536894110516522969272885192173669900392782465197506312048399987681703463801235485042423756235640603447122066",
+        "1376945178829045108008380835987620979304438294788415956605678509674588356753313865659068546846109894276784773457993",
+    ]);
+    assert_eq!(read_words(&mut rt, 0x1000, 24), expected.to_words_le());
+}
+
+#[test]
+fn test_bls12381_g2_add_identity() {
+    let generator = Bls12381G2Parameters::generator();
+    let (_, mut rt) = with_ctx(
+        &[(0x1000, &generator.to_words_le()), (0x2000, &generator.g2_neg().to_words_le())],
+        |ctx| create_g2_add_event::<Bls12381G2Parameters, Trace>(ctx, 0x1000, 0x2000),
+    );
+    assert_eq!(read_words(&mut rt, 0x1000, 24), vec![0; 24]);
+}
+
+#[test]
+fn test_g2_psi() {
+    let generator = Bls12381G2Parameters::generator();
+    let (event, mut rt) = with_ctx(&[(0x1000, &generator.to_words_le())], |ctx| {
+        create_g2_psi_event::<Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(event.p, generator.to_words_le());
+    assert_eq!(event.p_memory_records.len(), 24);
+
+    // psi is the multiplication by the BLS parameter `z = -0xd201000000010000` on G2, and psi^2
+    // by `z^2`.
+    let r = CurveType::Bls12381.prime_group_order();
+    let z = &r - BigUint::from(0xd201000000010000u64);
+    let psi = generator.g2_mul(&z).unwrap();
+    assert_eq!(read_words(&mut rt, 0x1000, 24), psi.to_words_le());
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    create_g2_psi_event::<Trace>(&mut ctx, 0x1000, 0);
+    let psi2 = generator.g2_mul(&(&z * &z % &r)).unwrap();
+    assert_eq!(read_words(&mut rt, 0x1000, 24), psi2.to_words_le());
+
+    write_words(&mut rt, 0x2000, &[0; 24]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    create_g2_psi_event::<Trace>(&mut ctx, 0x2000, 0);
+    assert_eq!(read_words(&mut rt, 0x2000, 24), vec![0; 24]);
+}
+
+fn check_read_affine_point<E: EllipticCurve>() {
+    let point = E::ec_generator();
+    let mut rt = executor();
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    ctx.mw_slice(0x1000, &point.to_words_le(), false);
+    ctx.clk += 1;
+
+    let (read, records, _) = ctx.read_affine_point::<E>(0x1000);
+    assert_eq!(read.x, point.x);
+    assert_eq!(read.y, point.y);
+    assert_eq!(records.len(), <E::BaseField as NumWords>::WordsCurvePoint::USIZE);
+    assert!(records.iter().zip(point.to_words_le()).all(|(record, word)| record.value == word));
+}
+
+curve_test!(
+    test_read_affine_point_round_trip,
+    check_read_affine_point: Ed25519, Bn254, Secp256k1, Bls12381
+);
+
+fn check_write_affine_point<E: EllipticCurve>() {
+    let point = E::ec_double(&E::ec_generator());
+    let num_words = <E::BaseField as NumWords>::WordsCurvePoint::USIZE;
+    let mut rt = executor();
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let (records, _) = ctx.write_affine_point(0x1000, &point, false);
+    assert_eq!(records.len(), num_words);
+
+    let written = AffinePoint::<E>::from_words_le(&read_words(&mut rt, 0x1000, num_words));
+    assert_eq!(written.x, point.x);
+    assert_eq!(written.y, point.y);
+}
+
+curve_test!(
+    test_write_affine_point_round_trip,
+    check_write_affine_point: Ed25519, Bn254, Secp256r1, Bls12381
+);
+
+#[test]
+fn test_secp256k1_decompress_sign_corrected() {
+    let generator = Secp256k1::ec_generator();
+    let words = generator.to_words_le();
+    let neg_y = Secp256k1BaseField::modulus() - &generator.y;
+
+    // The canonical root of the generator's y^2 is the generator's own, even, y coordinate.
+    for (sign_bit, sign_corrected, y) in [(0, false, generator.y.clone()), (1, true, neg_y)] {
+        let (event, _) = with_ctx(&[(0x1020, &words[..4])], |ctx| {
+            create_ec_decompress_event::<Secp256k1, Trace>(ctx, 0x1000, sign_bit)
+        });
+        assert_eq!(event.sign_corrected, sign_corrected);
+        assert_eq!(BigUint::from_bytes_le(&event.decompressed_y_bytes), y);
+    }
+}
+
+#[test]
+fn test_add_without_page_prot_records() {
+    let generator = Secp256k1::ec_generator().to_words_le();
+    let doubled = Secp256k1::ec_double(&Secp256k1::ec_generator()).to_words_le();
+
+    // The flag only matters to a program that enables untrusted programs, which the executor
+    // would refuse to run, so the context is driven directly.
+    let mut program = Program::new(vec![], 0, 0);
+    program.enable_untrusted_programs = true;
+    let mut rt = Executor::new(Arc::new(program), SP1CoreOpts::default());
+    rt.state.clk = 8;
+    write_words(&mut rt, 0x1000, &generator);
+    write_words(&mut rt, 0x2000, &doubled);
+
+    let mut ctx = SyscallContext::<PageProtFreeTrace>::new(&mut rt, true);
+    let event = create_ec_add_event::<Secp256k1, PageProtFreeTrace>(&mut ctx, 0x1000, 0x2000);
+
+    assert!(event.page_prot_records.read_page_prot_records.is_empty());
+    assert!(event.page_prot_records.write_page_prot_records.is_empty());
+    assert!(event.local_page_prot_access.is_empty());
+
+    // Single word accesses skip them too.
+    let mut ctx = SyscallContext::<PageProtFreeTrace>::new(&mut rt, true);
+    assert!(ctx.mr(0x3000).0.prev_page_prot_record.is_none());
+    assert!(ctx.mw(0x3008, 1, true).prev_page_prot_record.is_none());
+    assert!(ctx.postprocess().1.is_empty());
+
+    assert_eq!(read_words(&mut rt, 0x1000, 8), add_words::<Secp256k1>(&generator, &doubled));
+}
+
+#[test]
+fn test_secp256k1_glv_matches_double_and_add() {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let generator = Secp256k1::ec_generator();
+    let point = Secp256k1::ec_double(&generator);
+    for i in 0u32..1000 {
+        // Deterministic pseudo-random 256-bit scalars.
+        let mut hasher = Keccak::v256();
+        hasher.update(&i.to_le_bytes());
+        let mut bytes = [0u8; 32];
+        hasher.finalize(&mut bytes);
+        let k = BigUint::from_bytes_le(&bytes);
+
+        let glv = secp256k1_glv_scalar_mul(&point, &k).unwrap();
+        let naive = ec_scalar_mul(&point, &k).unwrap();
+        assert_eq!((glv.x, glv.y), (naive.x, naive.y), "scalar {k}");
+    }
+
+    let order = Secp256k1Parameters::prime_group_order();
+    assert!(secp256k1_glv_scalar_mul(&generator, &order).is_none());
+    assert!(secp256k1_glv_scalar_mul(&generator, &BigUint::ZERO).is_none());
+}
+
+#[test]
+fn test_add_result_is_identity() {
+    let generator = Secp256k1::ec_generator();
+    let neg_generator = AffinePoint::<Secp256k1>::new(
+        generator.x.clone(),
+        Secp256k1BaseField::modulus() - &generator.y,
+    );
+    let doubled = Secp256k1::ec_double(&generator);
+
+    for (q, result_is_identity) in [(neg_generator, true), (doubled, false)] {
+        let (event, _) =
+            with_ctx(&[(0x1000, &generator.to_words_le()), (0x2000, &q.to_words_le())], |ctx| {
+                create_ec_add_event::<Secp256k1, Trace>(ctx, 0x1000, 0x2000)
+            });
+        assert_eq!(event.result_is_identity, result_is_identity);
+
+        let event = EllipticCurveAddEventN::<8>::try_from(event).unwrap();
+        assert_eq!(event.result_is_identity, result_is_identity);
+    }
+
+    with_ctx(&[(0x1000, &generator.to_words_le())], |ctx| {
+        assert!(!create_ec_double_event::<Secp256k1, Trace>(ctx, 0x1000, 0).result_is_identity)
+    });
+}
+
+#[test]
+fn test_jubjub_add_and_double() {
+    let generator = Jubjub::ec_generator();
+    let doubled = AffinePoint::<Jubjub>::new(
+        biguint("51649308687106732245355180334412183425352679808664353824402608302951341127598"),
+        biguint("42341045032719442200023627857290420958524899951475010660656175327133162357681"),
+    );
+    let tripled = AffinePoint::<Jubjub>::new(
+        biguint("42747876515219196055051085994946250245437820367382162892902507989107803619675"),
+        biguint("47330627123043138289319845557100304432416973485572947695231610461184513390725"),
+    );
+
+    let (event, mut rt) = with_ctx(&[(0x1000, &generator.to_words_le())], |ctx| {
+        create_ec_double_event::<Jubjub, Trace>(ctx, 0x1000, 0)
+    });
+    assert!(!event.result_is_identity);
+    assert_eq!(read_words(&mut rt, 0x1000, 8), doubled.to_words_le());
+
+    assert_eq!(
+        add_words::<Jubjub>(&doubled.to_words_le(), &generator.to_words_le()),
+        tripled.to_words_le()
+    );
+}
+
+fn ed25519_decompress_words(y: &BigUint, sign_bit: u64) -> Result<Vec<u64>, EcSyscallError> {
+    let mut rt = executor();
+    let mut bytes = y.to_bytes_le();
+    bytes.resize(32, 0);
+    write_words(&mut rt, 0x1020, &bytes_to_words_le_vec(&bytes));
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = try_create_ec_decompress_event::<Ed25519, Trace>(&mut ctx, 0x1000, sign_bit)?;
+    assert_eq!(event.x_bytes, bytes);
+    Ok(read_words(&mut rt, 0x1000, 4))
+}
+
+#[test]
+fn test_ed25519_decompress() {
+    // Multiples of the generator, whose x coordinate is recovered from y and its parity.
+    let mut point = Ed25519::ec_generator();
+    for _ in 0..8 {
+        let words = point.to_words_le();
+        let sign_bit = u64::from(point.x.bit(0));
+        assert_eq!(ed25519_decompress_words(&point.y, sign_bit).unwrap(), words[..4]);
+        point = Ed25519::ec_double(&point);
+    }
+
+    // The edge cases of RFC 8032: `y = ±1` have `x = 0`, which rejects a set sign bit, and
+    // non-canonical `y` are rejected even without input validation.
+    let p = Ed25519BaseField::modulus();
+    for y in [BigUint::from(1u32), &p - 1u32] {
+        assert_eq!(ed25519_decompress_words(&y, 0).unwrap(), vec![0; 4]);
+        assert_eq!(ed25519_decompress_words(&y, 1), Err(EcSyscallError::NegativeZero));
+    }
+    for y in [p.clone(), &p + 1u32, (BigUint::from(1u32) << 255) - 1u32] {
+        assert_eq!(ed25519_decompress_words(&y, 0), Err(EcSyscallError::NonCanonical));
+    }
+
+    // `y = 2` has no point.
+    let result = ed25519_decompress_words(&BigUint::from(2u32), 0);
+    assert_eq!(result, Err(EcSyscallError::NonResidue));
+}
+
+#[test]
+fn test_jubjub_decompress() {
+    let generator = Jubjub::ec_generator();
+    let words = generator.to_words_le();
+    let neg_x = JubjubBaseField::modulus() - &generator.x;
+
+    // The generator's x coordinate is odd.
+    for (sign_bit, x) in [(1, generator.x.clone()), (0, neg_x)] {
+        // Edwards points compress to y, which is stored in the upper half of the slice.
+        let (event, mut rt) = with_ctx(&[(0x1020, &words[4..])], |ctx| {
+            create_ec_decompress_event::<Jubjub, Trace>(ctx, 0x1000, sign_bit)
+        });
+        assert_eq!(event.x_bytes, words_to_bytes_le_vec(&words[4..]));
+        assert_eq!(BigUint::from_bytes_le(&event.decompressed_y_bytes), x);
+        assert_eq!(event.sign_corrected, sign_bit == 1);
+
+        let x_words = read_words(&mut rt, 0x1000, 4);
+        assert_eq!(x_words, AffinePoint::<Jubjub>::new(x, BigUint::ZERO).to_words_le()[..4]);
+    }
+}
+
+#[test]
+fn test_decompress_rejects_non_canonical_x() {
+    let modulus = Secp256k1BaseField::modulus();
+    for x in [modulus.clone(), modulus + 1u32] {
+        let mut rt = executor_with(&[(0x1020, &scalar_words(&x))]);
+
+        let mut ctx = SyscallContext::<ValidatingTrace>::new(&mut rt, true);
+        let result =
+            try_create_ec_decompress_event::<Secp256k1, ValidatingTrace>(&mut ctx, 0x1000, 0);
+        assert_eq!(result.unwrap_err(), EcSyscallError::NonCanonical);
+    }
+}
+
+fn negate_words<E: EllipticCurve>(p: &[u64]) -> Vec<u64> {
+    let (event, mut rt) =
+        with_ctx(&[(0x1000, p)], |ctx| create_ec_negate_event::<E, Trace>(ctx, 0x1000, 0));
+    assert_eq!(event.p, p);
+    assert_eq!(event.p_memory_records.len(), p.len());
+    read_words(&mut rt, 0x1000, p.len())
+}
+
+#[test]
+fn test_secp256k1_negate() {
+    let generator = Secp256k1::ec_generator();
+    let negated = negate_words::<Secp256k1>(&generator.to_words_le());
+    assert_eq!(negated, Secp256k1::ec_neg(&generator).to_words_le());
+    assert_eq!(add_words::<Secp256k1>(&generator.to_words_le(), &negated), vec![0; 8]);
+
+    // The point at infinity is its own negation.
+    assert_eq!(negate_words::<Secp256k1>(&[0; 8]), vec![0; 8]);
+}
+
+#[test]
+fn test_ed25519_negate() {
+    let generator = Ed25519::ec_generator();
+    let negated = negate_words::<Ed25519>(&generator.to_words_le());
+    assert_eq!(negated, Ed25519::ec_neg(&generator).to_words_le());
+    assert_eq!(
+        add_words::<Ed25519>(&generator.to_words_le(), &negated),
+        Ed25519::neutral().to_words_le()
+    );
+
+    let neutral = Ed25519::neutral().to_words_le();
+    assert_eq!(negate_words::<Ed25519>(&neutral), neutral);
+}
+
+fn point_and_negate_words<E: EllipticCurve>(p: &[u64]) -> Vec<u64> {
+    let (event, mut rt) = with_ctx(&[(0x1000, p), (0x2000, &vec![0xff; p.len()])], |ctx| {
+        create_ec_point_and_negate_event::<E, Trace>(ctx, 0x1000, 0x2000)
+    });
+    assert_eq!(event.p, p);
+    assert_eq!(event.p_memory_records.iter().map(|r| r.value).collect::<Vec<_>>(), p);
+    assert_eq!(event.neg_memory_records.len(), p.len());
+    assert!(event.neg_memory_records.iter().all(|r| r.prev_value == 0xff));
+    // The negation is written a cycle after the point is read, even to a disjoint range.
+    assert!(event.p_memory_records.iter().all(|r| r.timestamp == event.clk));
+    assert!(event.neg_memory_records.iter().all(|r| r.timestamp == event.clk + 1));
+    assert_eq!(event.neg_memory_records.iter().map(|r| r.value).collect::<Vec<_>>(), event.neg);
+    assert_eq!(event.memory_records().count(), 2 * p.len());
+    assert_eq!(read_words(&mut rt, 0x1000, p.len()), p);
+    assert_eq!(read_words(&mut rt, 0x2000, p.len()), event.neg);
+    event.neg
+}
+
+#[test]
+fn test_point_and_negate() {
+    let generator = Secp256k1::ec_generator();
+    let words = generator.to_words_le();
+    assert_eq!(point_and_negate_words::<Secp256k1>(&words), negate_words::<Secp256k1>(&words));
+    assert_eq!(point_and_negate_words::<Secp256k1>(&[0; 8]), vec![0; 8]);
+
+    let generator = Ed25519::ec_generator().to_words_le();
+    assert_eq!(point_and_negate_words::<Ed25519>(&generator), negate_words::<Ed25519>(&generator));
+    let neutral = Ed25519::neutral().to_words_le();
+    assert_eq!(point_and_negate_words::<Ed25519>(&neutral), neutral);
+
+    // Writing the negation over the point bumps the clk between the read and the write.
+    let (event, mut rt) = with_ctx(&[(0x1000, &words)], |ctx| {
+        create_ec_point_and_negate_event::<Secp256k1, Trace>(ctx, 0x1000, 0x1000)
+    });
+    assert!(event
+        .p_memory_records
+        .iter()
+        .zip(&event.neg_memory_records)
+        .all(|(read, write)| write.timestamp > read.timestamp && write.prev_value == read.value));
+    assert_eq!(read_words(&mut rt, 0x1000, 8), negate_words::<Secp256k1>(&words));
+
+    check_fails_without_access::<Trace, _>(
+        &[(0x1000, &words)],
+        |ctx| try_create_ec_point_and_negate_event::<Secp256k1, Trace>(ctx, 0x1000, 0x2004),
+        EcSyscallError::Unaligned { name: "neg_ptr", ptr: 0x2004 },
+    );
+}
+
+fn ladder_step_words<E: EllipticCurve + WeierstrassParameters>(
+    r0: &[u64],
+    r1: &[u64],
+    bit: u64,
+) -> (Vec<u64>, Vec<u64>) {
+    let (event, mut rt) = with_ctx(&[(0x1000, r0), (0x1000 + 8 * r0.len() as u64, r1)], |ctx| {
+        create_ec_ladder_step_event::<E, Trace>(ctx, 0x1000, bit)
+    });
+    assert_eq!(event.points, [r0, r1].concat());
+    assert_eq!(event.memory_records.len(), 2 * r0.len());
+    let result = read_words(&mut rt, 0x1000, 2 * r0.len());
+    assert_eq!(result, event.result);
+    let (r0, r1) = result.split_at(r0.len());
+    (r0.to_vec(), r1.to_vec())
+}
+
+fn check_ladder_step<E: EllipticCurve + WeierstrassParameters>() {
+    // Each step matches a separate addition and doubling.
+    let generator = E::ec_generator();
+    let (mut r0, mut r1) = (generator.to_words_le(), E::ec_double(&generator).to_words_le());
+    for bit in [0, 1, 1, 0, 1, 0] {
+        let (p, q) = (AffinePoint::<E>::from_words_le(&r0), AffinePoint::from_words_le(&r1));
+        let expected = if bit == 1 {
+            (E::ec_add(&p, &q).to_words_le(), E::ec_double(&q).to_words_le())
+        } else {
+            (E::ec_double(&p).to_words_le(), E::ec_add(&p, &q).to_words_le())
+        };
+        (r0, r1) = ladder_step_words::<E>(&r0, &r1, bit);
+        assert_eq!((r0.clone(), r1.clone()), expected);
+    }
+
+    // The separate operations handle the point at infinity and equal or opposite points.
+    let g = generator.to_words_le();
+    let zero = vec![0; g.len()];
+    let double = E::ec_double(&generator).to_words_le();
+    let neg = E::ec_neg(&generator).to_words_le();
+    assert_eq!(ladder_step_words::<E>(&zero, &g, 0), (zero.clone(), g.clone()));
+    assert_eq!(ladder_step_words::<E>(&zero, &g, 1), (g.clone(), double.clone()));
+    assert_eq!(ladder_step_words::<E>(&g, &g, 0), (double.clone(), double.clone()));
+    assert_eq!(
+        ladder_step_words::<E>(&g, &neg, 1),
+        (zero, E::ec_double(&E::ec_neg(&generator)).to_words_le())
+    );
+}
+
+curve_test!(test_ladder_step, check_ladder_step: Secp256k1, Bn254, Bls12381);
+
+#[test]
+fn test_pages_touched() {
+    let generator = Secp256k1::ec_generator().to_words_le();
+    let doubled = Secp256k1::ec_double(&Secp256k1::ec_generator()).to_words_le();
+
+    // Each point within a single page, then the first point straddling a page boundary.
+    for (p_ptr, q_ptr, pages) in [(0x1000, 0x2000, 2), (0x1000, 0x1040, 1), (0x1fe0, 0x3000, 3)] {
+        let (event, _) = with_ctx(&[(p_ptr, &generator), (q_ptr, &doubled)], |ctx| {
+            create_ec_add_event::<Secp256k1, Trace>(ctx, p_ptr, q_ptr)
+        });
+        assert_eq!(event.pages_touched(), pages);
+    }
+
+    let (event, _) = with_ctx(&[(0x1fe0, &generator)], |ctx| {
+        create_ec_double_event::<Secp256k1, Trace>(ctx, 0x1fe0, 0)
+    });
+    assert_eq!(event.pages_touched(), 2);
+}
+
+fn bls12381_compress_words(p: &[u64]) -> Vec<u8> {
+    let (event, mut rt) = with_ctx(&[(0x1000, p)], |ctx| {
+        create_ec_compress_event::<Bls12381, Trace>(ctx, 0x1000, 0x2000)
+    });
+    assert_eq!(event.compressed.len(), 48);
+    let compressed = words_to_bytes_le_vec(&read_words(&mut rt, 0x2000, 6));
+    assert_eq!(compressed, event.compressed);
+    compressed
+}
+
+#[test]
+fn test_bls12381_compress_then_decompress() {
+    let mut point = Bls12381::ec_generator();
+    for _ in 0..4 {
+        let mut compressed = bls12381_compress_words(&point.to_words_le());
+        let sign_bit = u64::from(compressed[0] & 0x20 != 0);
+        compressed[0] &= 0x1f;
+        let x_words = bytes_be_to_words_le_vec(&compressed);
+
+        let (_, mut rt) = with_ctx(&[(0x1000 + 48, &x_words)], |ctx| {
+            create_ec_decompress_event::<Bls12381, Trace>(ctx, 0x1000, sign_bit)
+        });
+        // Decompression writes y to the lower half of the slice.
+        assert_eq!(read_words(&mut rt, 0x1000, 6), point.to_words_le()[6..]);
+
+        point = Bls12381::ec_double(&point);
+    }
+
+    let infinity = bls12381_compress_words(&[0; 12]);
+    assert_eq!(infinity[0], 0xc0);
+    assert!(infinity[1..].iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn test_events_record_syscall_id() {
+    let generator = Secp256k1::ec_generator().to_words_le();
+    let doubled = Secp256k1::ec_double(&Secp256k1::ec_generator()).to_words_le();
+
+    let mut rt = executor_with(&[(0x1000, &generator), (0x2000, &doubled)]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    ctx.syscall_code = SyscallCode::SECP256K1_ADD;
+    let event = create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x2000);
+    assert_eq!(event.syscall_id, SyscallCode::SECP256K1_ADD.syscall_id());
+
+    let mut rt = executor_with(&[(0x1000, &generator)]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    ctx.syscall_code = SyscallCode::SECP256K1_DOUBLE;
+    let event = create_ec_double_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(event.syscall_id, SyscallCode::SECP256K1_DOUBLE.syscall_id());
+    assert_ne!(event.syscall_id, SyscallCode::SECP256K1_ADD.syscall_id());
+
+    let mut rt = executor_with(&[(0x1000, &generator[..4])]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    ctx.syscall_code = SyscallCode::SECP256K1_DOUBLE;
+    let event = create_field_inverse_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(event.syscall_id, SyscallCode::SECP256K1_DOUBLE.syscall_id());
+}
+
+fn double_event<E: EllipticCurve, Ex: ExecutorConfig>(words: &[u64]) -> EllipticCurveDoubleEvent {
+    let mut rt = executor_with(&[(0x1000, words)]);
+    let mut ctx = SyscallContext::<Ex>::new(&mut rt, true);
+    let event = create_ec_double_event::<E, Ex>(&mut ctx, 0x1000, 0);
+    assert_eq!(
+        read_words(&mut rt, 0x1000, words.len()),
+        E::ec_double(&AffinePoint::from_words_le(words)).to_words_le()
+    );
+    event
+}
+
+#[test]
+fn test_double_unchanged_write() {
+    // Doubling fixes the identity of both curve families.
+    let ed25519_neutral = Ed25519::ec_neutral().unwrap().to_words_le();
+    let bn254_infinity = vec![0; 8];
+    for event in [
+        double_event::<Ed25519, ElidingTrace>(&ed25519_neutral),
+        double_event::<Bn254, ElidingTrace>(&bn254_infinity),
+    ] {
+        assert!(event.unchanged_write);
+        assert_eq!(event.p_memory_records.len(), event.p.len());
+        for (record, word) in event.p_memory_records.iter().zip(&event.p) {
+            assert_eq!(record.value, *word);
+            assert_eq!(record.prev_value, *word);
+        }
+    }
+
+    let generator = Secp256k1::ec_generator().to_words_le();
+    assert!(!double_event::<Secp256k1, ElidingTrace>(&generator).unchanged_write);
+    assert!(
+        !double_event::<Ed25519, ElidingTrace>(&Ed25519::ec_generator().to_words_le())
+            .unchanged_write
+    );
+
+    // The optimization is off by default.
+    assert!(!double_event::<Ed25519, Trace>(&ed25519_neutral).unchanged_write);
+    assert!(!double_event::<Bn254, Trace>(&bn254_infinity).unchanged_write);
+}
+
+fn check_double_identity<E: EllipticCurve>() {
+    let identity = encode_point::<E>(None).to_words_le();
+    let (event, mut rt) =
+        with_ctx(&[(0x1000, &identity)], |ctx| create_ec_double_event::<E, Trace>(ctx, 0x1000, 0));
+    assert_eq!(read_words(&mut rt, 0x1000, identity.len()), identity);
+    assert!(event.result_is_identity);
+    assert!(!event.unchanged_write);
+    assert_eq!(event.p, identity);
+    assert_eq!(event.p_memory_records.len(), identity.len());
+    for (record, word) in event.p_memory_records.iter().zip(&identity) {
+        assert_eq!(record.value, *word);
+        assert_eq!(record.prev_value, *word);
+    }
+}
+
+curve_test!(
+    test_double_identity,
+    check_double_identity: Secp256k1, Bn254, Bls12381, Pallas, Ed25519, Jubjub
+);
+
+#[test]
+fn test_double_syscall_rejects_identity() {
+    // The double chip inverts `2y`, so the syscall refuses the identity the event constructor
+    // writes back, before touching memory.
+    let identity = encode_point::<Secp256k1>(None).to_words_le();
+    let result = ec_syscall(SyscallCode::SECP256K1_DOUBLE, &identity, &[], 0);
+    assert_eq!(result, (Some(EC_SYSCALL_FAILED), false));
+    let generator = Secp256k1::ec_generator().to_words_le();
+    assert_eq!(ec_syscall(SyscallCode::SECP256K1_DOUBLE, &generator, &[], 0), (None, true));
+}
+
+fn check_add_and_double<E: EllipticCurve>(doubled: &AffinePoint<E>, tripled: &AffinePoint<E>) {
+    let generator = E::ec_generator().to_words_le();
+
+    let (_, mut rt) =
+        with_ctx(&[(0x1000, &generator)], |ctx| create_ec_double_event::<E, Trace>(ctx, 0x1000, 0));
+    let result = read_words(&mut rt, 0x1000, generator.len());
+    assert_eq!(&AffinePoint::<E>::from_words_le(&result), doubled);
+
+    let (_, mut rt) = with_ctx(&[(0x1000, &doubled.to_words_le()), (0x2000, &generator)], |ctx| {
+        create_ec_add_event::<E, Trace>(ctx, 0x1000, 0x2000)
+    });
+    let result = read_words(&mut rt, 0x1000, generator.len());
+    assert_eq!(&AffinePoint::<E>::from_words_le(&result), tripled);
+}
+
+#[test]
+fn test_pasta_add_and_double() {
+    check_add_and_double::<Pallas>(
+        &AffinePoint::new(
+            biguint(
+                "12664759760331458874453076485325239921471337210849432813230171084403110838275",
+            ),
+            biguint(
+                "19449452489080454700052938888178047022259553573804486106032048451047634501628",
+            ),
+        ),
+        &AffinePoint::new(
+            biguint("4027241023027617754036171531542546502751647131375064771810253584944963179107"),
+            biguint(
+                "21762326383673887073830845720227757791980770399450032709429395080608314263493",
+            ),
+        ),
+    );
+    check_add_and_double::<Vesta>(
+        &AffinePoint::new(
+            biguint(
+                "12664759760331458874453076485325239921471337210849470728609887452422096289795",
+            ),
+            biguint(
+                "19449452489080454700052938888178047022259553573804544333222327159076790730748",
+            ),
+        ),
+        &AffinePoint::new(
+            biguint(
+                "25090067966472946007446590780583652548116456464496053869245354133418193309279",
+            ),
+            biguint(
+                "14485812765332067710838382555935059365898177416503303828814702067459945738374",
+            ),
+        ),
+    );
+}
+
+fn count_records(event: &impl EcEvent) -> (usize, usize) {
+    let (mut num_reads, mut num_writes) = (0, 0);
+    for record in event.memory_records() {
+        match record {
+            MemoryRecordRef::Read(_) => num_reads += 1,
+            MemoryRecordRef::Write(_) => num_writes += 1,
+        }
+    }
+    (num_reads, num_writes)
+}
+
+#[test]
+fn test_memory_records() {
+    let generator = Secp256k1::ec_generator();
+    let words = generator.to_words_le();
+
+    let (add, _) = with_ctx(
+        &[(0x1000, &words), (0x2000, &Secp256k1::ec_double(&generator).to_words_le())],
+        |ctx| create_ec_add_event::<Secp256k1, Trace>(ctx, 0x1000, 0x2000),
+    );
+    assert_eq!(count_records(&add), (8, 8));
+    // The reads of q come before the writes of p.
+    let records = add.memory_records().collect::<Vec<_>>();
+    assert!(records[..8].iter().all(|record| matches!(record, MemoryRecordRef::Read(_))));
+    assert!(records[8..].iter().all(|record| matches!(record, MemoryRecordRef::Write(_))));
+
+    let (double, _) = with_ctx(&[(0x1000, &words)], |ctx| {
+        create_ec_double_event::<Secp256k1, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(count_records(&double), (0, 8));
+
+    let (decompress, _) = with_ctx(&[(0x1020, &words[..4])], |ctx| {
+        create_ec_decompress_event::<Secp256k1, Trace>(ctx, 0x1000, u64::from(generator.y.bit(0)))
+    });
+    assert_eq!(count_records(&decompress), (4, 4));
+
+    let bls_words = Bls12381::ec_generator().to_words_le();
+    let (double, _) = with_ctx(&[(0x1000, &bls_words)], |ctx| {
+        create_ec_double_event::<Bls12381, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(count_records(&double), (0, 12));
+}
+
+#[test]
+fn test_debug_assert_result_on_curve() {
+    let generator = Secp256k1::ec_generator();
+    debug_assert_result_on_curve(true, &Secp256k1::ec_double(&generator));
+    debug_assert_result_on_curve(true, &encode_point::<Secp256k1>(None));
+    debug_assert_result_on_curve(true, &Ed25519::ec_neutral().unwrap());
+
+    // Results of inputs off the curve are not checked.
+    let corrupted = AffinePoint::<Secp256k1>::new(&generator.x + 1u32, generator.y.clone());
+    debug_assert_result_on_curve(false, &corrupted);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "is not on the curve")]
+fn test_debug_assert_result_on_curve_corrupted() {
+    let generator = Secp256k1::ec_generator();
+    let corrupted = AffinePoint::<Secp256k1>::new(&generator.x + 1u32, generator.y);
+    debug_assert_result_on_curve(true, &corrupted);
+}
+
+#[test]
+fn test_slice_view_matches_slice_unsafe() {
+    let generator = Bls12381G2Parameters::generator().to_words_le();
+    // The second region straddles a page of the page table, with its last words unset.
+    let regions = [(0x1000, generator.len()), (0x200000 - 0x20, generator.len() + 4)];
+
+    let mut rt = executor();
+    for (ptr, _) in regions {
+        write_words(&mut rt, ptr, &generator);
+    }
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    for (ptr, len) in regions {
+        let owned = ctx.slice_unsafe(ptr, len);
+        let borrowed = ctx.slice_view(ptr, len).collect::<Vec<_>>();
+        assert_eq!(borrowed, owned);
+        assert_eq!(borrowed[..generator.len()], generator);
+    }
+    assert_eq!(ctx.slice_view(0x1000, 0).count(), 0);
+}
+
+#[test]
+fn test_page_prot_violation() {
+    let mut program = Program::new(vec![], 0, 0);
+    program.enable_untrusted_programs = true;
+    let mut rt = Executor::new(Arc::new(program), SP1CoreOpts::default());
+    rt.state.clk = 8;
+
+    let generator = Secp256k1::ec_generator();
+    let words = generator.to_words_le();
+    let page = PAGE_SIZE as u64;
+    write_words(&mut rt, page, &words);
+    write_words(&mut rt, 2 * page, &words);
+    write_words(&mut rt, 3 * page + 0x20, &words[..4]);
+    for page_idx in 1..=3 {
+        rt.state.page_prots.insert(
+            page_idx,
+            PageProtRecord { external_flag: false, timestamp: 0, page_prot: PROT_WRITE },
+        );
+    }
+
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    assert!(!ctx.page_prot_allows(2 * page, 8, PROT_READ));
+    assert!(ctx.page_prot_allows(2 * page, 8, PROT_WRITE));
+    assert!(ctx.page_prot_allows(4 * page, 8, PROT_READ));
+    assert_eq!(
+        try_create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 4 * page, 2 * page).unwrap_err(),
+        EcSyscallError::PageProtViolation { ptr: 2 * page }
+    );
+    assert_eq!(
+        try_create_ec_decompress_event::<Secp256k1, Trace>(
+            &mut ctx,
+            3 * page,
+            u64::from(generator.y.bit(0))
+        )
+        .unwrap_err(),
+        EcSyscallError::PageProtViolation { ptr: 3 * page + 0x20 }
+    );
+    assert_eq!(
+        try_create_ec_add_batch_event::<Secp256k1, Trace>(&mut ctx, page, 4 * page, 2).unwrap_err(),
+        EcSyscallError::PageProtViolation { ptr: page }
+    );
+    assert_eq!(
+        try_create_ec_add_to_event::<Secp256k1, Trace>(&mut ctx, 4 * page, page, 5 * page)
+            .unwrap_err(),
+        EcSyscallError::PageProtViolation { ptr: page }
+    );
+
+    // Nothing was read or written before the check failed.
+    assert!(ctx.postprocess().0.is_empty());
+    assert_eq!(read_words(&mut rt, 2 * page, 8), words);
+}
+
+#[test]
+#[should_panic(expected = "page protection forbids the access at 0x1000")]
+fn test_negate_page_prot_violation() {
+    let mut program = Program::new(vec![], 0, 0);
+    program.enable_untrusted_programs = true;
+    let mut rt = Executor::new(Arc::new(program), SP1CoreOpts::default());
+    rt.state
+        .page_prots
+        .insert(1, PageProtRecord { external_flag: false, timestamp: 0, page_prot: PROT_READ });
+
+    // The point can be read but not negated in place.
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    create_ec_negate_event::<Secp256k1, Trace>(&mut ctx, PAGE_SIZE as u64, 0);
+}
+
+#[test]
+fn test_write_initialized_new_pages() {
+    let mut program = Program::new(vec![], 0, 0);
+    program.enable_untrusted_programs = true;
+    let mut rt = Executor::new(Arc::new(program), SP1CoreOpts::default());
+    rt.state.clk = 8;
+
+    let generator = Secp256k1::ec_generator();
+    let words = generator.to_words_le();
+    let page = PAGE_SIZE as u64;
+    write_words(&mut rt, page, &words);
+    // This point straddles two pages.
+    write_words(&mut rt, 3 * page - 0x10, &words);
+    write_words(&mut rt, 4 * page, &words);
+    write_words(&mut rt, 4 * page + 0x100, &Secp256k1::ec_double(&generator).to_words_le());
+    write_words(&mut rt, 5 * page, &words);
+
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let fresh = create_ec_double_event::<Secp256k1, Trace>(&mut ctx, page, 0);
+    assert_eq!(fresh.page_prot_records.write_initialized_new_pages(), 1);
+    ctx.clk += 8;
+    let written = create_ec_double_event::<Secp256k1, Trace>(&mut ctx, page, 0);
+    assert_eq!(written.page_prot_records.write_initialized_new_pages(), 0);
+    ctx.clk += 8;
+    let straddling = create_ec_double_event::<Secp256k1, Trace>(&mut ctx, 3 * page - 0x10, 0);
+    assert_eq!(straddling.page_prot_records.write_initialized_new_pages(), 2);
+
+    // An add reads `q` before writing `p`, so a page holding both was first touched by the read.
+    ctx.clk += 8;
+    let same_page = create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 4 * page, 4 * page + 0x100);
+    assert_eq!(same_page.page_prot_records.write_initialized_new_pages(), 0);
+    ctx.clk += 8;
+    let other_page = create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 5 * page, 4 * page);
+    assert_eq!(other_page.page_prot_records.write_initialized_new_pages(), 1);
+}
+
+#[test]
+fn test_add_event_bytes() {
+    let generator = Secp256k1::ec_generator();
+    let doubled = Secp256k1::ec_double(&generator);
+    let sum = Secp256k1::ec_add(&generator, &doubled);
+    let len = 2 * <Secp256k1BaseField as NumLimbs>::Limbs::USIZE;
+    let len_arg = len as u64;
+
+    // Surround each point with marker bytes, at offsets within the first word.
+    let add_bytes = |p_offset: usize, q_offset: usize| {
+        let mut rt = executor();
+        let mut p_bytes = vec![0xaa; 0x100];
+        p_bytes[p_offset..p_offset + len]
+            .copy_from_slice(&words_to_bytes_le_vec(&generator.to_memory_words()));
+        let mut q_bytes = vec![0xbb; 0x100];
+        q_bytes[q_offset..q_offset + len]
+            .copy_from_slice(&words_to_bytes_le_vec(&doubled.to_memory_words()));
+        write_words(&mut rt, 0x1000, &bytes_to_words_le_vec(&p_bytes));
+        write_words(&mut rt, 0x2000, &bytes_to_words_le_vec(&q_bytes));
+
+        let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+        let event = create_ec_add_event_bytes::<Secp256k1, Trace>(
+            &mut ctx,
+            0x1000 + p_offset as u64,
+            0x2000 + q_offset as u64,
+            len_arg,
+        );
+        assert_eq!(event.p, generator.to_memory_words());
+        assert_eq!(event.q, doubled.to_memory_words());
+        p_bytes[p_offset..p_offset + len]
+            .copy_from_slice(&words_to_bytes_le_vec(&sum.to_memory_words()));
+        assert_eq!(read_words(&mut rt, 0x1000, 0x20), bytes_to_words_le_vec(&p_bytes));
+        assert_eq!(read_words(&mut rt, 0x2000, 0x20), bytes_to_words_le_vec(&q_bytes));
+        event
+    };
+
+    let aligned = add_bytes(0, 0);
+    assert_eq!((aligned.p_memory_records.len(), aligned.q_memory_records.len()), (8, 8));
+    let misaligned = add_bytes(3, 5);
+    assert_eq!((misaligned.p_memory_records.len(), misaligned.q_memory_records.len()), (9, 9));
+    assert!(!misaligned.result_is_identity);
+
+    let mut rt = executor();
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    for wrong_len in [len_arg - 1, len_arg + 8, len_arg / 2] {
+        assert_eq!(
+            try_create_ec_add_event_bytes::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x2000, wrong_len)
+                .unwrap_err(),
+            EcSyscallError::InvalidLength { len: wrong_len, expected: len_arg }
+        );
+    }
+}
+
+fn equals_event<E: EllipticCurve>(p: &[u64], q: &[u64]) -> EllipticCurveEqualsEvent {
+    let (event, mut rt) = with_ctx(&[(0x1000, p), (0x2000, q)], |ctx| {
+        create_ec_equals_event::<E, Trace>(ctx, 0x1000, 0x2000, 0x3000)
+    });
+    assert_eq!(read_words(&mut rt, 0x3000, 1), [u64::from(event.equal)]);
+    assert_eq!(count_records(&event), (2 * p.len(), 1));
+    event
+}
+
+#[test]
+fn test_ec_equals() {
+    let generator = Secp256k1::ec_generator();
+    let doubled = Secp256k1::ec_double(&generator);
+    assert!(equals_event::<Secp256k1>(&generator.to_words_le(), &generator.to_words_le()).equal);
+    assert!(!equals_event::<Secp256k1>(&generator.to_words_le(), &doubled.to_words_le()).equal);
+    assert!(!equals_event::<Secp256k1>(&generator.to_words_le(), &[0; 8]).equal);
+    assert!(equals_event::<Secp256k1>(&[0; 8], &[0; 8]).equal);
+
+    // An unreduced encoding of the identity equals the canonical one.
+    let p = Secp256k1BaseField::modulus();
+    let unreduced = AffinePoint::<Secp256k1>::new(p.clone(), p).to_words_le();
+    assert!(equals_event::<Secp256k1>(&unreduced, &[0; 8]).equal);
+
+    let neutral = Ed25519::ec_neutral().unwrap();
+    let unreduced = AffinePoint::<Ed25519>::new(Ed25519BaseField::modulus(), neutral.y.clone());
+    assert!(equals_event::<Ed25519>(&neutral.to_words_le(), &unreduced.to_words_le()).equal);
+    let generator = Ed25519::ec_generator();
+    assert!(!equals_event::<Ed25519>(&neutral.to_words_le(), &generator.to_words_le()).equal);
+
+    // Aliased pointers compare a point with itself, and the cost does not depend on the outcome.
+    let mut rt = executor_with(&[(0x1000, &doubled.to_words_le())]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_equals_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x1000, 0x1000);
+    assert!(event.equal);
+    assert_eq!(ctx.clk - event.clk, 2);
+}
+
+fn is_negation_event<E: EllipticCurve>(p: &[u64], q: &[u64]) -> EllipticCurveIsNegationEvent {
+    let (event, mut rt) = with_ctx(&[(0x1000, p), (0x2000, q)], |ctx| {
+        create_ec_is_negation_event::<E, Trace>(ctx, 0x1000, 0x2000, 0x3000)
+    });
+    assert_eq!(read_words(&mut rt, 0x3000, 1), [u64::from(event.negation)]);
+    assert_eq!(count_records(&event), (2 * p.len(), 1));
+    event
+}
+
+#[test]
+fn test_ec_is_negation() {
+    let generator = Secp256k1::ec_generator();
+    let negated = ec_negate(&generator).to_words_le();
+    let generator = generator.to_words_le();
+    assert!(is_negation_event::<Secp256k1>(&generator, &negated).negation);
+    assert!(is_negation_event::<Secp256k1>(&negated, &generator).negation);
+    assert!(!is_negation_event::<Secp256k1>(&generator, &generator).negation);
+    assert!(!is_negation_event::<Secp256k1>(&generator, &[0; 8]).negation);
+    assert!(is_negation_event::<Secp256k1>(&[0; 8], &[0; 8]).negation);
+
+    let generator = Ed25519::ec_generator();
+    let negated = ec_negate(&generator).to_words_le();
+    let generator = generator.to_words_le();
+    assert!(is_negation_event::<Ed25519>(&generator, &negated).negation);
+    assert!(!is_negation_event::<Ed25519>(&generator, &generator).negation);
+    let neutral = Ed25519::ec_neutral().unwrap().to_words_le();
+    assert!(is_negation_event::<Ed25519>(&neutral, &neutral).negation);
+}
+
+fn check_identity_encoding<E: EllipticCurve>(encoding: IdentityEncoding) {
+    assert_eq!(E::IDENTITY_ENCODING, encoding);
+    let identity = match encoding {
+        IdentityEncoding::Zero => vec![0; <E::BaseField as NumWords>::WordsCurvePoint::USIZE],
+        IdentityEncoding::Neutral => E::ec_neutral().unwrap().to_words_le(),
+    };
+    let generator = E::ec_generator();
+    let words = generator.to_words_le();
+
+    // P + (-P) writes the identity.
+    let (event, mut rt) =
+        with_ctx(&[(0x1000, &words), (0x2000, &E::ec_neg(&generator).to_words_le())], |ctx| {
+            create_ec_add_event::<E, Trace>(ctx, 0x1000, 0x2000)
+        });
+    assert!(event.result_is_identity);
+    assert_eq!(read_words(&mut rt, 0x1000, words.len()), identity);
+
+    // The identity is detected as an input.
+    let (event, mut rt) = with_ctx(&[(0x1000, &identity), (0x2000, &words)], |ctx| {
+        create_ec_add_event::<E, Trace>(ctx, 0x1000, 0x2000)
+    });
+    assert!(!event.result_is_identity);
+    assert_eq!(read_words(&mut rt, 0x1000, words.len()), words);
+
+    let (event, mut rt) =
+        with_ctx(&[(0x1000, &identity)], |ctx| create_ec_double_event::<E, Trace>(ctx, 0x1000, 0));
+    assert!(event.result_is_identity);
+    assert_eq!(read_words(&mut rt, 0x1000, words.len()), identity);
+}
+
+#[test]
+fn test_identity_encoding() {
+    check_identity_encoding::<Secp256k1>(IdentityEncoding::Zero);
+    check_identity_encoding::<Secp256r1>(IdentityEncoding::Zero);
+    check_identity_encoding::<Bn254>(IdentityEncoding::Zero);
+    check_identity_encoding::<Bls12381>(IdentityEncoding::Zero);
+    check_identity_encoding::<Pallas>(IdentityEncoding::Zero);
+    check_identity_encoding::<Vesta>(IdentityEncoding::Zero);
+    check_identity_encoding::<Ed25519>(IdentityEncoding::Neutral);
+    check_identity_encoding::<Jubjub>(IdentityEncoding::Neutral);
+}
+
+fn load_validate(words: &[u64], check_subgroup: bool) -> EcValidationStatus {
+    let (event, mut rt) = with_ctx(&[(0x1000, words)], |ctx| {
+        create_ec_load_validate_event::<Bls12381, Trace>(ctx, 0x1000, 0x2000, check_subgroup)
+    });
+    assert_eq!(read_words(&mut rt, 0x2000, 1), [event.status as u64]);
+    event.status
+}
+
+#[test]
+fn test_ec_load_validate() {
+    let generator = Bls12381::ec_generator();
+    for check_subgroup in [false, true] {
+        assert_eq!(
+            load_validate(&generator.to_words_le(), check_subgroup),
+            EcValidationStatus::Valid
+        );
+        assert_eq!(load_validate(&[0; 12], check_subgroup), EcValidationStatus::Valid);
+    }
+
+    let off_curve = AffinePoint::<Bls12381>::new(generator.x.clone(), &generator.y + 1u32);
+    assert_eq!(load_validate(&off_curve.to_words_le(), false), EcValidationStatus::NotOnCurve);
+    assert_eq!(load_validate(&off_curve.to_words_le(), true), EcValidationStatus::NotOnCurve);
+
+    // A point on the curve outside of the prime-order subgroup.
+    let point = AffinePoint::<Bls12381>::new(
+        BigUint::from(4u32),
+        biguint(
+            "1630892974828014537729259858097113969650871260980656934049590190201941782487224876496582135785777461178964897591404",
+        ),
+    );
+    assert_eq!(load_validate(&point.to_words_le(), false), EcValidationStatus::Valid);
+    assert_eq!(load_validate(&point.to_words_le(), true), EcValidationStatus::NotInSubgroup);
+}
+
+#[test]
+fn test_add_clk_delta() {
+    let generator = Secp256k1::ec_generator();
+    let mut rt = executor_with(&[
+        (0x1000, &generator.to_words_le()),
+        (0x2000, &Secp256k1::ec_double(&generator).to_words_le()),
+    ]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let start_clk = ctx.clk;
+    let event = create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x2000);
+
+    // q is read at the start clk and p is written one cycle later.
+    assert_eq!(ctx.clk - start_clk, 1);
+    assert_eq!(event.cycles, 1);
+    assert!(event.q_memory_records.iter().all(|record| record.timestamp == start_clk));
+    assert!(event.p_memory_records.iter().all(|record| record.timestamp == start_clk + 1));
+}
+
+fn ecrecover_event(hash: &str, r: &str, s: &str, recovery_id: u64) -> EcrecoverEvent {
+    let mut input = Vec::new();
+    for int in [hash, r, s] {
+        let mut bytes = BigUint::parse_bytes(int.as_bytes(), 16).unwrap().to_bytes_be();
+        bytes.splice(0..0, vec![0u8; 32 - bytes.len()]);
+        input.extend(bytes_to_words_le_vec(&bytes));
+    }
+    input.push(recovery_id);
+
+    let (event, mut rt) =
+        with_ctx(&[(0x1000, &input)], |ctx| create_ecrecover_event::<Trace>(ctx, 0x2000, 0x1000));
+    assert_eq!(read_words(&mut rt, 0x2000, 8), event.public_key);
+    event
+}
+
+#[test]
+fn test_ecrecover() {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let hash = "456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3";
+    let r = "9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac8038825608";
+    let s = "4f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada";
+
+    // The Ethereum `v` of this signature is 28, so the recovery id is 1.
+    let event = ecrecover_event(hash, r, s, 1);
+    assert!(event.valid);
+    let public_key = AffinePoint::<Secp256k1>::from_words_le(&event.public_key);
+    assert_eq!(
+        public_key.x,
+        BigUint::parse_bytes(
+            b"f57c1d4c961024e998eaec4b6bebec90e788ef5ade22e636ce76111b60db107d",
+            16
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        public_key.y,
+        BigUint::parse_bytes(
+            b"4c3404b9908a2f357c84ccb48cf412be41d09574a9291c9c7eb5173ccf2a339f",
+            16
+        )
+        .unwrap()
+    );
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&public_key.to_bytes_be());
+    let mut address = [0u8; 32];
+    hasher.finalize(&mut address);
+    assert_eq!(
+        BigUint::from_bytes_be(&address[12..]),
+        BigUint::parse_bytes(b"7156526fbd7a3c72969b54f64e42c10fbb768c8a", 16).unwrap()
+    );
+
+    // The other parity recovers a different key.
+    let event = ecrecover_event(hash, r, s, 0);
+    assert!(event.valid);
+    assert_ne!(AffinePoint::<Secp256k1>::from_words_le(&event.public_key), public_key);
+
+    // There is no point with `x = 5`, `s` must be nonzero, and the recovery id is at most 3.
+    for (r, s, recovery_id) in [("5", s, 1), (r, "0", 1), (r, s, 4)] {
+        let event = ecrecover_event(hash, r, s, recovery_id);
+        assert!(!event.valid);
+        assert_eq!(event.public_key, vec![0; 8]);
+    }
+}
+
+#[test]
+fn test_estimated_size() {
+    let within_margin = |estimate: usize, actual: usize| estimate.abs_diff(actual) * 20 <= actual;
+
+    let generator = Bls12381::ec_generator();
+    let (add, _) = with_ctx(
+        &[
+            (0x1000, &generator.to_words_le()),
+            (0x2000, &Bls12381::ec_double(&generator).to_words_le()),
+        ],
+        |ctx| create_ec_add_event::<Bls12381, Trace>(ctx, 0x1000, 0x2000),
+    );
+    assert!(within_margin(add.estimated_size(), add.deep_size_of()));
+
+    // An MSM over a few points, with the scalars right after the points.
+    let n = 4u64;
+    let mut input = vec![n];
+    let mut point = generator.clone();
+    for _ in 0..n {
+        input.extend(point.to_words_le());
+        point = Bls12381::ec_double(&point);
+    }
+    input.extend((1..=n * 6).collect::<Vec<_>>());
+    let (msm, _) = with_ctx(&[(0x1000, &input)], |ctx| {
+        create_ec_msm_event::<Bls12381, Trace>(ctx, 0x8000, 0x1000)
+    });
+    assert!(within_margin(msm.estimated_size(), msm.deep_size_of()));
+}
+
+fn miller_step(t: &[u64]) -> MillerStepEvent {
+    let (event, mut rt) =
+        with_ctx(&[(0x1000, t), (0x2000, &Bls12381::ec_generator().to_words_le())], |ctx| {
+            create_miller_step_event::<Trace>(ctx, 0x1000, 0x2000, 0x3000)
+        });
+    assert_eq!(read_words(&mut rt, 0x3000, 72), event.line);
+    event
+}
+
+#[test]
+fn test_miller_step() {
+    let generator = Bls12381G2Parameters::generator();
+    let event = miller_step(&generator.to_words_le());
+    let doubled = generator.g2_double().unwrap().to_words_le();
+    assert_eq!(
+        event.t_memory_records.iter().map(|record| record.value).collect::<Vec<_>>(),
+        doubled
+    );
+
+    // The line from a reference pairing implementation using the same `Fp12` tower, whose
+    // pairing was checked to be bilinear.
+    let coeff = |s: &str| {
+        let mut bytes = BigUint::parse_bytes(s.as_bytes(), 16).unwrap().to_bytes_le();
+        bytes.resize(48, 0);
+        bytes_to_words_le_vec(&bytes)
+    };
+    let zero = vec![0; 6];
+    let expected = [
+        coeff("4c208bdb300097927393e963768099390a3f9d581d8828070e39167384e44fdaf716fa49d68b0bdf431a2f53189c109"),
+        coeff("546ca700477f9c2f9def9691be2f7e6eaa0f1474cb64c53ce3b4d4da03cbdac75933b5468ab4b88cf058f147ba2cda9"),
+        coeff("11bfe7fb8923ace27a0692871443365b3e5e0dd7ef388153c5bb27d923a3dedb6b9f9cbfd022a8eb913e281a830fac9c"),
+        coeff("13d0fab25f0e7099c6ffaa8ddd3cb036f5c35df322a9359ff8f98a4f5c6a84c50b6007c296eafda2ffa2d20b253a6633"),
+        zero.clone(),
+        zero.clone(),
+        zero.clone(),
+        zero.clone(),
+        coeff("8b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+        zero.clone(),
+        zero.clone(),
+        zero,
+    ]
+    .concat();
+    assert_eq!(event.line, expected);
+    // `2T` and the line are written a cycle apart.
+    assert_eq!(event.t_memory_records[0].timestamp, 9);
+    assert_eq!(event.line_memory_records[0].timestamp, 10);
+
+    // Doubling the point at infinity leaves it in place, with a line of one.
+    let event = miller_step(&[0; 24]);
+    assert!(event.t_memory_records.iter().all(|record| record.value == 0));
+    let mut one = vec![0; 72];
+    one[0] = 1;
+    assert_eq!(event.line, one);
+
+    // The line may start right after `T`.
+    with_ctx(&[], |ctx| create_miller_step_event::<Trace>(ctx, 0x1000, 0x2000, 0x1000 + 8 * 24));
+}
+
+#[test]
+#[should_panic(expected = "the line must not overlap T")]
+fn test_miller_step_line_overlapping_t() {
+    with_ctx(&[], |ctx| create_miller_step_event::<Trace>(ctx, 0x1000, 0x2000, 0x1000 - 8 * 71));
+}
+
+#[test]
+fn test_curve_type_syscall_codes() {
+    use strum::IntoEnumIterator;
+
+    let registered = [
+        (SyscallCode::ED_ADD, CurveType::Ed25519, EcOp::Add),
+        (SyscallCode::ED_DECOMPRESS, CurveType::Ed25519, EcOp::Decompress),
+        (SyscallCode::SECP256K1_ADD, CurveType::Secp256k1, EcOp::Add),
+        (SyscallCode::SECP256K1_DOUBLE, CurveType::Secp256k1, EcOp::Double),
+        (SyscallCode::SECP256K1_DECOMPRESS, CurveType::Secp256k1, EcOp::Decompress),
+        (SyscallCode::BN254_ADD, CurveType::Bn254, EcOp::Add),
+        (SyscallCode::BN254_DOUBLE, CurveType::Bn254, EcOp::Double),
+        (SyscallCode::BLS12381_ADD, CurveType::Bls12381, EcOp::Add),
+        (SyscallCode::BLS12381_DOUBLE, CurveType::Bls12381, EcOp::Double),
+        (SyscallCode::BLS12381_DECOMPRESS, CurveType::Bls12381, EcOp::Decompress),
+        (SyscallCode::SECP256R1_ADD, CurveType::Secp256r1, EcOp::Add),
+        (SyscallCode::SECP256R1_DOUBLE, CurveType::Secp256r1, EcOp::Double),
+        (SyscallCode::SECP256R1_DECOMPRESS, CurveType::Secp256r1, EcOp::Decompress),
+    ];
+    for (code, curve, op) in registered {
+        assert_eq!(CurveType::from_syscall_code(code as u32), Some(curve));
+        assert_eq!(EcOp::from_syscall_code(code as u32), Some(op));
+        assert_eq!(curve.syscall_code(op), Some(code as u32));
+    }
+
+    // No other syscall maps to a curve.
+    for code in SyscallCode::iter() {
+        if !registered.iter().any(|&(registered_code, _, _)| registered_code == code) {
+            assert_eq!(CurveType::from_syscall_code(code as u32), None, "{code:?}");
+            assert_eq!(EcOp::from_syscall_code(code as u32), None, "{code:?}");
+        }
+    }
+    assert_eq!(CurveType::Jubjub.syscall_code(EcOp::Add), None);
+}
+
+fn add_to_words<E: EllipticCurve>(
+    p: &[u64],
+    q: &[u64],
+    q_ptr: u64,
+    out_ptr: u64,
+) -> (Vec<u64>, EllipticCurveAddToEvent) {
+    let (event, mut rt) = with_ctx(&[(0x1000, p), (q_ptr, q)], |ctx| {
+        create_ec_add_to_event::<E, Trace>(ctx, 0x1000, q_ptr, out_ptr)
+    });
+    (read_words(&mut rt, out_ptr, p.len()), event)
+}
+
+fn check_add_to<E: EllipticCurve>() {
+    let generator = E::ec_generator();
+    let p = generator.to_words_le();
+    let q = E::ec_double(&generator).to_words_le();
+    let neg_p = E::ec_neg(&generator).to_words_le();
+
+    for q in [&q, &neg_p] {
+        let expected = add_words::<E>(&p, q);
+
+        // Both inputs are left in place.
+        let (event, mut rt) = with_ctx(&[(0x1000, &p), (0x2000, q)], |ctx| {
+            create_ec_add_to_event::<E, Trace>(ctx, 0x1000, 0x2000, 0x3000)
+        });
+        assert_eq!(read_words(&mut rt, 0x3000, p.len()), expected);
+        assert_eq!(read_words(&mut rt, 0x1000, p.len()), p);
+        assert_eq!(read_words(&mut rt, 0x2000, p.len()), *q);
+
+        // The clk is bumped before reading q and before writing the sum, whether or not the
+        // ranges overlap.
+        for out_ptr in [0x3000, 0x1000, 0x2000, 0x2008] {
+            let (result, event) = add_to_words::<E>(&p, q, 0x2000, out_ptr);
+            assert_eq!(result, expected);
+            assert_eq!(event.cycles, 2);
+            assert!(event.p_memory_records.iter().all(|record| record.timestamp == event.clk));
+            assert!(event.q_memory_records.iter().all(|record| record.timestamp == event.clk + 1));
+            assert!(event
+                .out_memory_records
+                .iter()
+                .all(|record| record.timestamp == event.clk + 2));
+        }
+    }
+
+    // Fully aliased pointers double the point.
+    let (result, event) = add_to_words::<E>(&p, &p, 0x1000, 0x1000);
+    assert_eq!(result, add_words::<E>(&p, &p));
+    assert_eq!(event.cycles, 2);
+}
+
+curve_test!(test_add_to, check_add_to: Secp256k1, Bn254, Ed25519);
+
+fn decompress_batch<E: EllipticCurve + WeierstrassParameters>(
+    xs: &[BigUint],
+    sign_bits: &[bool],
+) -> (Vec<u64>, EllipticCurveDecompressBatchEvent) {
+    let num_x_words = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+    let mut input = vec![xs.len() as u64];
+    let mut packed = vec![0u64; xs.len().div_ceil(64)];
+    for (i, &sign_bit) in sign_bits.iter().enumerate() {
+        packed[i / 64] |= u64::from(sign_bit) << (i % 64);
+    }
+    input.extend(packed);
+    for x in xs {
+        let mut bytes = x.to_bytes_le();
+        bytes.resize(num_x_words * 8, 0);
+        input.extend(bytes_to_words_le_vec(&bytes));
+    }
+
+    let (event, mut rt) = with_ctx(&[(0x1000, &input)], |ctx| {
+        create_ec_decompress_batch_event::<E, Trace>(ctx, 0x1000, 0x8000)
+    });
+    let output = read_words(&mut rt, 0x8000, 1 + event.points.len());
+    assert_eq!(output[0], event.status);
+    assert_eq!(output[1..], event.points);
+    (output, event)
+}
+
+#[test]
+fn test_ec_decompress_batch() {
+    let generator = Secp256k1::ec_generator();
+    let mut points = vec![generator.clone(), Secp256k1::ec_double(&generator)];
+    for _ in 0..68 {
+        points.push(Secp256k1::ec_add(&points[points.len() - 1], &points[0]));
+    }
+    let xs = points.iter().map(|point| point.x.clone()).collect::<Vec<_>>();
+    let sign_bits = points.iter().map(|point| point.y.bit(0)).collect::<Vec<_>>();
+
+    // The sign bits span two words.
+    let (output, event) = decompress_batch::<Secp256k1>(&xs, &sign_bits);
+    assert_eq!(event.status, 0);
+    assert_eq!(event.sign_bits.len(), 2);
+    let expected = points.iter().flat_map(AffinePoint::to_words_le).collect::<Vec<_>>();
+    assert_eq!(output[1..], expected);
+
+    // There is no point with `x = 5`, so it is written as zeros and the rest still decompress.
+    let mut xs = xs[..3].to_vec();
+    xs[1] = BigUint::from(5u32);
+    let (output, event) = decompress_batch::<Secp256k1>(&xs, &sign_bits[..3]);
+    assert_eq!(event.status, 2);
+    assert_eq!(output[1..9], points[0].to_words_le());
+    assert_eq!(output[9..17], [0; 8]);
+    assert_eq!(output[17..25], points[2].to_words_le());
+    assert_eq!(event.x_memory_records.len(), 12);
+    assert_eq!(event.points_memory_records.len(), 24);
+
+    // Every slice access is at a cycle of its own.
+    assert_eq!(event.n_memory_record.timestamp, 8);
+    assert_eq!(event.sign_bits_memory_records[0].timestamp, 9);
+    for i in 0..3 {
+        assert_eq!(event.x_memory_records[4 * i].timestamp, 10 + i as u64);
+        assert_eq!(event.points_memory_records[8 * i].timestamp, 14 + i as u64);
+    }
+    assert_eq!(event.status_memory_record.timestamp, 13);
+}
+
+fn decompress_xonly(
+    x: &BigUint,
+) -> (Result<EllipticCurveDecompressEvent, EcSyscallError>, Vec<u64>) {
+    let mut bytes = x.to_bytes_le();
+    bytes.resize(32, 0);
+    let (result, mut rt) = with_ctx(&[(0x1020, &bytes_to_words_le_vec(&bytes))], |ctx| {
+        try_create_ec_decompress_xonly_event::<Secp256k1, Trace>(ctx, 0x1000)
+    });
+    (result, read_words(&mut rt, 0x1000, 4))
+}
+
+#[test]
+fn test_ec_decompress_xonly() {
+    // Public keys from the BIP340 test vectors, with `y` computed by `lift_x`.
+    for (x, y) in [
+        (
+            "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
+        ),
+        (
+            "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            "2ce19b946c4ee58546f5251d441a065ea50735606985e5b228788bec4e582898",
+        ),
+    ] {
+        let x = BigUint::parse_bytes(x.as_bytes(), 16).unwrap();
+        let y = BigUint::parse_bytes(y.as_bytes(), 16).unwrap();
+        let (result, written) = decompress_xonly(&x);
+        assert!(!result.unwrap().sign_bit);
+        assert_eq!(BigUint::from_bytes_le(&words_to_bytes_le_vec(&written)), y);
+    }
+
+    // Test vector 5 has a public key that is not on the curve, and test vector 14 one that
+    // exceeds the field size. Neither is written back.
+    let off_curve = BigUint::parse_bytes(
+        b"eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34",
+        16,
+    )
+    .unwrap();
+    let (result, written) = decompress_xonly(&off_curve);
+    assert_eq!(result.unwrap_err(), EcSyscallError::NonResidue);
+    assert_eq!(written, [0; 4]);
+
+    let (result, written) = decompress_xonly(&(Secp256k1BaseField::modulus() + 1u32));
+    assert_eq!(result.unwrap_err(), EcSyscallError::NonCanonical);
+    assert_eq!(written, [0; 4]);
+}
+
+fn decompress_sec1<E: EllipticCurve + WeierstrassParameters>(
+    compressed: &[u8],
+    output_offset: usize,
+) -> (Result<EllipticCurveSec1DecompressEvent, EcSyscallError>, Vec<u8>) {
+    // Surround the encodings with marker bytes, at offsets within the first word.
+    let mut input = vec![0xaa; 0x60];
+    input[3..3 + compressed.len()].copy_from_slice(compressed);
+    let (result, mut rt) = with_ctx(
+        &[(0x1000, &bytes_to_words_le_vec(&input)), (0x2000, &[u64::from_le_bytes([0xbb; 8]); 12])],
+        |ctx| {
+            try_create_ec_decompress_sec1_event::<E, Trace>(
+                ctx,
+                0x1003,
+                0x2000 + output_offset as u64,
+            )
+        },
+    );
+    assert_eq!(read_words(&mut rt, 0x1000, 12), bytes_to_words_le_vec(&input));
+    (result, words_to_bytes_le_vec(&read_words(&mut rt, 0x2000, 12)))
+}
+
+#[test]
+fn test_ec_decompress_sec1() {
+    let secp256k1_modulus = Secp256k1BaseField::modulus();
+    let g_x = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    let g_y = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+    let negated_g_y =
+        format!("{:064x}", &secp256k1_modulus - BigUint::parse_bytes(g_y.as_bytes(), 16).unwrap());
+    // The generator and its negation, then 2G, whose `y` is even.
+    for (compressed, uncompressed) in [
+        (format!("02{g_x}"), format!("04{g_x}{g_y}")),
+        (format!("03{g_x}"), format!("04{g_x}{negated_g_y}")),
+        (
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5".to_string(),
+            "04c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5\
+             1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a"
+                .to_string(),
+        ),
+    ] {
+        let compressed = hex::decode(compressed).unwrap();
+        let uncompressed = hex::decode(uncompressed).unwrap();
+        for output_offset in [0, 5] {
+            let (result, output) = decompress_sec1::<Secp256k1>(&compressed, output_offset);
+            let event = result.unwrap();
+            assert_eq!(event.compressed, compressed);
+            assert_eq!(event.uncompressed, uncompressed);
+            assert_eq!(output[output_offset..output_offset + 65], uncompressed);
+            assert!(output[..output_offset].iter().all(|&b| b == 0xbb));
+            assert!(output[output_offset + 65..].iter().all(|&b| b == 0xbb));
+            assert_eq!(event.compressed_memory_records.len(), 5);
+            assert_eq!(event.uncompressed_memory_records.len(), 9);
+        }
+    }
+
+    // The generator of secp256r1 has an odd `y`.
+    let (result, _) = decompress_sec1::<Secp256r1>(
+        &hex::decode("036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296").unwrap(),
+        0,
+    );
+    assert_eq!(
+        result.unwrap().uncompressed,
+        hex::decode(
+            "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296\
+             4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+        )
+        .unwrap()
+    );
+
+    // An uncompressed or hybrid prefix, an `x` with no point and an unreduced `x` are rejected
+    // without writing anything.
+    let off_curve = "eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34";
+    let unreduced = format!("{:064x}", &secp256k1_modulus + 1u32);
+    for (compressed, err) in [
+        (format!("04{g_x}"), EcSyscallError::InvalidFlags(0x04)),
+        (format!("06{g_x}"), EcSyscallError::InvalidFlags(0x06)),
+        (format!("00{g_x}"), EcSyscallError::InvalidFlags(0x00)),
+        (format!("02{off_curve}"), EcSyscallError::NonResidue),
+        (format!("03{unreduced}"), EcSyscallError::NonCanonical),
+    ] {
+        let (result, output) = decompress_sec1::<Secp256k1>(&hex::decode(compressed).unwrap(), 0);
+        assert_eq!(result.unwrap_err(), err);
+        assert!(output.iter().all(|&b| b == 0xbb));
+    }
+}
+
+#[test]
+fn test_ec_ops_quota() {
+    let generator = Secp256k1::ec_generator();
+    let mut rt = executor_with(&[(0x1000, &generator.to_words_le())]);
+
+    for _ in 0..2 {
+        let mut ctx = SyscallContext::<QuotaTrace>::new(&mut rt, true);
+        assert!(try_create_ec_double_event::<Secp256k1, QuotaTrace>(&mut ctx, 0x1000, 0).is_ok());
+    }
+    let quadrupled = read_words(&mut rt, 0x1000, 8);
+    let mut ctx = SyscallContext::<QuotaTrace>::new(&mut rt, true);
+    assert_eq!(
+        try_create_ec_double_event::<Secp256k1, QuotaTrace>(&mut ctx, 0x1000, 0).unwrap_err(),
+        EcSyscallError::QuotaExceeded { max: 2 }
+    );
+    assert_eq!(read_words(&mut rt, 0x1000, 8), quadrupled);
+
+    // The quota is reset at the shard boundary.
+    rt.bump_record::<QuotaTrace>();
+    let mut ctx = SyscallContext::<QuotaTrace>::new(&mut rt, true);
+    assert!(try_create_ec_double_event::<Secp256k1, QuotaTrace>(&mut ctx, 0x1000, 0).is_ok());
+}
+
+#[test]
+fn test_ec_op_at_shard_boundary() {
+    let generator = Secp256k1::ec_generator();
+    let (first, mut rt) = with_ctx(&[(0x1000, &generator.to_words_le())], |ctx| {
+        create_ec_double_event::<Secp256k1, Trace>(ctx, 0x1000, 0)
+    });
+
+    // Finish the shard as `execute_cycle` does, and double again on the first cycle of the next.
+    rt.state.clk += 8;
+    rt.state.initial_timestamp = rt.state.clk;
+    rt.bump_record::<Trace>();
+    let first_shard_accesses = std::mem::take(&mut rt.record.cpu_local_memory_access);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let second = create_ec_double_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(second.clk, rt.state.initial_timestamp);
+
+    // Each event has exactly one local access per word, and the second starts where the first
+    // ended, so no access is lost or counted twice.
+    let accesses = |event: &EllipticCurveDoubleEvent| {
+        let mut accesses = event.local_mem_access.clone();
+        accesses.sort_by_key(|access| access.addr);
+        accesses
+    };
+    let (first, second) = (accesses(&first), accesses(&second));
+    assert_eq!(first.len(), 8);
+    assert_eq!(second.len(), 8);
+    for (i, (first, second)) in first.iter().zip(&second).enumerate() {
+        assert_eq!(first.addr, 0x1000 + 8 * i as u64);
+        assert_eq!(second.addr, first.addr);
+        assert_eq!(second.initial_mem_access.timestamp, first.final_mem_access.timestamp);
+        assert_eq!(second.initial_mem_access.value, first.final_mem_access.value);
+    }
+    assert!(first_shard_accesses.is_empty());
+    assert!(rt.record.cpu_local_memory_access.is_empty());
+}
+
+#[test]
+fn test_ec_op_straddling_shard_boundary() {
+    let mut rt = executor_with(&[(0x1000, &Secp256k1::ec_generator().to_words_le())]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    ctx.rt.state.initial_timestamp = ctx.clk;
+    let event = create_ec_double_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(ctx.execution_error, Some(ExecutionError::SyscallStraddlesShard(event.clk)));
+    assert!(event.local_mem_access.is_empty());
+}
+
+#[test]
+fn test_add_event_coordinate_words() {
+    let generator = Bn254::ec_generator();
+    let doubled = Bn254::ec_double(&generator);
+    let (event, _) =
+        with_ctx(&[(0x1000, &generator.to_words_le()), (0x2000, &doubled.to_words_le())], |ctx| {
+            create_ec_add_event::<Bn254, Trace>(ctx, 0x1000, 0x2000)
+        });
+
+    assert_eq!([event.p_x_words(), event.p_y_words()].concat(), event.p);
+    assert_eq!([event.q_x_words(), event.q_y_words()].concat(), event.q);
+    let coordinate = |words: &[u64]| BigUint::from_bytes_le(&words_to_bytes_le_vec(words));
+    assert_eq!(coordinate(event.p_x_words()), generator.x);
+    assert_eq!(coordinate(event.p_y_words()), generator.y);
+    assert_eq!(coordinate(event.q_x_words()), doubled.x);
+    assert_eq!(coordinate(event.q_y_words()), doubled.y);
+}
+
+fn check_event_affine_points<E: EllipticCurve>() {
+    let generator = E::ec_generator();
+    let doubled = E::ec_double(&generator);
+    let (event, mut rt) = with_ctx(
+        &[(0x1000, &generator.to_memory_words()), (0x2000, &doubled.to_memory_words())],
+        |ctx| create_ec_add_to_event::<E, Trace>(ctx, 0x1000, 0x2000, 0x3000),
+    );
+    assert_eq!(event.p_affine::<E>(), generator);
+    assert_eq!(event.q_affine::<E>(), doubled);
+    assert_eq!(event.result_affine::<E>(), E::ec_add(&generator, &doubled));
+
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_point_and_negate_event::<E, Trace>(&mut ctx, 0x1000, 0x4000);
+    assert_eq!(event.p_affine::<E>(), generator);
+    assert_eq!(event.neg_affine::<E>(), ec_negate(&generator));
+
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_add_event::<E, Trace>(&mut ctx, 0x1000, 0x2000);
+    assert_eq!(event.p_affine::<E>(), generator);
+    assert_eq!(event.q_affine::<E>(), doubled);
+
+    let sum = E::ec_add(&generator, &doubled);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_double_event::<E, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(event.p_affine::<E>(), sum);
+
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_negate_event::<E, Trace>(&mut ctx, 0x1000, 0);
+    assert_eq!(event.p_affine::<E>(), E::ec_double(&sum));
+    assert_eq!(
+        AffinePoint::<E>::from_memory_words(&read_words(
+            &mut rt,
+            0x1000,
+            AffinePoint::<E>::num_memory_words()
+        )),
+        ec_negate(&E::ec_double(&sum))
+    );
+}
+
+curve_test!(test_event_affine_points, check_event_affine_points: Secp256k1, Bn254, Ed25519);
+
+#[test]
+fn test_add_event_validate() {
+    let generator = Secp256k1::ec_generator();
+    let doubled = Secp256k1::ec_double(&generator);
+    let mut rt =
+        executor_with(&[(0x1000, &generator.to_words_le()), (0x2000, &doubled.to_words_le())]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    ctx.syscall_code = SyscallCode::SECP256K1_ADD;
+    let event = create_ec_add_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x2000);
+    assert_eq!(event.validate(), Ok(()));
+    let bytes = event.to_compact_bytes();
+    assert!(EllipticCurveAddEvent::from_compact_bytes(&bytes).is_ok());
+
+    let mut truncated = event.clone();
+    truncated.p_memory_records.pop();
+    let err = EcEventError::LengthMismatch { field: "p_memory_records", expected: 8, actual: 7 };
+    assert_eq!(truncated.validate(), Err(err.clone()));
+    let bytes = truncated.to_compact_bytes();
+    assert_eq!(
+        EllipticCurveAddEvent::from_compact_bytes(&bytes).unwrap_err(),
+        CompactDecodeError::Invalid(err)
+    );
+
+    // The lengths must agree with the curve, not just with each other.
+    let mut short = event;
+    for list in [&mut short.p, &mut short.q] {
+        list.truncate(4);
+    }
+    short.p_memory_records.truncate(4);
+    short.q_memory_records.truncate(4);
+    assert_eq!(
+        short.validate(),
+        Err(EcEventError::LengthMismatch { field: "p", expected: 8, actual: 4 })
+    );
+}
+
+#[test]
+fn test_add_event_verify() {
+    let generator = Bls12381::ec_generator();
+    let (event, _) = with_ctx(
+        &[
+            (0x1000, &generator.to_words_le()),
+            (0x2000, &Bls12381::ec_double(&generator).to_words_le()),
+        ],
+        |ctx| create_ec_add_event::<Bls12381, Trace>(ctx, 0x1000, 0x2000),
+    );
+    assert!(event.verify::<Bls12381>());
+    let (to_identity, _) = with_ctx(
+        &[
+            (0x1000, &generator.to_words_le()),
+            (0x3000, &Bls12381::ec_neg(&generator).to_words_le()),
+        ],
+        |ctx| create_ec_add_event::<Bls12381, Trace>(ctx, 0x3000, 0x1000),
+    );
+    assert!(to_identity.result_is_identity);
+    assert!(to_identity.verify::<Bls12381>());
+
+    let mut tampered = event.clone();
+    tampered.p_memory_records[3].value ^= 1;
+    assert!(!tampered.verify::<Bls12381>());
+    let mut tampered = event;
+    tampered.q = generator.to_words_le();
+    assert!(!tampered.verify::<Bls12381>());
+}
+
+#[test]
+fn test_ec_record_pool() {
+    let generator = Secp256k1::ec_generator();
+    let double = Secp256k1::ec_double(&generator);
+    let mut rt =
+        executor_with(&[(0x1000, &generator.to_words_le()), (0x2000, &double.to_words_le())]);
+
+    // A secp256k1 workload: once the first events are recycled, their vectors serve every
+    // later event.
+    let (mut point, mut q) = (generator, double);
+    for i in 0..16 {
+        let mut ctx = SyscallContext::<PooledTrace>::new(&mut rt, true);
+        let add = create_ec_add_event::<Secp256k1, PooledTrace>(&mut ctx, 0x1000, 0x2000);
+        let dbl = create_ec_double_event::<Secp256k1, PooledTrace>(&mut ctx, 0x2000, 0);
+        assert_eq!(add.p, point.to_words_le());
+        ctx.rt.ec_record_pool.recycle(PrecompileEvent::Secp256k1Add(add));
+        ctx.rt.ec_record_pool.recycle(PrecompileEvent::Secp256k1Double(dbl));
+        if i == 0 {
+            assert_eq!(ctx.rt.ec_record_pool.allocations(), 6);
+        }
+        point = Secp256k1::ec_add(&point, &q);
+        q = Secp256k1::ec_double(&q);
+    }
+    // An add draws `p`, `q` and their records, and a double `p` and its records.
+    assert_eq!(rt.ec_record_pool.takes(), 16 * 6);
+    assert_eq!(rt.ec_record_pool.allocations(), 6);
+    assert_eq!(read_words(&mut rt, 0x1000, 8), point.to_words_le());
+    assert_eq!(read_words(&mut rt, 0x2000, 8), q.to_words_le());
+
+    // The events of a shard go back to the pool once they are no longer needed.
+    let program = Arc::new(crate::programs::tests::secp256r1_add_program());
+    let mut runtime = Executor::new(program, SP1CoreOpts::default());
+    let (mut record, _) = runtime.execute_record(false).unwrap();
+    let num_adds = record.precompile_events.events[&SyscallCode::SECP256R1_ADD].len();
+    assert!(num_adds > 0);
+    runtime.recycle_ec_events(&mut record);
+    assert!(record.precompile_events.events.values().all(Vec::is_empty));
+    assert!(runtime.ec_record_pool.len() >= 4 * num_adds);
+}
+
+#[test]
+fn test_add_event_replay() {
+    // The additions of a real run replay without the executor.
+    let program = Arc::new(crate::programs::tests::secp256r1_add_program());
+    let mut runtime = Executor::new(program, SP1CoreOpts::default());
+    let mut events = Vec::new();
+    loop {
+        let (record, done) = runtime.execute_record(false).unwrap();
+        for (_, event) in
+            record.precompile_events.events.get(&SyscallCode::SECP256R1_ADD).into_iter().flatten()
+        {
+            let PrecompileEvent::Secp256r1Add(event) = event else {
+                panic!("expected a secp256r1 add event")
+            };
+            events.push(event.clone());
+        }
+        if done {
+            break;
+        }
+    }
+    assert!(!events.is_empty());
+    for event in &events {
+        assert_eq!(event.replay::<Secp256r1>(), Ok(()));
+    }
+
+    let inconsistent = |field| Err(EcEventError::Inconsistent { field });
+    let event = &events[0];
+    let mut tampered = event.clone();
+    tampered.q[0] ^= 1;
+    assert_eq!(tampered.replay::<Secp256r1>(), inconsistent("q_memory_records"));
+    let mut tampered = event.clone();
+    tampered.p_memory_records[0].prev_value ^= 1;
+    assert_eq!(tampered.replay::<Secp256r1>(), inconsistent("p_memory_records"));
+    let mut tampered = event.clone();
+    tampered.p_memory_records[7].value ^= 1;
+    assert_eq!(tampered.replay::<Secp256r1>(), inconsistent("p_memory_records"));
+    let mut tampered = event.clone();
+    tampered.result_is_identity = !tampered.result_is_identity;
+    assert_eq!(tampered.replay::<Secp256r1>(), inconsistent("result_is_identity"));
+    let mut tampered = event.clone();
+    tampered.p_memory_records[0].timestamp = tampered.q_memory_records[0].timestamp;
+    assert_eq!(tampered.replay::<Secp256r1>(), inconsistent("p_memory_records"));
+    let mut tampered = event.clone();
+    tampered.q.pop();
+    assert!(matches!(
+        tampered.replay::<Secp256r1>(),
+        Err(EcEventError::LengthMismatch { field: "q", .. })
+    ));
+}
+
+fn edwards_decompress_words<E: EllipticCurve + EdwardsParameters>(
+    compressed: &[u8],
+) -> (Vec<u64>, EdwardsDecompressEvent) {
+    let (event, mut rt) = with_ctx(&[(0x1000, &bytes_to_words_le_vec(compressed))], |ctx| {
+        create_edwards_decompress_event::<E, Trace>(ctx, 0x1000, 0x2000)
+    });
+    let output = read_words(&mut rt, 0x2000, 1 + event.point.len());
+    assert_eq!(output[0], event.status);
+    assert_eq!(output[1..], event.point);
+    assert_eq!(event.point_memory_records[0].timestamp, event.status_memory_record.timestamp + 1);
+    (output, event)
+}
+
+#[test]
+fn test_edwards_decompress() {
+    // Public keys of RFC 8032 tests 1 to 3, with `x` computed from the secret keys.
+    for (public_key, x, y) in [
+        (
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "55d0e09a2b9d34292297e08d60d0f620c513d47253187c24b12786bd777645ce",
+            "1a5107f7681a02af2523a6daf372e10e3a0764c9d3fe4bd5b70ab18201985ad7",
+        ),
+        (
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "74ad28205b4f384bc0813e6585864e528085f91fb6a5096f244ae01e57de43ae",
+            "0c66f42af155cdc08c96c42ecf2c989cbc7e1b4da70ab7925a8943e8c317403d",
+        ),
+        (
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "61213aa2dc9d68833f65d1b48dcf859818236f1734e3e9b945a9ff5486cdbd02",
+            "258090481591eb5dac0333ba13ed160858f03002d07ea48da3a118628ecd51fc",
+        ),
+    ] {
+        let (output, event) =
+            edwards_decompress_words::<Ed25519>(&hex::decode(public_key).unwrap());
+        assert_eq!(event.status, 0);
+        let hex_int = |s: &str| BigUint::parse_bytes(s.as_bytes(), 16).unwrap();
+        let expected = AffinePoint::<Ed25519>::new(hex_int(x), hex_int(y));
+        assert_eq!(output[1..], expected.to_words_le());
+        assert_eq!(event.compressed_memory_records.len(), 4);
+        assert_eq!(event.point_memory_records.len(), 8);
+    }
+
+    // There is no point with `y = 2`, `y = p` is not reduced, and the neutral element has no
+    // encoding with the sign bit set.
+    let modulus = Ed25519BaseField::modulus();
+    let mut neutral_negated = BigUint::from(1u32).to_bytes_le();
+    neutral_negated.resize(32, 0);
+    neutral_negated[31] |= 0x80;
+    for compressed in [BigUint::from(2u32).to_bytes_le(), modulus.to_bytes_le(), neutral_negated] {
+        let mut compressed = compressed;
+        compressed.resize(32, 0);
+        let (output, event) = edwards_decompress_words::<Ed25519>(&compressed);
+        assert_eq!(event.status, 1);
+        assert_eq!(output[1..], [0; 8]);
+    }
+
+    let mut point = Jubjub::ec_generator();
+    for _ in 0..4 {
+        let mut compressed = point.y.to_bytes_le();
+        compressed.resize(32, 0);
+        compressed[31] |= u8::from(point.x.bit(0)) << 7;
+        let (output, event) = edwards_decompress_words::<Jubjub>(&compressed);
+        assert_eq!(event.status, 0);
+        assert_eq!(output[1..], point.to_words_le());
+        point = Jubjub::ec_double(&point);
+    }
+}
+
+fn g2_decompress_words(compressed: &[u8]) -> G2DecompressEvent {
+    let (event, mut rt) = with_ctx(&[(0x1000, &bytes_to_words_le_vec(compressed))], |ctx| {
+        create_g2_decompress_event::<Trace>(ctx, 0x1000, 0x2000)
+    });
+    let output = read_words(&mut rt, 0x2000, 1 + event.point.len());
+    assert_eq!(output[0], event.status);
+    assert_eq!(output[1..], event.point);
+    assert_eq!(event.point_memory_records[0].timestamp, event.status_memory_record.timestamp + 1);
+    event
+}
+
+#[test]
+fn test_g2_decompress() {
+    let hex = |s: &str| hex::decode(s).unwrap();
+    let generator = Bls12381G2Parameters::generator();
+
+    // The serialization of the generator, and the aggregate of the public keys of the secret
+    // keys 1 and 2, which is the public key of 3.
+    let event = g2_decompress_words(&hex(concat!(
+        "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac",
+        "7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326",
+        "a805bbefd48056c8c121bdb8",
+    )));
+    assert_eq!(event.status, 0);
+    assert_eq!(event.point, generator.to_words_le());
+    let aggregate = generator.g2_add(&generator.g2_double().unwrap()).unwrap();
+    let event = g2_decompress_words(&hex(concat!(
+        "89380275bbc8e5dcea7dc4dd7e0550ff2ac480905396eda55062650f8d251c96eb480673937cc6d9d6a4",
+        "4aaa56ca66dc122915c824a0857e2ee414a3dccb23ae691ae54329781315a0c75df1c04d6d7a50a030fc",
+        "866f09d516020ef82324afae",
+    )));
+    assert_eq!(event.status, 0);
+    assert_eq!(event.point, aggregate.to_words_le());
+
+    // The negated generator differs only by the sign flag.
+    let mut negated = bls12381_g2_compress(Some(&generator));
+    negated[0] |= Y_IS_ODD_FLAG;
+    assert_eq!(g2_decompress_words(&negated).point, generator.g2_neg().to_words_le());
+
+    let infinity = g2_decompress_words(&bls12381_g2_compress(None));
+    assert_eq!(infinity.status, 0);
+    assert!(infinity.point.iter().all(|&word| word == 0));
+
+    // Uncompressed flags, a signed infinity, and an x coordinate off the curve are rejected.
+    let mut uncompressed = bls12381_g2_compress(Some(&generator));
+    uncompressed[0] &= !COMPRESSION_FLAG;
+    let mut signed_infinity = bls12381_g2_compress(None);
+    signed_infinity[0] |= Y_IS_ODD_FLAG;
+    let mut off_curve = [0u8; 96];
+    off_curve[0] = COMPRESSION_FLAG;
+    off_curve[95] = 1;
+    assert_eq!(g2_decompress(&uncompressed), Err(EcSyscallError::InvalidFlags(0x00)));
+    assert_eq!(g2_decompress(&signed_infinity), Err(EcSyscallError::InvalidFlags(0xe0)));
+    assert_eq!(g2_decompress(&off_curve), Err(EcSyscallError::NonResidue));
+    for invalid in [uncompressed, signed_infinity, off_curve] {
+        let event = g2_decompress_words(&invalid);
+        assert_eq!(event.status, 1);
+        assert!(event.point.iter().all(|&word| word == 0));
+    }
+}
+
+#[test]
+fn test_limbs_to_words() {
+    fn check<E: EllipticCurve>() {
+        let num_limbs = <E::BaseField as NumLimbs>::Limbs::USIZE;
+        assert_eq!(num_limbs % 8, 0, "the {} limbs are not whole words", E::CURVE_TYPE);
+        assert_eq!(limbs_to_words(num_limbs), <E::BaseField as NumWords>::WordsFieldElement::USIZE);
+    }
+
+    assert_eq!(limbs_to_words(32), 4);
+    assert_eq!(limbs_to_words(48), 6);
+    // A 33-byte element takes a fifth, partially used word.
+    assert_eq!(limbs_to_words(33), 5);
+
+    check::<Secp256k1>();
+    check::<Secp256r1>();
+    check::<Bn254>();
+    check::<Bls12381>();
+    check::<Ed25519>();
+    check::<Jubjub>();
+    check::<Pallas>();
+    check::<Vesta>();
+}
+
+fn bls_deserialize(g1: &[u8], g2: &[u8]) -> BlsDeserializeEvent {
+    let (event, mut rt) = with_ctx(
+        &[(0x1000, &bytes_to_words_le_vec(g1)), (0x2000, &bytes_to_words_le_vec(g2))],
+        |ctx| create_bls_deserialize_event::<Trace>(ctx, 0x1000, 0x2000, 0x3000),
+    );
+    let output = read_words(&mut rt, 0x3000, 37);
+    assert_eq!(output[0], event.status());
+    assert_eq!(output[1..13], event.g1_point);
+    assert_eq!(output[13..], event.g2_point);
+    event
+}
+
+#[test]
+fn test_bls_deserialize() {
+    let g1 = Bls12381::ec_generator();
+    let g2 = Bls12381G2Parameters::generator();
+    let g1_bytes = bls12381_compress(Some(&g1));
+    let g2_bytes = bls12381_g2_compress(Some(&g2));
+
+    let event = bls_deserialize(&g1_bytes, &g2_bytes);
+    assert_eq!(event.status(), 0);
+    assert_eq!(event.g1_point, g1.to_memory_words());
+    assert_eq!(event.g2_point, g2.to_words_le());
+    // Each slice is accessed a cycle after the previous one.
+    let timestamps = [
+        event.g1_memory_records[0].timestamp,
+        event.g2_memory_records[0].timestamp,
+        event.status_memory_record.timestamp,
+        event.point_memory_records[0].timestamp,
+    ];
+    assert_eq!(timestamps, [8, 9, 10, 11]);
+    let negated = Bls12381::ec_neg(&g1);
+    let event = bls_deserialize(&bls12381_compress(Some(&negated)), &bls12381_g2_compress(None));
+    assert_eq!(event.status(), 0);
+    assert_eq!(event.g1_point, negated.to_memory_words());
+    assert!(event.g2_point.iter().all(|&word| word == 0));
+
+    // The points with `x = 4` in G1 and `x = 2` in G2 are on their curves but not in the
+    // prime-order subgroups.
+    let g1_outside = bls12381_compress(Some(&AffinePoint::<Bls12381>::new(
+        BigUint::from(4u32),
+        BigUint::parse_bytes(
+            b"0a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c",
+            16,
+        )
+        .unwrap(),
+    )));
+    let g2_outside =
+        bls12381_g2_compress(Some(&bls12381_g2_decompress(&Fp2::from_u32(2, 0), false).unwrap()));
+    let mut g1_off_curve = [0u8; 48];
+    g1_off_curve[0] = COMPRESSION_FLAG;
+    g1_off_curve[47] = 1;
+    let mut g2_uncompressed = g2_bytes;
+    g2_uncompressed[0] &= !COMPRESSION_FLAG;
+
+    let (valid, outside, invalid) = (
+        EcValidationStatus::Valid,
+        EcValidationStatus::NotInSubgroup,
+        EcValidationStatus::NotOnCurve,
+    );
+    for (g1_bytes, g2_bytes, g1_status, g2_status) in [
+        (&g1_outside, &g2_bytes, outside, valid),
+        (&g1_bytes, &g2_outside, valid, outside),
+        (&g1_off_curve, &g2_outside, invalid, outside),
+        (&g1_outside, &g2_uncompressed, outside, invalid),
+    ] {
+        let event = bls_deserialize(g1_bytes, g2_bytes);
+        assert_eq!((event.g1_status, event.g2_status), (g1_status, g2_status));
+        assert_eq!(event.status(), g1_status as u64 | ((g2_status as u64) << 32));
+        if g1_status != valid {
+            assert!(event.g1_point.iter().all(|&word| word == 0));
+        }
+        if g2_status != valid {
+            assert!(event.g2_point.iter().all(|&word| word == 0));
+        }
+    }
+}
+
+#[test]
+fn test_ec_clear_cofactor() {
+    // The point with `x = 4` is on the curve but not in the prime-order subgroup.
+    let p_affine = AffinePoint::<Bls12381>::new(
+        BigUint::from(4u32),
+        BigUint::parse_bytes(
+            b"0a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c",
+            16,
+        )
+        .unwrap(),
+    );
+    assert!(!in_prime_subgroup(p_affine.clone()));
+
+    let clear_cofactor = |words: &[u64]| {
+        let (event, mut rt) = with_ctx(&[(0x1000, words)], |ctx| {
+            create_ec_clear_cofactor_event::<Bls12381, Trace>(ctx, 0x1000, 0)
+        });
+        assert_eq!(event.p, words);
+        read_words(&mut rt, 0x1000, words.len())
+    };
+    let result = AffinePoint::<Bls12381>::from_words_le(&clear_cofactor(&p_affine.to_words_le()));
+    assert!(Bls12381::ec_is_on_curve(&result));
+    assert!(in_prime_subgroup(result.clone()));
+
+    // The fast version multiplies by `1 - z` rather than by `h`, a different representative.
+    let naive = ec_scalar_mul(&p_affine, &Bls12381::cofactor()).unwrap();
+    assert!(in_prime_subgroup(naive.clone()));
+    assert_ne!(result, naive);
+
+    let identity = vec![0; p_affine.to_words_le().len()];
+    assert_eq!(clear_cofactor(&identity), identity);
+
+    // The cofactor of BN254 G1 is 1.
+    let generator = Bn254::ec_generator().to_words_le();
+    let (_, mut rt) = with_ctx(&[(0x1000, &generator)], |ctx| {
+        create_ec_clear_cofactor_event::<Bn254, Trace>(ctx, 0x1000, 0)
+    });
+    assert_eq!(read_words(&mut rt, 0x1000, generator.len()), generator);
+}
+
+#[test]
+fn test_hash_to_curve() {
+    let int = |hex: &str| BigUint::parse_bytes(hex.as_bytes(), 16).unwrap();
+    let map_to_curve = |u: &str| {
+        let mut u_words = int(u).to_bytes_le();
+        u_words.resize(48, 0);
+        let u_words = bytes_to_words_le_vec(&u_words);
+        let (event, mut rt) = with_ctx(&[(0x1000, &u_words)], |ctx| {
+            create_hash_to_curve_event::<Bls12381, Trace>(ctx, 0x2000, 0x1000)
+        });
+        assert_eq!(event.u, u_words);
+        assert_eq!(read_words(&mut rt, 0x2000, 12), event.p);
+        AffinePoint::<Bls12381>::from_words_le(&event.p)
+    };
+
+    // The empty message of the `BLS12381G1_XMD:SHA-256_SSWU_RO_` test vectors of RFC 9380,
+    // Appendix J.9.1: `u[0]` and `u[1]` map to `Q0` and `Q1`, and `P` is their sum with the
+    // cofactor cleared.
+    let q0 = map_to_curve("0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f");
+    assert_eq!(q0.x, int("11a3cce7e1d90975990066b2f2643b9540fa40d6137780df4e753a8054d07580db3b7f1f03396333d4a359d1fe3766fe"));
+    assert_eq!(q0.y, int("0eeaf6d794e479e270da10fdaf768db4c96b650a74518fc67b04b03927754bac66f3ac720404f339ecdcc028afa091b7"));
+    let q1 = map_to_curve("019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9");
+    assert_eq!(q1.x, int("160003aaf1632b13396dbad518effa00fff532f604de1a7fc2082ff4cb0afa2d63b2c32da1bef2bf6c5ca62dc6b72f9c"));
+    assert_eq!(q1.y, int("0d8bb2d14e20cf9f6036152ed386d79189415b6d015a20133acb4e019139b94e9c146aaad5817f866c95d609a361735e"));
+
+    let sum = Bls12381::ec_add(&q0, &q1).to_words_le();
+    let (_, mut rt) = with_ctx(&[(0x1000, &sum)], |ctx| {
+        create_ec_clear_cofactor_event::<Bls12381, Trace>(ctx, 0x1000, 0)
+    });
+    let p = AffinePoint::<Bls12381>::from_words_le(&read_words(&mut rt, 0x1000, 12));
+    assert_eq!(p.x, int("052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1"));
+    assert_eq!(p.y, int("08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265"));
+
+    assert!(map_to_curve_for_curve::<Bls12381>().is_some());
+    assert!(map_to_curve_for_curve::<Secp256k1>().is_none());
+}
+
+#[test]
+fn test_decompress_non_residue_stats() {
+    let generator = Secp256k1::ec_generator();
+    let xs = [generator.x.clone(), BigUint::from(5u32), BigUint::from(7u32)];
+    let mut rt = executor();
+    let stats = Arc::clone(&rt.ec_stats);
+    let num_x_words = 4;
+    let mut input = vec![xs.len() as u64, 0];
+    for x in &xs {
+        let mut bytes = x.to_bytes_le();
+        bytes.resize(num_x_words * 8, 0);
+        input.extend(bytes_to_words_le_vec(&bytes));
+    }
+    // An unreduced coordinate fails the batch but is not a non-residue.
+    input[0] += 1;
+    input.extend([u64::MAX; 4]);
+    write_words(&mut rt, 0x1000, &input);
+    // 4^3 + 3 is not a quadratic residue modulo the Bn254 base field, and there is no Ed25519
+    // point with `y = 2`.
+    write_words(&mut rt, 0x4020, &[4, 0, 0, 0]);
+    write_words(&mut rt, 0x5000, &[2, 0, 0, 0]);
+    write_words(&mut rt, 0x6000, &[3, 0, 0, 0]);
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_decompress_batch_event::<Secp256k1, Trace>(&mut ctx, 0x1000, 0x8000);
+    assert_eq!(event.status, 2);
+    assert_eq!(event.points[..8], generator.to_words_le());
+    assert_eq!(stats.decompress_non_residues(), 2);
+    let result = try_create_ec_decompress_event::<Bn254, Trace>(&mut ctx, 0x4000, 0);
+    assert_eq!(result.unwrap_err(), EcSyscallError::NonResidue);
+    assert_eq!(
+        create_edwards_decompress_event::<Ed25519, Trace>(&mut ctx, 0x5000, 0x9000).status,
+        1
+    );
+    assert_eq!(
+        create_edwards_decompress_event::<Ed25519, Trace>(&mut ctx, 0x6000, 0x9000).status,
+        0
+    );
+    assert_eq!(stats.decompress_non_residues(), 4);
+}
+
+fn check_add_projective<E: EllipticCurve + WeierstrassParameters>() {
+    let generator = E::ec_generator();
+    let mut points = vec![generator.clone()];
+    for _ in 0..5 {
+        points.push(E::ec_add(&points[points.len() - 1], &generator));
+    }
+    let num_words = generator.to_words_le().len();
+    let identity = vec![0; num_words];
+    let mut inputs = points.iter().map(AffinePoint::to_words_le).collect::<Vec<_>>();
+    // The point at infinity leaves the sum unchanged. The sum of the first two points is the
+    // third, so adding it doubles.
+    inputs.insert(2, identity.clone());
+
+    let mut rt = executor();
+    for (i, input) in inputs.iter().enumerate() {
+        write_words(&mut rt, 0x2000 + 0x100 * i as u64, input);
+    }
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    for i in 0..inputs.len() {
+        create_ec_add_projective_event::<E, Trace>(&mut ctx, 0x1000, 0x2000 + 0x100 * i as u64);
+        create_ec_add_event::<E, Trace>(&mut ctx, 0x8000, 0x2000 + 0x100 * i as u64);
+    }
+    let event = create_ec_normalize_projective_event::<E, Trace>(&mut ctx, 0x1000, 0x9000);
+    let expected = read_words(&mut rt, 0x8000, num_words);
+    assert_eq!(event.result, expected);
+    assert_eq!(read_words(&mut rt, 0x9000, num_words), expected);
+    assert_eq!(event.acc.len(), 3 * num_words / 2);
+
+    // A zeroed accumulator is the point at infinity.
+    let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+    let event = create_ec_normalize_projective_event::<E, Trace>(&mut ctx, 0xa000, 0x9000);
+    assert_eq!(event.result, identity);
+}
+
+fn check_jacobian_round_trip<E: EllipticCurve + WeierstrassParameters>() {
+    // A fixed xorshift sequence of scalars, so the points are arbitrary but reproducible.
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let generator = E::ec_generator();
+    let num_words = generator.to_words_le().len();
+    let mut inputs = (0..8)
+        .map(|_| {
+            let scalar = (BigUint::from(next()) << 64u32) + next();
+            encode_point(ec_scalar_mul(&generator, &scalar)).to_memory_words()
+        })
+        .collect::<Vec<_>>();
+    inputs.push(vec![0; num_words]);
+
+    for input in inputs {
+        let (to, mut rt) = with_ctx(&[(0x1000, &input)], |ctx| {
+            create_ec_to_jacobian_event::<E, Trace>(ctx, 0x1000, 0x2000)
+        });
+        assert_eq!(to.result.len(), JacobianPoint::<E>::num_memory_words());
+        assert_eq!(read_words(&mut rt, 0x2000, to.result.len()), to.result);
+        let jacobian = JacobianPoint::<E>::from_memory_words(&to.result);
+        assert_eq!(jacobian.is_identity(), input.iter().all(|&word| word == 0));
+
+        let mut ctx = SyscallContext::<Trace>::new(&mut rt, true);
+        let from = create_ec_normalize_projective_event::<E, Trace>(&mut ctx, 0x2000, 0x3000);
+        assert_eq!(from.result, input);
+        assert_eq!(read_words(&mut rt, 0x3000, num_words), input);
+    }
+}
+
+curve_test!(test_jacobian_round_trip, check_jacobian_round_trip: Secp256k1, Bn254, Bls12381);
+
+curve_test!(test_add_projective, check_add_projective: Secp256k1, Bn254, Bls12381);
+
+#[test]
+fn test_add_with_mock_context() {
+    let num_words = <Secp256k1BaseField as NumWords>::WordsCurvePoint::USIZE;
+    let generator = Secp256k1::ec_generator();
+    let doubled = Secp256k1::ec_double(&generator);
+
+    let mut ctx = MockSyscallContext::new(8, 0x0a);
+    ctx.write_words(0x1000, &generator.to_memory_words());
+    ctx.write_words(0x2000, &doubled.to_memory_words());
+    let event = ec_add_event::<Secp256k1, Trace>(&mut ctx, None, 0x1000, 0x2000).unwrap();
+
+    let sum = Secp256k1::ec_add(&generator, &doubled);
+    assert_eq!(ctx.read_words(0x1000, num_words), sum.to_memory_words());
+    assert_eq!(event.p, generator.to_memory_words());
+    assert_eq!(event.q, doubled.to_memory_words());
+    assert_eq!((event.clk, event.cycles, event.syscall_id), (8, 1, 0x0a));
+    assert!(!event.result_is_identity);
+    assert!(event.q_memory_records.iter().all(|record| record.timestamp == 8));
+    for (record, prev_value) in event.p_memory_records.iter().zip(&event.p) {
+        assert_eq!((record.timestamp, record.prev_value), (9, *prev_value));
+    }
+    assert!(event.page_prot_records.read_page_prot_records.is_empty());
+
+    // Adding the negation writes the point at infinity.
+    ctx.write_words(0x2000, &Secp256k1::ec_neg(&sum).to_memory_words());
+    let event = ec_add_event::<Secp256k1, Trace>(&mut ctx, None, 0x1000, 0x2000).unwrap();
+    assert!(event.result_is_identity);
+    assert_eq!(event.clk, 9);
+    assert!(event.q_memory_records.iter().all(|record| record.prev_timestamp == 8));
+}
+
+#[test]
+fn test_add_with_mock_page_prot() {
+    let num_words = <Secp256k1BaseField as NumWords>::WordsCurvePoint::USIZE;
+    let generator = Secp256k1::ec_generator();
+    let page = PAGE_SIZE as u64;
+
+    let mut ctx = MockSyscallContext::new(8, 0x0a);
+    ctx.enable_page_prot = true;
+    ctx.page_prots.insert(2, PROT_WRITE);
+    ctx.write_words(page, &generator.to_memory_words());
+    ctx.write_words(2 * page, &generator.to_memory_words());
+    assert_eq!(
+        check_page_prot(&ctx, 2 * page, num_words, PROT_READ).unwrap_err(),
+        EcSyscallError::PageProtViolation { ptr: 2 * page }
+    );
+
+    ctx.page_prots.insert(2, PROT_READ);
+    let event = ec_add_event::<Secp256k1, Trace>(&mut ctx, None, page, 2 * page).unwrap();
+    let records = &event.page_prot_records;
+    assert_eq!(records.read_page_prot_records.len(), 1);
+    assert_eq!(records.read_page_prot_records[0].page_prot, PROT_READ);
+    assert_eq!(records.write_page_prot_records.len(), 1);
+    assert_eq!(records.write_page_prot_records[0].page_prot, DEFAULT_PAGE_PROT);
+    assert_eq!(ctx.read_words(page, num_words), Secp256k1::ec_double(&generator).to_memory_words());
+}
@@ -0,0 +1,225 @@
+//! The error type shared by the precompile event constructors.
+
+use thiserror::Error;
+
+use crate::ExecutionError;
+
+/// Errors that can occur while creating a precompile event.
+///
+/// The fallible `try_` constructors of the elliptic curve events return these, and the others
+/// panic with their message.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EcSyscallError {
+    /// The x coordinate does not correspond to a point on the curve.
+    #[error("x coordinate is not on the curve")]
+    NonResidue,
+    /// The sign bit of a compressed point is neither 0 nor 1.
+    #[error("sign bit must be 0 or 1, got {0}")]
+    InvalidSignBit(u64),
+    /// A point does not lie on the curve.
+    #[error("point is not on the curve")]
+    NotOnCurve,
+    /// A compressed coordinate is not less than the base field modulus.
+    #[error("compressed coordinate is not reduced modulo the base field")]
+    NonCanonical,
+    /// The compressed point of a twisted Edwards curve has `x = 0` but its sign bit is set, an
+    /// encoding RFC 8032 rejects.
+    #[error("x coordinate is zero but the sign bit is set")]
+    NegativeZero,
+    /// The operands of an add or double syscall take an exceptional case of the short Weierstrass
+    /// formulas, which the precompile chips do not constrain.
+    #[error("the operands take an exceptional case of the curve formulas")]
+    ExceptionalOperands,
+    /// The flag bits of a serialized point are inconsistent.
+    #[error("invalid serialization flags {0:#04x}")]
+    InvalidFlags(u8),
+    /// A pointer argument is not 8-byte aligned.
+    #[error("{name} must be 8-byte aligned, got {ptr:#x}")]
+    Unaligned {
+        /// The name of the pointer argument.
+        name: &'static str,
+        /// The misaligned pointer.
+        ptr: u64,
+    },
+    /// A byte length argument is not the size of the value it refers to.
+    #[error("expected a length of {expected} bytes, got {len}")]
+    InvalidLength {
+        /// The length argument.
+        len: u64,
+        /// The required length.
+        expected: u64,
+    },
+    /// A length argument in words is zero or larger than the event allows.
+    #[error("expected between 1 and {max} words, got {num_words}")]
+    InvalidNumWords {
+        /// The length argument.
+        num_words: u64,
+        /// The largest length allowed.
+        max: u64,
+    },
+    /// The event is on a custom curve whose id has no implementation registered with the
+    /// executor.
+    #[error("no implementation is registered for the custom curve with id {0}")]
+    UnregisteredCurve(u32),
+    /// The curve is configured with points or field elements of zero words.
+    #[error("the curve has points of zero words")]
+    EmptyPoint,
+    /// The two points of an addition overlap without being the same point.
+    #[error("points at {p_ptr:#x} and {q_ptr:#x} partially overlap")]
+    PartialOverlap {
+        /// The pointer to the first point.
+        p_ptr: u64,
+        /// The pointer to the second point.
+        q_ptr: u64,
+    },
+    /// A pointer argument refers to memory that another argument of the same call also uses.
+    #[error("{name} at {ptr:#x} overlaps the memory of another argument")]
+    Overlap {
+        /// The name of the pointer argument.
+        name: &'static str,
+        /// The overlapping pointer.
+        ptr: u64,
+    },
+    /// A buffer argument runs past the end of the address space.
+    #[error("the buffer {name} at {ptr:#x} runs past the end of the address space")]
+    AddressOverflow {
+        /// The name of the pointer argument.
+        name: &'static str,
+        /// The pointer to the buffer.
+        ptr: u64,
+    },
+    /// A pointer argument refers to memory whose page protection forbids the access.
+    #[error("page protection forbids the access at {ptr:#x}")]
+    PageProtViolation {
+        /// The pointer whose memory is protected.
+        ptr: u64,
+    },
+    /// The shard has already created [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`] elliptic curve
+    /// events.
+    ///
+    /// [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`]: crate::ExecutorConfig::MAX_EC_OPS_PER_SHARD
+    #[error("the shard exceeded its quota of {max} elliptic curve operations")]
+    QuotaExceeded {
+        /// The maximum number of elliptic curve events per shard.
+        max: u64,
+    },
+    /// The execution cannot go on, as when the clock overflows. The syscalls end the execution
+    /// with the inner error whatever the [`AssertionPolicy`].
+    #[error(transparent)]
+    Execution(#[from] ExecutionError),
+    /// A single event was asked for more elliptic curve operations than it can perform, whether
+    /// or not the shard has a quota.
+    #[error("requested {count} elliptic curve operations in one event, at most {max} are allowed")]
+    TooManyOperations {
+        /// The number of operations requested.
+        count: u64,
+        /// The maximum number of operations of one event.
+        max: u64,
+    },
+}
+
+/// How the elliptic curve syscalls handle an [`EcSyscallError`] from their event constructors, set
+/// by [`ExecutorConfig::ASSERTION_POLICY`].
+///
+/// Only [`AssertionPolicy::Panic`] can be used under [`ExecutorMode::Trace`]: the other policies
+/// emit no precompile event for a failed syscall, which would leave the syscall chip with a send
+/// that no precompile chip receives, so [`Executor::execute`] refuses them.
+///
+/// [`ExecutorConfig::ASSERTION_POLICY`]: crate::ExecutorConfig::ASSERTION_POLICY
+/// [`ExecutorMode::Trace`]: crate::ExecutorMode::Trace
+/// [`Executor::execute`]: crate::Executor::execute
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AssertionPolicy {
+    /// Panic with the error, aborting the execution.
+    #[default]
+    Panic,
+    /// Return [`EC_SYSCALL_FAILED`] to the program in `t0` and emit no event.
+    ReturnError,
+    /// Log the error and halt the program with [`EC_SYSCALL_FAILED_EXIT_CODE`], emitting no event.
+    LogAndHalt,
+}
+
+/// The value returned in `t0` by an elliptic curve syscall that failed under
+/// [`AssertionPolicy::ReturnError`].
+pub const EC_SYSCALL_FAILED: u64 = u64::MAX;
+
+/// The exit code of a program halted by an elliptic curve syscall that failed under
+/// [`AssertionPolicy::LogAndHalt`].
+pub const EC_SYSCALL_FAILED_EXIT_CODE: u32 = 1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        for (err, message) in [
+            (EcSyscallError::NonResidue, "x coordinate is not on the curve"),
+            (EcSyscallError::InvalidSignBit(2), "sign bit must be 0 or 1, got 2"),
+            (EcSyscallError::NotOnCurve, "point is not on the curve"),
+            (
+                EcSyscallError::NonCanonical,
+                "compressed coordinate is not reduced modulo the base field",
+            ),
+            (
+                EcSyscallError::ExceptionalOperands,
+                "the operands take an exceptional case of the curve formulas",
+            ),
+            (EcSyscallError::InvalidFlags(0xe0), "invalid serialization flags 0xe0"),
+            (
+                EcSyscallError::Unaligned { name: "p_ptr", ptr: 0x1004 },
+                "p_ptr must be 8-byte aligned, got 0x1004",
+            ),
+            (
+                EcSyscallError::InvalidLength { len: 31, expected: 32 },
+                "expected a length of 32 bytes, got 31",
+            ),
+            (
+                EcSyscallError::InvalidNumWords { num_words: 0, max: 512 },
+                "expected between 1 and 512 words, got 0",
+            ),
+            (
+                EcSyscallError::UnregisteredCurve(7),
+                "no implementation is registered for the custom curve with id 7",
+            ),
+            (EcSyscallError::EmptyPoint, "the curve has points of zero words"),
+            (EcSyscallError::NegativeZero, "x coordinate is zero but the sign bit is set"),
+            (
+                EcSyscallError::PartialOverlap { p_ptr: 0x1000, q_ptr: 0x1020 },
+                "points at 0x1000 and 0x1020 partially overlap",
+            ),
+            (
+                EcSyscallError::Overlap { name: "sign_ptr", ptr: 0x1020 },
+                "sign_ptr at 0x1020 overlaps the memory of another argument",
+            ),
+            (
+                EcSyscallError::AddressOverflow { name: "base_ptr", ptr: 0x1000 },
+                "the buffer base_ptr at 0x1000 runs past the end of the address space",
+            ),
+            (
+                EcSyscallError::PageProtViolation { ptr: 0x2000 },
+                "page protection forbids the access at 0x2000",
+            ),
+            (
+                EcSyscallError::QuotaExceeded { max: 2 },
+                "the shard exceeded its quota of 2 elliptic curve operations",
+            ),
+            (
+                EcSyscallError::TooManyOperations { count: 5000, max: 4096 },
+                "requested 5000 elliptic curve operations in one event, at most 4096 are allowed",
+            ),
+            (
+                EcSyscallError::Execution(ExecutionError::ClockOverflow(7)),
+                "clock overflow at cycle 7",
+            ),
+        ] {
+            assert_eq!(err.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn test_is_error() {
+        let err: Box<dyn std::error::Error> = Box::new(EcSyscallError::NotOnCurve);
+        assert!(err.source().is_none());
+    }
+}
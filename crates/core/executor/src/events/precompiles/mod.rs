@@ -1,5 +1,6 @@
 mod ec;
 mod edwards;
+mod error;
 mod fptower;
 mod keccak256_permute;
 mod mprotect;
@@ -15,6 +16,7 @@ use crate::{deserialize_hashmap_as_vec, serialize_hashmap_as_vec, syscalls::Sysc
 use deepsize2::DeepSizeOf;
 pub use ec::*;
 pub use edwards::*;
+pub use error::*;
 pub use fptower::*;
 use hashbrown::HashMap;
 pub use keccak256_permute::*;
@@ -89,6 +91,8 @@ pub enum PrecompileEvent {
     Mprotect(MProtectEvent),
     /// POSEIDON2 precompile event.
     POSEIDON2(Poseidon2PrecompileEvent),
+    /// Elliptic curve add or double precompile event that reduces to a copy.
+    EcNoop(EllipticCurveNoopEvent),
 }
 
 /// Trait to retrieve all the local memory events from a vec of precompile events.
@@ -158,6 +162,9 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::POSEIDON2(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::EcNoop(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
                 PrecompileEvent::Mprotect(_) => {
                     // Mprotect doesn't have local memory access events
                 }
@@ -227,6 +234,9 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::EdDecompress(e) => {
                     iterators.push(e.local_page_prot_access.iter());
                 }
+                PrecompileEvent::EcNoop(e) => {
+                    iterators.push(e.local_page_prot_access.iter());
+                }
             }
         }
 
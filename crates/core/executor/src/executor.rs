@@ -7,8 +7,8 @@ use crate::profiler::Profiler;
 use crate::{
     estimator::RecordEstimator,
     events::{
-        InstructionDecodeEvent, InstructionFetchEvent, MemoryRecordEnum,
-        PageProtInitializeFinalizeEvent, PageProtLocalEvent, PageProtRecord,
+        EcEventSink, EcRecordPool, EcStats, InstructionDecodeEvent, InstructionFetchEvent,
+        MemoryRecordEnum, PageProtInitializeFinalizeEvent, PageProtLocalEvent, PageProtRecord,
         NUM_LOCAL_PAGE_PROT_ENTRIES_PER_ROW_EXEC, NUM_PAGE_PROT_ENTRIES_PER_ROW_EXEC,
     },
     StatusCode, NUM_REGISTERS,
@@ -20,6 +20,7 @@ use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 use rrs_lib::process_instruction;
 use serde::{Deserialize, Serialize};
+use sp1_curves::registry::CustomCurveRegistry;
 use sp1_hypercube::air::PublicValues;
 use sp1_primitives::consts::{
     DEFAULT_PAGE_PROT, MAXIMUM_MEMORY_SIZE, PAGE_SIZE, PROT_EXEC, PROT_READ, PROT_WRITE,
@@ -31,9 +32,9 @@ use crate::{
     disassembler::InstructionTranspiler,
     estimate_trace_elements,
     events::{
-        AluEvent, BranchEvent, JumpEvent, MemInstrEvent, MemoryAccessPosition, MemoryEntry,
-        MemoryInitializeFinalizeEvent, MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord,
-        SyscallEvent, UTypeEvent, NUM_LOCAL_MEMORY_ENTRIES_PER_ROW_EXEC,
+        AluEvent, AssertionPolicy, BranchEvent, JumpEvent, MemInstrEvent, MemoryAccessPosition,
+        MemoryEntry, MemoryInitializeFinalizeEvent, MemoryLocalEvent, MemoryReadRecord,
+        MemoryWriteRecord, SyscallEvent, UTypeEvent, NUM_LOCAL_MEMORY_ENTRIES_PER_ROW_EXEC,
     },
     hook::{HookEnv, HookRegistry},
     memory::{Entry, Memory},
@@ -110,6 +111,18 @@ pub struct Executor<'a> {
     /// Report of the program execution.
     pub report: ExecutionReport,
 
+    /// Counters of notable elliptic curve precompile outcomes, shared so that they can be read
+    /// while or after the program executes.
+    pub ec_stats: Arc<EcStats>,
+
+    /// The sink of elliptic curve precompile events, opened on the first event when
+    /// [`ExecutorConfig::EC_EVENT_TRACE_PATH`] is set.
+    pub ec_event_sink: Option<EcEventSink>,
+
+    /// The reusable vectors of the syscall memory records, drawn from when
+    /// [`ExecutorConfig::POOL_EC_RECORDS`] is set.
+    pub ec_record_pool: EcRecordPool,
+
     /// The memory accesses for the current cycle.
     pub memory_accesses: MemoryAccessRecord,
 
@@ -162,6 +175,9 @@ pub struct Executor<'a> {
     /// Registry of hooks, to be invoked by writing to certain file descriptors.
     pub hook_registry: HookRegistry<'a>,
 
+    /// The implementations of the custom curves the elliptic curve events operate on.
+    pub custom_curves: CustomCurveRegistry,
+
     /// The costs of the program.
     pub costs: EnumMap<RiscvAirId, u64>,
 
@@ -212,6 +228,53 @@ pub trait ExecutorConfig {
     const MODE: ExecutorMode;
     /// Whether the executor is in unconstrained mode.
     const UNCONSTRAINED: bool;
+    /// Whether elliptic curve precompiles check that their input points lie on the curve.
+    const VALIDATE_EC_INPUTS: bool = false;
+    /// Whether the memory accesses of syscalls record page protection accesses. Programs that do
+    /// not enable untrusted programs record none either way, and [`Executor::execute`] refuses to
+    /// run one that does with this disabled, so it only takes effect for syscall contexts used
+    /// outside of [`Executor::execute`].
+    const RECORD_PAGE_PROT: bool = true;
+    /// Whether elliptic curve doubling writes back the words it read when the result equals the
+    /// input, instead of re-encoding the result. A write is recorded either way.
+    const ELIDE_UNCHANGED_EC_WRITES: bool = false;
+    /// Whether elliptic curve additions that reduce to a copy are recorded as
+    /// [`EllipticCurveNoopEvent`]s instead of add events. The provers have no chip for them, so
+    /// [`Executor::execute`] refuses this under [`ExecutorMode::Trace`], and it is only for cost
+    /// accounting through [`ExecutorConfig::EC_EVENT_TRACE_PATH`].
+    ///
+    /// [`EllipticCurveNoopEvent`]: crate::events::EllipticCurveNoopEvent
+    const EMIT_EC_NOOP_EVENTS: bool = false;
+    /// The path of a file that each elliptic curve precompile event is written to as a JSON line
+    /// when it is created, or `None` to not write them. The file is created on the first event and
+    /// writes are buffered. See [`EcEventSink`].
+    const EC_EVENT_TRACE_PATH: Option<&'static str> = None;
+    /// Whether the elliptic curve events are created with a checksum of their contents, for
+    /// detecting corruption when they are persisted. See [`EcEventChecksum`].
+    ///
+    /// [`EcEventChecksum`]: crate::events::EcEventChecksum
+    const EC_EVENT_CHECKSUMS: bool = false;
+    /// Whether elliptic curve double events of short Weierstrass curves record the `Z` coordinate
+    /// of the doubling in Jacobian coordinates, before it is normalized back to affine. See
+    /// [`EllipticCurveDoubleEvent::jacobian_z_words`].
+    ///
+    /// [`EllipticCurveDoubleEvent::jacobian_z_words`]:
+    /// crate::events::EllipticCurveDoubleEvent::jacobian_z_words
+    const RECORD_JACOBIAN_Z: bool = false;
+    /// Whether the memory records of syscall slice accesses are drawn from the executor's
+    /// [`EcRecordPool`], and the vectors of elliptic curve events are returned to it, instead of
+    /// being allocated and dropped for every event.
+    const POOL_EC_RECORDS: bool = false;
+    /// How the elliptic curve syscalls handle a misaligned pointer or an invalid input, which
+    /// their event constructors report as an [`EcSyscallError`]. A failed syscall emits no
+    /// precompile event, so [`Executor::execute`] refuses any policy but
+    /// [`AssertionPolicy::Panic`] under [`ExecutorMode::Trace`].
+    ///
+    /// [`EcSyscallError`]: crate::events::EcSyscallError
+    const ASSERTION_POLICY: AssertionPolicy = AssertionPolicy::Panic;
+    /// The maximum number of elliptic curve precompile events created in a shard, or `None` for no
+    /// limit. Past it, the elliptic curve event constructors fail with a quota error.
+    const MAX_EC_OPS_PER_SHARD: Option<u64> = None;
 }
 
 /// The simple mode of the executor.
@@ -312,10 +375,14 @@ pub struct LocalCounts {
 
     /// The number of instruction decode events that occurred in this shard.
     pub shard_distinct_instructions: HashSet<u32>,
+
+    /// The number of elliptic curve precompile events created in this shard, counted against
+    /// [`ExecutorConfig::MAX_EC_OPS_PER_SHARD`].
+    pub ec_ops: u64,
 }
 
 /// Errors that the [``Executor``] can throw.
-#[derive(Error, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ExecutionError {
     /// The execution failed with an invalid memory access.
     #[error("invalid memory access for opcode {0} and address {1}")]
@@ -360,6 +427,29 @@ pub enum ExecutionError {
     /// Page protect is off, and the instruction is not found.
     #[error("Instruction not found, page protect/ untrusted program set to off")]
     InstructionNotFound(),
+
+    /// Advancing the clock from the given cycle would overflow a `u64`.
+    #[error("clock overflow at cycle {0}")]
+    ClockOverflow(u64),
+
+    /// A new shard was started while the syscall ending at the given cycle was executing.
+    #[error("syscall at clk {0} straddles a shard boundary")]
+    SyscallStraddlesShard(u64),
+
+    /// [`ExecutorConfig::EMIT_EC_NOOP_EVENTS`] is set under [`ExecutorMode::Trace`].
+    #[error(
+        "elliptic curve no-op events cannot be recorded in a trace, which has no chip for them"
+    )]
+    EcNoopEventsInTrace(),
+
+    /// The config skips page protection records for a program that enables untrusted programs.
+    #[error("page protection recording is disabled but the program enables untrusted programs")]
+    PageProtRecordingDisabled(),
+
+    /// [`ExecutorConfig::ASSERTION_POLICY`] is not [`AssertionPolicy::Panic`] under
+    /// [`ExecutorMode::Trace`].
+    #[error("elliptic curve syscall errors must panic in a trace, which has no event for them")]
+    EcSyscallErrorsInTrace(),
 }
 
 impl<'a> Executor<'a> {
@@ -456,11 +546,15 @@ impl<'a> Executor<'a> {
             unconstrained_state: Box::new(ForkState::default()),
             emit_global_memory_events: true,
             report: ExecutionReport::default(),
+            ec_stats: Arc::default(),
+            ec_event_sink: None,
+            ec_record_pool: EcRecordPool::default(),
             local_counts: LocalCounts::default(),
             print_report: false,
             record_estimator: None,
             subproof_verifier: context.subproof_verifier,
             hook_registry,
+            custom_curves: context.custom_curves,
             max_cycles: context.max_cycles,
             deferred_proof_verification: context.deferred_proof_verification.into(),
             memory_checkpoint: Memory::default(),
@@ -2108,10 +2202,14 @@ impl<'a> Executor<'a> {
         }
 
         let mut precompile_rt: SyscallContext<'_, '_, E> = SyscallContext::new(self, external);
+        precompile_rt.syscall_code = syscall;
         let (a, precompile_next_pc, returned_exit_code) = {
             // Executing a syscall optionally returns a value to write to the t0
             // register. If it returns None, we just keep the syscall_id in t0.
             let res = (syscall_impl.handler)(&mut precompile_rt, syscall, b, c);
+            if let Some(err) = precompile_rt.execution_error.take() {
+                return Err(err);
+            }
             let a = if let Some(val) = res { val } else { syscall_id };
 
             (a, precompile_rt.next_pc, precompile_rt.exit_code)
@@ -2153,7 +2251,7 @@ impl<'a> Executor<'a> {
         // Allow the syscall impl to modify state.clk/pc (exit unconstrained does this)
         self.rw_cpu::<E>(t0, a);
         let clk = self.state.clk;
-        self.state.clk += 256;
+        self.state.clk = clk.checked_add(256).ok_or(ExecutionError::ClockOverflow(clk))?;
 
         Ok((a, b, c, clk, precompile_next_pc, syscall, returned_exit_code))
     }
@@ -2362,6 +2460,17 @@ impl<'a> Executor<'a> {
         Ok((std::mem::take(&mut self.record), done))
     }
 
+    /// Returns the vectors of the elliptic curve events of a shard's record to the
+    /// [`EcRecordPool`], once the events are no longer needed, so that the next shards reuse them.
+    /// The precompile events of the record are left empty.
+    pub fn recycle_ec_events(&mut self, record: &mut ExecutionRecord) {
+        for events in record.precompile_events.events.values_mut() {
+            for (_, event) in events.drain(..) {
+                self.ec_record_pool.recycle(event);
+            }
+        }
+    }
+
     /// Execute the program until the shard boundry.
     ///
     /// # Errors
@@ -2520,6 +2629,16 @@ impl<'a> Executor<'a> {
 
     /// Executes up to the shard boundry. Returning whether the program has finished.
     pub fn execute<E: ExecutorConfig>(&mut self) -> Result<bool, ExecutionError> {
+        if E::EMIT_EC_NOOP_EVENTS && E::MODE == ExecutorMode::Trace {
+            return Err(ExecutionError::EcNoopEventsInTrace());
+        }
+        if !E::RECORD_PAGE_PROT && self.program.enable_untrusted_programs {
+            return Err(ExecutionError::PageProtRecordingDisabled());
+        }
+        if E::ASSERTION_POLICY != AssertionPolicy::Panic && E::MODE == ExecutorMode::Trace {
+            return Err(ExecutionError::EcSyscallErrorsInTrace());
+        }
+
         // Get the program.
         let program = self.program.clone();
 
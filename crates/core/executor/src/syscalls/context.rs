@@ -1,14 +1,15 @@
 use std::marker::PhantomData;
 
 use hashbrown::HashMap;
-use sp1_primitives::consts::{PAGE_SIZE, PROT_READ, PROT_WRITE};
+use sp1_curves::{AffinePoint, EllipticCurve};
+use sp1_primitives::consts::{DEFAULT_PAGE_PROT, PAGE_SIZE, PROT_READ, PROT_WRITE};
 
 use crate::{
     events::{
-        MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord, PageProtLocalEvent, PageProtRecord,
-        PrecompileEvent, SyscallEvent,
+        EcEventSink, MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord, PageProtLocalEvent,
+        PageProtRecord, PrecompileEvent, SyscallEvent,
     },
-    ExecutionRecord, Executor, ExecutorConfig, ExecutorMode, Register,
+    ExecutionError, ExecutionRecord, Executor, ExecutorConfig, ExecutorMode, Register,
 };
 
 use super::SyscallCode;
@@ -25,12 +26,19 @@ pub struct SyscallContext<'a, 'b: 'a, E: ExecutorConfig> {
     pub next_pc: u64,
     /// The exit code.
     pub exit_code: u32,
+    /// The code of the syscall being executed, set by the executor before dispatching.
+    pub syscall_code: SyscallCode,
     /// The runtime.
     pub rt: &'a mut Executor<'b>,
     /// The local memory access events for the syscall.
     pub local_memory_access: Option<HashMap<u64, MemoryLocalEvent>>,
     /// The local page protection access events for the syscall.
     pub local_page_prot_access: Option<HashMap<u64, PageProtLocalEvent>>,
+    /// The initial timestamp of the shard the syscall is executed in.
+    pub initial_timestamp: u64,
+    /// An error ending the execution, set by a syscall that cannot complete. The executor returns
+    /// it once the syscall handler does.
+    pub execution_error: Option<ExecutionError>,
     /// Phantom data.
     pub _phantom: PhantomData<E>,
 }
@@ -39,14 +47,18 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
     /// Create a new [`SyscallContext`].
     pub fn new(runtime: &'a mut Executor<'b>, external_flag: bool) -> Self {
         let clk = runtime.state.clk;
+        let initial_timestamp = runtime.state.initial_timestamp;
         Self {
             external_flag,
             clk,
             next_pc: runtime.state.pc.wrapping_add(4),
             exit_code: 0,
+            syscall_code: SyscallCode::default(),
             rt: runtime,
             local_memory_access: external_flag.then_some(HashMap::new()),
             local_page_prot_access: external_flag.then_some(HashMap::new()),
+            initial_timestamp,
+            execution_error: None,
             _phantom: PhantomData,
         }
     }
@@ -64,8 +76,19 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
         syscall_event: SyscallEvent,
         event: PrecompileEvent,
     ) {
+        if let Some(path) = E::EC_EVENT_TRACE_PATH {
+            let sink = self.rt.ec_event_sink.get_or_insert_with(|| {
+                EcEventSink::create(path).unwrap_or_else(|err| {
+                    panic!("failed to create the EC event trace {path}: {err}")
+                })
+            });
+            sink.write_event(syscall_code, &event)
+                .unwrap_or_else(|err| panic!("failed to write the EC event trace: {err}"));
+        }
         if E::MODE == ExecutorMode::Trace {
             self.record_mut().precompile_events.add_event(syscall_code, syscall_event, event);
+        } else if E::POOL_EC_RECORDS {
+            self.rt.ec_record_pool.recycle(event);
         }
     }
 
@@ -75,7 +98,7 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
     pub fn mr(&mut self, addr: u64) -> (MemoryReadRecord, u64) {
         let mut record =
             self.rt.mr::<E>(addr, self.external_flag, self.clk, self.local_memory_access.as_mut());
-        if self.rt.program.enable_untrusted_programs {
+        if self.records_page_prot() {
             let page_prot_record = self.rt.page_prot_access::<E>(
                 addr / PAGE_SIZE as u64,
                 PROT_READ,
@@ -89,6 +112,40 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
         (record, record.value)
     }
 
+    /// Whether [`Self::mr`], [`Self::mw`], [`Self::mr_slice`] and [`Self::mw_slice`] produce page
+    /// protection records.
+    ///
+    /// Only programs that enable untrusted programs have them. Configurations with
+    /// [`ExecutorConfig::RECORD_PAGE_PROT`] disabled also skip them, which [`Executor::execute`]
+    /// refuses for such programs, so this only differs from `enable_untrusted_programs` in a
+    /// context used outside of it.
+    fn records_page_prot(&self) -> bool {
+        E::RECORD_PAGE_PROT && self.rt.program.enable_untrusted_programs
+    }
+
+    /// Whether the pages holding `len` words at `addr` allow an access with `page_prot_bitmap`,
+    /// checked the same way as the accesses of [`Self::mr_slice`] and [`Self::mw_slice`].
+    ///
+    /// This does not touch memory, so syscalls can reject a protected pointer before any record is
+    /// made. Pages are unrestricted unless the program enables untrusted programs.
+    #[must_use]
+    pub fn page_prot_allows(&self, addr: u64, len: usize, page_prot_bitmap: u8) -> bool {
+        if !self.rt.program.enable_untrusted_programs || len == 0 {
+            return true;
+        }
+        let first_page = addr / PAGE_SIZE as u64;
+        let last_page = (addr + len as u64 * 8 - 1) / PAGE_SIZE as u64;
+        (first_page..=last_page).all(|page_idx| {
+            let page_prot = self
+                .rt
+                .state
+                .page_prots
+                .get(&page_idx)
+                .map_or(DEFAULT_PAGE_PROT, |record| record.page_prot);
+            page_prot & page_prot_bitmap != 0
+        })
+    }
+
     /// Read a slice of words from memory.
     ///
     /// `addr` must be a pointer to main memory, not a register.
@@ -99,9 +156,13 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
         addr: u64,
         len: usize,
     ) -> (Vec<MemoryReadRecord>, Vec<u64>, Vec<PageProtRecord>) {
-        let mut records = Vec::with_capacity(len);
-        let mut values = Vec::with_capacity(len);
+        let (mut records, mut values) = if E::POOL_EC_RECORDS {
+            (self.rt.ec_record_pool.take_reads(len), self.rt.ec_record_pool.take_words(len))
+        } else {
+            (Vec::with_capacity(len), Vec::with_capacity(len))
+        };
         let mut page_accesses = HashMap::new();
+        let records_page_prot = self.records_page_prot();
 
         for i in 0..len {
             let current_addr = addr + i as u64 * 8;
@@ -114,13 +175,15 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
             records.push(record);
             values.push(record.value);
 
-            let page_idx = current_addr / (PAGE_SIZE as u64);
-            page_accesses.entry(page_idx).or_insert(PROT_READ);
+            if records_page_prot {
+                let page_idx = current_addr / (PAGE_SIZE as u64);
+                page_accesses.entry(page_idx).or_insert(PROT_READ);
+            }
         }
         // Generate the page prot records - one per unique page
         let mut page_prot_records = Vec::with_capacity(page_accesses.len());
 
-        if self.rt.program.enable_untrusted_programs {
+        if records_page_prot {
             let mut page_accesses: Vec<_> = page_accesses.iter().collect();
             page_accesses.sort_by_key(|(page_idx, _)| *page_idx);
 
@@ -147,6 +210,22 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
         (records, values, page_prot_records)
     }
 
+    /// Read an elliptic curve point from memory.
+    ///
+    /// `ptr` must be a pointer to main memory, not a register. The point spans
+    /// [`AffinePoint::num_memory_words`] words, with the coordinates in the curve's
+    /// `MEMORY_ENDIANNESS` and `COORD_STRIDE_WORDS` words apart.
+    ///
+    /// Returns a tuple of (point, memory records, page protection records).
+    pub fn read_affine_point<C: EllipticCurve>(
+        &mut self,
+        ptr: u64,
+    ) -> (AffinePoint<C>, Vec<MemoryReadRecord>, Vec<PageProtRecord>) {
+        let (records, words, page_prot_records) =
+            self.mr_slice(ptr, AffinePoint::<C>::num_memory_words());
+        (AffinePoint::from_memory_words(&words), records, page_prot_records)
+    }
+
     /// Write a word to memory.
     ///
     /// `addr` must be a pointer to main memory, not a register.
@@ -161,7 +240,7 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
             self.clk,
             self.local_memory_access.as_mut(),
         );
-        if self.rt.program.enable_untrusted_programs {
+        if self.records_page_prot() {
             let page_prot_bitmap =
                 if is_read_and_write { PROT_READ | PROT_WRITE } else { PROT_WRITE };
 
@@ -187,8 +266,13 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
         values: &[u64],
         is_read_and_write: bool,
     ) -> (Vec<MemoryWriteRecord>, Vec<PageProtRecord>) {
-        let mut records = Vec::with_capacity(values.len());
+        let mut records = if E::POOL_EC_RECORDS {
+            self.rt.ec_record_pool.take_writes(values.len())
+        } else {
+            Vec::with_capacity(values.len())
+        };
         let mut page_accesses = HashMap::new();
+        let records_page_prot = self.records_page_prot();
 
         for i in 0..values.len() {
             let current_addr = addr + i as u64 * 8;
@@ -201,14 +285,16 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
             );
             records.push(record);
 
-            let page_idx = current_addr / (PAGE_SIZE as u64);
-            let page_prot = if is_read_and_write { PROT_READ | PROT_WRITE } else { PROT_WRITE };
-            page_accesses.entry(page_idx).or_insert(page_prot);
+            if records_page_prot {
+                let page_idx = current_addr / (PAGE_SIZE as u64);
+                let page_prot = if is_read_and_write { PROT_READ | PROT_WRITE } else { PROT_WRITE };
+                page_accesses.entry(page_idx).or_insert(page_prot);
+            }
         }
 
         // Generate the page prot records - one per unique page
         let mut page_prot_records = Vec::with_capacity(page_accesses.len());
-        if self.rt.program.enable_untrusted_programs {
+        if records_page_prot {
             let mut page_accesses: Vec<_> = page_accesses.iter().collect();
             page_accesses.sort_by_key(|(page_idx, _)| *page_idx);
 
@@ -235,6 +321,25 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
         (records, page_prot_records)
     }
 
+    /// Write an elliptic curve point to memory, with the coordinates in the curve's
+    /// `MEMORY_ENDIANNESS` and `COORD_STRIDE_WORDS` words apart.
+    ///
+    /// `is_read_and_write` is forwarded to [`Self::mw_slice`]. Pass `true` when the point
+    /// overwrites an input that the syscall read from the same location, as the in-place add and
+    /// double syscalls do, so that the page must be both readable and writable. Pass `false` when
+    /// the location is only written, such as a dedicated result buffer or the `y` half of a
+    /// decompression slice.
+    ///
+    /// Returns a tuple of (memory records, page protection records).
+    pub fn write_affine_point<C: EllipticCurve>(
+        &mut self,
+        ptr: u64,
+        point: &AffinePoint<C>,
+        is_read_and_write: bool,
+    ) -> (Vec<MemoryWriteRecord>, Vec<PageProtRecord>) {
+        self.mw_slice(ptr, &point.to_memory_words(), is_read_and_write)
+    }
+
     /// Get the page protection records for a range of addresses.
     ///
     /// This is used to get the page protection records for a range of addresses that are not
@@ -302,11 +407,25 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
 
     /// Postprocess the syscall.  Specifically will process the syscall's memory and page prot local
     /// events.
+    ///
+    /// A syscall never straddles a shard boundary: shards are only finished between cycles, so all
+    /// of the local events returned belong to the shard whose record receives the syscall's event.
+    /// The local events of the syscall are kept apart from the executor's until now, and the
+    /// executor's pending events for the same addresses are moved to the record, so that each
+    /// address has exactly one local event per shard and per syscall.
+    ///
+    /// If a new shard was started while the syscall was executing anyway, no local event is
+    /// returned and [`ExecutionError::SyscallStraddlesShard`] is set as the
+    /// [`Self::execution_error`], ending the execution once the syscall returns.
     pub fn postprocess(&mut self) -> (Vec<MemoryLocalEvent>, Vec<PageProtLocalEvent>) {
         let mut syscall_local_mem_events = Vec::new();
         let mut syscall_local_page_prot_events = Vec::new();
 
         if E::MODE == ExecutorMode::Trace && !E::UNCONSTRAINED {
+            if self.rt.state.initial_timestamp != self.initial_timestamp {
+                self.execution_error.get_or_insert(ExecutionError::SyscallStraddlesShard(self.clk));
+                return (syscall_local_mem_events, syscall_local_page_prot_events);
+            }
             // Will need to transfer the existing memory local events in the executor to it's
             // record, and return all the syscall memory local events.  This is similar
             // to what `bump_record` does.
@@ -363,13 +482,53 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
     /// Get a slice of double words, but doesn't use a memory record.
     #[must_use]
     pub fn slice_unsafe(&mut self, addr: u64, len: usize) -> Vec<u64> {
-        let mut values = Vec::new();
+        let mut values =
+            if E::POOL_EC_RECORDS { self.rt.ec_record_pool.take_words(len) } else { Vec::new() };
         for i in 0..len {
             values.push(self.rt.double_word::<E>(addr + i as u64 * 8));
         }
         values
     }
 
+    /// Get a lazy view of a slice of double words, but doesn't use a memory record.
+    ///
+    /// This reads the same words as [`Self::slice_unsafe`] without collecting them into a vector,
+    /// for callers that only need the words transiently. Each word is looked up as the view is
+    /// consumed. Nothing in memory changes while a syscall holds the view.
+    ///
+    /// It cannot be a `&[u64]`: each word of the page table is a [`MemoryRecord`] stored next to
+    /// its timestamp, a word that was never written has no entry and reads as zero, and in
+    /// checkpoint and unconstrained mode every read also records the word in the memory checkpoint,
+    /// which is why the view borrows the context mutably.
+    ///
+    /// [`MemoryRecord`]: crate::events::MemoryRecord
+    pub fn slice_view(&mut self, addr: u64, len: usize) -> impl Iterator<Item = u64> + '_ {
+        (0..len).map(move |i| self.rt.double_word::<E>(addr + i as u64 * 8))
+    }
+
+    /// Advance the clock between two accesses of the syscall that may alias.
+    ///
+    /// Memory and page protection records need strictly increasing timestamps, so an access that
+    /// may touch the same word or page as an earlier access of the same syscall, such as a result
+    /// written over an input, must happen at a later clock cycle.
+    ///
+    /// Returns an [`ExecutionError::ClockOverflow`] error, leaving the clock unchanged, if it is at
+    /// `u64::MAX`.
+    pub fn advance_for_aliasing(&mut self) -> Result<(), ExecutionError> {
+        self.clk_checked_add(1)
+    }
+
+    /// Advance the clock by `increment`, failing instead of wrapping around if the clock would
+    /// overflow a `u64`. The clock is unchanged on failure.
+    ///
+    /// Records are ordered by their timestamps, so a clock that wraps around would silently
+    /// corrupt the order of every later access.
+    pub fn clk_checked_add(&mut self, increment: u64) -> Result<(), ExecutionError> {
+        self.clk =
+            self.clk.checked_add(increment).ok_or(ExecutionError::ClockOverflow(self.clk))?;
+        Ok(())
+    }
+
     /// Set the next program counter.
     pub fn set_next_pc(&mut self, next_pc: u64) {
         self.next_pc = next_pc;
@@ -380,3 +539,90 @@ impl<'a, 'b, E: ExecutorConfig> SyscallContext<'a, 'b, E> {
         self.exit_code = exit_code;
     }
 }
+
+/// The memory interface of a syscall, as used by the precompile event constructors.
+///
+/// [`SyscallContext`] implements it over the executor's memory. Keeping the constructors generic
+/// over it lets their memory accesses and arithmetic be tested without a full [`Executor`].
+pub trait SyscallMemory {
+    /// The current clock cycle.
+    fn clk(&self) -> u64;
+
+    /// The syscall id of the syscall being executed.
+    fn syscall_id(&self) -> u32;
+
+    /// See [`SyscallContext::page_prot_allows`].
+    fn page_prot_allows(&self, addr: u64, len: usize, page_prot_bitmap: u8) -> bool;
+
+    /// See [`SyscallContext::slice_unsafe`].
+    fn slice_unsafe(&mut self, addr: u64, len: usize) -> Vec<u64>;
+
+    /// See [`SyscallContext::mr_slice`].
+    fn mr_slice(
+        &mut self,
+        addr: u64,
+        len: usize,
+    ) -> (Vec<MemoryReadRecord>, Vec<u64>, Vec<PageProtRecord>);
+
+    /// See [`SyscallContext::mw_slice`].
+    fn mw_slice(
+        &mut self,
+        addr: u64,
+        values: &[u64],
+        is_read_and_write: bool,
+    ) -> (Vec<MemoryWriteRecord>, Vec<PageProtRecord>);
+
+    /// See [`SyscallContext::clk_checked_add`].
+    fn clk_checked_add(&mut self, increment: u64) -> Result<(), ExecutionError>;
+
+    /// See [`SyscallContext::advance_for_aliasing`].
+    fn advance_for_aliasing(&mut self) -> Result<(), ExecutionError> {
+        self.clk_checked_add(1)
+    }
+
+    /// See [`SyscallContext::postprocess`].
+    fn postprocess(&mut self) -> (Vec<MemoryLocalEvent>, Vec<PageProtLocalEvent>);
+}
+
+impl<E: ExecutorConfig> SyscallMemory for SyscallContext<'_, '_, E> {
+    fn clk(&self) -> u64 {
+        self.clk
+    }
+
+    fn syscall_id(&self) -> u32 {
+        self.syscall_code.syscall_id()
+    }
+
+    fn page_prot_allows(&self, addr: u64, len: usize, page_prot_bitmap: u8) -> bool {
+        SyscallContext::page_prot_allows(self, addr, len, page_prot_bitmap)
+    }
+
+    fn slice_unsafe(&mut self, addr: u64, len: usize) -> Vec<u64> {
+        SyscallContext::slice_unsafe(self, addr, len)
+    }
+
+    fn mr_slice(
+        &mut self,
+        addr: u64,
+        len: usize,
+    ) -> (Vec<MemoryReadRecord>, Vec<u64>, Vec<PageProtRecord>) {
+        SyscallContext::mr_slice(self, addr, len)
+    }
+
+    fn mw_slice(
+        &mut self,
+        addr: u64,
+        values: &[u64],
+        is_read_and_write: bool,
+    ) -> (Vec<MemoryWriteRecord>, Vec<PageProtRecord>) {
+        SyscallContext::mw_slice(self, addr, values, is_read_and_write)
+    }
+
+    fn clk_checked_add(&mut self, increment: u64) -> Result<(), ExecutionError> {
+        SyscallContext::clk_checked_add(self, increment)
+    }
+
+    fn postprocess(&mut self) -> (Vec<MemoryLocalEvent>, Vec<PageProtLocalEvent>) {
+        SyscallContext::postprocess(self)
+    }
+}
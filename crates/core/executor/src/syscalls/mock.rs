@@ -0,0 +1,162 @@
+//! A [`SyscallMemory`] backed by a plain map, for testing syscall logic without an [`Executor`].
+//!
+//! [`Executor`]: crate::Executor
+
+use hashbrown::HashMap;
+use sp1_primitives::consts::{DEFAULT_PAGE_PROT, PAGE_SIZE, PROT_READ, PROT_WRITE};
+
+use super::SyscallMemory;
+use crate::{
+    events::{
+        MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord, PageProtLocalEvent, PageProtRecord,
+    },
+    ExecutionError,
+};
+
+/// A mock syscall context over a sparse word-addressed memory.
+///
+/// Unset words read as zero and unset pages have [`DEFAULT_PAGE_PROT`]. Page protections are only
+/// enforced and recorded when `enable_page_prot` is set, mirroring untrusted programs in the real
+/// executor. There are no shards, so [`SyscallMemory::postprocess`] returns no local events.
+#[derive(Debug, Default)]
+pub(crate) struct MockSyscallContext {
+    /// The current clock cycle.
+    pub clk: u64,
+    /// The syscall id reported to the event constructors.
+    pub syscall_id: u32,
+    /// The memory, keyed by the address of each word.
+    pub memory: HashMap<u64, u64>,
+    /// The page protections, keyed by page index.
+    pub page_prots: HashMap<u64, u8>,
+    /// Whether page protections are enforced and recorded.
+    pub enable_page_prot: bool,
+    /// The timestamp of the last access to each word.
+    timestamps: HashMap<u64, u64>,
+    /// The timestamp of the last access to each page.
+    page_timestamps: HashMap<u64, u64>,
+}
+
+impl MockSyscallContext {
+    /// Creates a context at `clk` with empty memory.
+    pub fn new(clk: u64, syscall_id: u32) -> Self {
+        Self { clk, syscall_id, ..Self::default() }
+    }
+
+    /// Writes `words` starting at `addr`, without making any record.
+    pub fn write_words(&mut self, addr: u64, words: &[u64]) {
+        for (i, &word) in words.iter().enumerate() {
+            self.memory.insert(addr + i as u64 * 8, word);
+        }
+    }
+
+    /// Reads `len` words starting at `addr`, without making any record.
+    pub fn read_words(&self, addr: u64, len: usize) -> Vec<u64> {
+        (0..len).map(|i| self.memory.get(&(addr + i as u64 * 8)).copied().unwrap_or(0)).collect()
+    }
+
+    fn page_prot(&self, page_idx: u64) -> u8 {
+        self.page_prots.get(&page_idx).copied().unwrap_or(DEFAULT_PAGE_PROT)
+    }
+
+    fn access_word(&mut self, addr: u64) -> u64 {
+        self.timestamps.insert(addr, self.clk).unwrap_or(0)
+    }
+
+    /// Makes one page protection record per page of the `len` words at `addr`.
+    fn access_pages(&mut self, addr: u64, len: usize, page_prot_bitmap: u8) -> Vec<PageProtRecord> {
+        if !self.enable_page_prot || len == 0 {
+            return Vec::new();
+        }
+        let first_page = addr / PAGE_SIZE as u64;
+        let last_page = (addr + len as u64 * 8 - 1) / PAGE_SIZE as u64;
+        (first_page..=last_page)
+            .map(|page_idx| {
+                let page_prot = self.page_prot(page_idx);
+                assert!(
+                    page_prot & page_prot_bitmap != 0,
+                    "page {page_idx} does not allow access {page_prot_bitmap}"
+                );
+                let timestamp = self.page_timestamps.insert(page_idx, self.clk).unwrap_or(0);
+                PageProtRecord { external_flag: false, timestamp, page_prot }
+            })
+            .collect()
+    }
+}
+
+impl SyscallMemory for MockSyscallContext {
+    fn clk(&self) -> u64 {
+        self.clk
+    }
+
+    fn syscall_id(&self) -> u32 {
+        self.syscall_id
+    }
+
+    fn page_prot_allows(&self, addr: u64, len: usize, page_prot_bitmap: u8) -> bool {
+        if !self.enable_page_prot || len == 0 {
+            return true;
+        }
+        let first_page = addr / PAGE_SIZE as u64;
+        let last_page = (addr + len as u64 * 8 - 1) / PAGE_SIZE as u64;
+        (first_page..=last_page).all(|page_idx| self.page_prot(page_idx) & page_prot_bitmap != 0)
+    }
+
+    fn slice_unsafe(&mut self, addr: u64, len: usize) -> Vec<u64> {
+        self.read_words(addr, len)
+    }
+
+    fn mr_slice(
+        &mut self,
+        addr: u64,
+        len: usize,
+    ) -> (Vec<MemoryReadRecord>, Vec<u64>, Vec<PageProtRecord>) {
+        let values = self.read_words(addr, len);
+        let records = values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| MemoryReadRecord {
+                value,
+                timestamp: self.clk,
+                prev_timestamp: self.access_word(addr + i as u64 * 8),
+                prev_page_prot_record: None,
+            })
+            .collect();
+        let page_prot_records = self.access_pages(addr, len, PROT_READ);
+        (records, values, page_prot_records)
+    }
+
+    fn mw_slice(
+        &mut self,
+        addr: u64,
+        values: &[u64],
+        is_read_and_write: bool,
+    ) -> (Vec<MemoryWriteRecord>, Vec<PageProtRecord>) {
+        let records = values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| {
+                let word_addr = addr + i as u64 * 8;
+                MemoryWriteRecord {
+                    value,
+                    timestamp: self.clk,
+                    prev_value: self.memory.insert(word_addr, value).unwrap_or(0),
+                    prev_timestamp: self.access_word(word_addr),
+                    prev_page_prot_record: None,
+                }
+            })
+            .collect();
+        let page_prot = if is_read_and_write { PROT_READ | PROT_WRITE } else { PROT_WRITE };
+        let page_prot_records = self.access_pages(addr, values.len(), page_prot);
+        (records, page_prot_records)
+    }
+
+    fn clk_checked_add(&mut self, increment: u64) -> Result<(), ExecutionError> {
+        self.clk =
+            self.clk.checked_add(increment).ok_or(ExecutionError::ClockOverflow(self.clk))?;
+        Ok(())
+    }
+
+    fn postprocess(&mut self) -> (Vec<MemoryLocalEvent>, Vec<PageProtLocalEvent>) {
+        (Vec::new(), Vec::new())
+    }
+}
@@ -6,6 +6,8 @@ mod context;
 mod deferred;
 mod halt;
 mod hint;
+#[cfg(test)]
+pub(crate) mod mock;
 mod mprotect;
 mod precompiles;
 mod unconstrained;
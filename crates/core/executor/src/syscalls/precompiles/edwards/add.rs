@@ -1,7 +1,9 @@
 use sp1_curves::{edwards::EdwardsParameters, EllipticCurve};
 
 use crate::{
-    events::{create_ec_add_event, PrecompileEvent},
+    events::{
+        handle_ec_syscall_error, try_create_ec_add_event, EllipticCurveNoopEvent, PrecompileEvent,
+    },
     syscalls::{SyscallCode, SyscallContext},
     ExecutorConfig,
 };
@@ -15,9 +17,27 @@ pub(crate) fn edwards_add_assign_syscall<
     arg1: u64,
     arg2: u64,
 ) -> Option<u64> {
-    let event = create_ec_add_event::<E, Ex>(rt, arg1, arg2);
+    let event = match try_create_ec_add_event::<E, Ex>(rt, arg1, arg2) {
+        Ok(event) => event,
+        Err(err) => return handle_ec_syscall_error(rt, &err),
+    };
     let syscall_event =
         rt.rt.syscall_event(event.clk, syscall_code, arg1, arg2, false, rt.next_pc, rt.exit_code);
+    let event = if Ex::EMIT_EC_NOOP_EVENTS {
+        match EllipticCurveNoopEvent::try_from(event) {
+            Ok(event) => {
+                rt.add_precompile_event(
+                    syscall_code,
+                    syscall_event,
+                    PrecompileEvent::EcNoop(event),
+                );
+                return None;
+            }
+            Err(event) => event,
+        }
+    } else {
+        event
+    };
     rt.add_precompile_event(syscall_code, syscall_event, PrecompileEvent::EdAdd(event));
     None
 }
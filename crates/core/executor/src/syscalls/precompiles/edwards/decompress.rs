@@ -49,7 +49,10 @@ pub fn edwards_decompress_syscall<Ex: ExecutorConfig>(
     decompressed_x_bytes.resize(32, 0u8);
     let decompressed_x_words: [u64; WORDS_FIELD_ELEMENT] = bytes_to_words_le(&decompressed_x_bytes);
 
-    rt.clk += 1;
+    if let Err(err) = rt.advance_for_aliasing() {
+        rt.execution_error = Some(err);
+        return None;
+    }
 
     // Write decompressed X into slice
     let (x_memory_records_vec, write_page_prot_records) =
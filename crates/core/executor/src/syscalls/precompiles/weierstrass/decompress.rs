@@ -1,7 +1,7 @@
 use sp1_curves::{CurveType, EllipticCurve};
 
 use crate::{
-    events::{create_ec_decompress_event, PrecompileEvent},
+    events::{handle_ec_syscall_error, try_create_ec_decompress_event, PrecompileEvent},
     syscalls::{SyscallCode, SyscallContext},
     ExecutorConfig,
 };
@@ -13,7 +13,10 @@ pub(crate) fn weierstrass_decompress_syscall<E: EllipticCurve, Ex: ExecutorConfi
     slice_ptr: u64,
     sign_bit: u64,
 ) -> Option<u64> {
-    let event = create_ec_decompress_event::<E, Ex>(ctx, slice_ptr, sign_bit);
+    let event = match try_create_ec_decompress_event::<E, Ex>(ctx, slice_ptr, sign_bit) {
+        Ok(event) => event,
+        Err(err) => return handle_ec_syscall_error(ctx, &err),
+    };
     let syscall_event = ctx.rt.syscall_event(
         event.clk,
         syscall_code,
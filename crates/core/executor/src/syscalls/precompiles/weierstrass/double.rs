@@ -1,7 +1,7 @@
 use sp1_curves::{CurveType, EllipticCurve};
 
 use crate::{
-    events::{create_ec_double_event, PrecompileEvent},
+    events::{handle_ec_syscall_error, try_create_ec_double_assign_event, PrecompileEvent},
     syscalls::{SyscallCode, SyscallContext},
     ExecutorConfig,
 };
@@ -13,7 +13,10 @@ pub(crate) fn weierstrass_double_assign_syscall<E: EllipticCurve, Ex: ExecutorCo
     arg1: u64,
     arg2: u64,
 ) -> Option<u64> {
-    let event = create_ec_double_event::<E, Ex>(ctx, arg1, arg2);
+    let event = match try_create_ec_double_assign_event::<E, Ex>(ctx, arg1, arg2) {
+        Ok(event) => event,
+        Err(err) => return handle_ec_syscall_error(ctx, &err),
+    };
     let syscall_event = ctx.rt.syscall_event(
         event.clk,
         syscall_code,
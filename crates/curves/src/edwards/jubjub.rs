@@ -0,0 +1,194 @@
+use generic_array::GenericArray;
+use num::{BigUint, Num, One, Zero};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use crate::{
+    edwards::{EdwardsCurve, EdwardsParameters},
+    params::{FieldParameters, NumLimbs},
+    AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
+};
+
+/// The Jubjub curve `-x^2 + y^2 = 1 + dx^2y^2` with `d = -(10240/10241)`, defined over the scalar
+/// field of BLS12-381.
+pub type Jubjub = EdwardsCurve<JubjubParameters>;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JubjubParameters;
+
+/// The base field of Jubjub, which is the scalar field of BLS12-381.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JubjubBaseField;
+
+impl FieldParameters for JubjubBaseField {
+    const MODULUS: &'static [u8] = &[
+        1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8, 216,
+        57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+    ];
+
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(Self::MODULUS)
+    }
+}
+
+impl NumLimbs for JubjubBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for JubjubParameters {
+    type BaseField = JubjubBaseField;
+    const CURVE_TYPE: CurveType = CurveType::Jubjub;
+}
+
+impl EdwardsParameters for JubjubParameters {
+    const D: GenericArray<u8, U32> = GenericArray::from_array([
+        177, 62, 52, 214, 214, 95, 6, 1, 38, 157, 87, 55, 109, 127, 45, 41, 212, 127, 189, 230, 7,
+        146, 253, 245, 72, 43, 250, 75, 231, 24, 147, 42,
+    ]);
+
+    fn prime_group_order() -> BigUint {
+        BigUint::from_str_radix(
+            "0e7db4ea6533afa906673b0101343b00a6682093ccc81082d0970e5ed6f72cb7",
+            16,
+        )
+        .unwrap()
+    }
+
+    /// The generator of the prime order subgroup used by the `jubjub` crate.
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str_radix(
+            "0926d4f32059c712d418a7ff26753b6ad5b9a7d3ef8e282747bf46920a95a753",
+            16,
+        )
+        .unwrap();
+        let y = BigUint::from_str_radix(
+            "57a1019e6de9b67553bb37d0c21cfd056d65674dcedbddbc305632adaaf2b530",
+            16,
+        )
+        .unwrap();
+        (x, y)
+    }
+}
+
+/// Computes the square root of a number in the base field of Jubjub, returning `None` for
+/// non-residues.
+///
+/// The modulus is `1 mod 4`, so the root is found with Tonelli-Shanks. Like [`ed25519_sqrt`], the
+/// result is always the root whose least significant bit is 0.
+///
+/// [`ed25519_sqrt`]: crate::edwards::ed25519::ed25519_sqrt
+pub fn jubjub_sqrt(a: &BigUint) -> Option<BigUint> {
+    let modulus = JubjubBaseField::modulus();
+    let a = a % &modulus;
+    if a.is_zero() {
+        return Some(a);
+    }
+
+    let one = BigUint::one();
+    let minus_one = &modulus - 1u32;
+    // Euler's criterion.
+    if a.modpow(&(&minus_one >> 1), &modulus) != one {
+        return None;
+    }
+
+    // Write `modulus - 1 = 2^s * t` with `t` odd. 7 is a quadratic non-residue.
+    let s = minus_one.trailing_zeros().unwrap();
+    let t = &minus_one >> s;
+    let mut m = s;
+    let mut c = BigUint::from(7u32).modpow(&t, &modulus);
+    let mut r = a.modpow(&((&t + 1u32) >> 1), &modulus);
+    let mut u = a.modpow(&t, &modulus);
+    while u != one {
+        // Find the least `i` such that `u^(2^i) = 1`.
+        let mut i = 0;
+        let mut u_pow = u.clone();
+        while u_pow != one {
+            u_pow = (&u_pow * &u_pow) % &modulus;
+            i += 1;
+        }
+        let b = c.modpow(&(BigUint::one() << (m - i - 1)), &modulus);
+        r = (r * &b) % &modulus;
+        c = (&b * &b) % &modulus;
+        u = (u * &c) % &modulus;
+        m = i;
+    }
+
+    if r.bit(0) {
+        r = &modulus - r;
+    }
+    Some(r)
+}
+
+/// Decompresses a Jubjub point from its big-endian `y` coordinate and the parity of `x`.
+///
+/// This is the twisted Edwards convention: `x` is recovered from `y` as the square root of
+/// `(y^2 - 1) / (dy^2 + 1)`, negated if its parity does not match `sign_bit`. Returns `None` if
+/// there is no such `x`.
+pub fn jubjub_decompress<E: EllipticCurve>(
+    bytes_be: &[u8],
+    sign_bit: u32,
+) -> Option<AffinePoint<E>> {
+    let modulus = JubjubBaseField::modulus();
+    let y = BigUint::from_bytes_be(bytes_be) % &modulus;
+    let yy = (&y * &y) % &modulus;
+    let u = (&yy + &modulus - 1u32) % &modulus;
+    let v = (&yy * JubjubParameters::d_biguint() + 1u32) % &modulus;
+    if v.is_zero() {
+        return None;
+    }
+
+    let v_inv = v.modpow(&(&modulus - 2u32), &modulus);
+    let mut x = jubjub_sqrt(&((u * v_inv) % &modulus))?;
+    if x.bit(0) != (sign_bit == 1) {
+        x = (&modulus - &x) % &modulus;
+    }
+
+    Some(AffinePoint::new(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jubjub_generator() {
+        let generator = Jubjub::ec_generator();
+        assert!(Jubjub::ec_is_on_curve(&generator));
+
+        let d = JubjubParameters::d_biguint();
+        let p = JubjubBaseField::modulus();
+        assert_eq!((d * 10241u32 + 10240u32) % &p, BigUint::zero());
+
+        let order = JubjubParameters::prime_group_order();
+        assert_eq!(&generator * &order, Jubjub::neutral());
+    }
+
+    #[test]
+    fn test_jubjub_decompress() {
+        let mut point = Jubjub::ec_generator();
+        for _ in 0..10 {
+            let sign_bit = u32::from(point.x.bit(0));
+            let decompressed = jubjub_decompress::<Jubjub>(&point.y.to_bytes_be(), sign_bit);
+            assert_eq!(decompressed, Some(point.clone()));
+            point = Jubjub::ec_double(&point);
+        }
+    }
+
+    #[test]
+    fn test_jubjub_sqrt() {
+        let p = JubjubBaseField::modulus();
+        for a in 1u32..50 {
+            let a = BigUint::from(a);
+            match jubjub_sqrt(&a) {
+                Some(root) => {
+                    assert!(!root.bit(0));
+                    assert_eq!((&root * &root) % &p, a);
+                }
+                None => assert_eq!(a.modpow(&((&p - 1u32) >> 1), &p), &p - 1u32),
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod ed25519;
+pub mod jubjub;
 
 use generic_array::GenericArray;
 use num::{BigUint, Zero};
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use super::CurveType;
 use crate::{
     params::{FieldParameters, NumLimbs},
-    AffinePoint, EllipticCurve, EllipticCurveParameters,
+    AffinePoint, EllipticCurve, EllipticCurveParameters, IdentityEncoding,
 };
 
 use crate::{edwards::ed25519::Ed25519BaseField, params::NumWords};
@@ -84,6 +85,8 @@ impl<E: EdwardsParameters> EdwardsCurve<E> {
 }
 
 impl<E: EdwardsParameters> EllipticCurve for EdwardsCurve<E> {
+    const IDENTITY_ENCODING: IdentityEncoding = IdentityEncoding::Neutral;
+
     fn ec_add(p: &AffinePoint<Self>, q: &AffinePoint<Self>) -> AffinePoint<Self> {
         p.ed_add(q)
     }
@@ -105,6 +108,10 @@ impl<E: EdwardsParameters> EllipticCurve for EdwardsCurve<E> {
         let modulus = E::BaseField::modulus();
         AffinePoint::new(&modulus - &p.x, p.y.clone())
     }
+
+    fn ec_is_on_curve(p: &AffinePoint<Self>) -> bool {
+        p.ed_is_on_curve()
+    }
 }
 
 impl<E: EdwardsParameters> AffinePoint<EdwardsCurve<E>> {
@@ -131,6 +138,19 @@ impl<E: EdwardsParameters> AffinePoint<EdwardsCurve<E>> {
     pub(crate) fn ed_double(&self) -> AffinePoint<EdwardsCurve<E>> {
         self.ed_add(self)
     }
+
+    /// Returns whether the point has reduced coordinates satisfying `-x^2 + y^2 = 1 + dx^2y^2`.
+    pub fn ed_is_on_curve(&self) -> bool {
+        let p = <E as EllipticCurveParameters>::BaseField::modulus();
+        if self.x >= p || self.y >= p {
+            return false;
+        }
+        let x2 = (&self.x * &self.x) % &p;
+        let y2 = (&self.y * &self.y) % &p;
+        let lhs = (&y2 + &p - &x2) % &p;
+        let rhs = (1u32 + E::d_biguint() * &x2 * &y2) % &p;
+        lhs == rhs
+    }
 }
 
 #[cfg(test)]
@@ -142,6 +162,17 @@ mod tests {
     use super::*;
     use crate::edwards::ed25519::{Ed25519, Ed25519Parameters};
 
+    #[test]
+    fn test_ed_is_on_curve() {
+        let base = Ed25519::ec_generator();
+        assert!(Ed25519::ec_is_on_curve(&base));
+        assert!(Ed25519::ec_is_on_curve(&Ed25519::neutral()));
+        assert!(Ed25519::ec_is_on_curve(&base.ed_double()));
+
+        let off_curve = AffinePoint::<Ed25519>::new(base.x.clone(), &base.y + 1u32);
+        assert!(!Ed25519::ec_is_on_curve(&off_curve));
+    }
+
     #[test]
     fn test_bigint_ed_add() {
         type E = Ed25519;
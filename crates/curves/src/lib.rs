@@ -1,6 +1,7 @@
 pub mod edwards;
 pub mod params;
 // pub mod polynomial;
+pub mod registry;
 pub mod scalar_mul;
 pub mod uint256;
 pub mod utils;
@@ -64,13 +65,19 @@ pub const COMPRESSED_POINT_BYTES: usize = 32;
 /// words needed to represent a field element as a point consists of the x and y coordinates.
 pub const NUM_WORDS_EC_POINT: usize = 2 * NUM_WORDS_FIELD_ELEMENT;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CurveType {
     Secp256k1,
     Secp256r1,
     Bn254,
     Ed25519,
     Bls12381,
+    Jubjub,
+    Pallas,
+    Vesta,
+    /// A curve implemented outside of this crate, registered under this id in a
+    /// [`registry::CustomCurveRegistry`].
+    Custom(u32),
 }
 
 impl Display for CurveType {
@@ -81,6 +88,119 @@ impl Display for CurveType {
             CurveType::Bn254 => write!(f, "Bn254"),
             CurveType::Ed25519 => write!(f, "Ed25519"),
             CurveType::Bls12381 => write!(f, "Bls12381"),
+            CurveType::Jubjub => write!(f, "Jubjub"),
+            CurveType::Pallas => write!(f, "Pallas"),
+            CurveType::Vesta => write!(f, "Vesta"),
+            CurveType::Custom(id) => write!(f, "Custom({id})"),
+        }
+    }
+}
+
+/// An elliptic curve operation with its own syscall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcOp {
+    Add,
+    Double,
+    Decompress,
+}
+
+/// The syscall code of every elliptic curve operation the executor dispatches, with its curve.
+///
+/// These mirror the `SyscallCode` variants of `sp1-core-executor`, which depends on this crate.
+const EC_SYSCALL_CODES: [(CurveType, EcOp, u32); 13] = [
+    (CurveType::Ed25519, EcOp::Add, 0x00_01_01_07),
+    (CurveType::Ed25519, EcOp::Decompress, 0x00_00_01_08),
+    (CurveType::Secp256k1, EcOp::Add, 0x00_01_01_0A),
+    (CurveType::Secp256k1, EcOp::Double, 0x00_00_01_0B),
+    (CurveType::Secp256k1, EcOp::Decompress, 0x00_00_01_0C),
+    (CurveType::Bn254, EcOp::Add, 0x00_01_01_0E),
+    (CurveType::Bn254, EcOp::Double, 0x00_00_01_0F),
+    (CurveType::Bls12381, EcOp::Decompress, 0x00_00_01_1C),
+    (CurveType::Bls12381, EcOp::Add, 0x00_01_01_1E),
+    (CurveType::Bls12381, EcOp::Double, 0x00_00_01_1F),
+    (CurveType::Secp256r1, EcOp::Add, 0x00_01_01_2C),
+    (CurveType::Secp256r1, EcOp::Double, 0x00_00_01_2D),
+    (CurveType::Secp256r1, EcOp::Decompress, 0x00_00_01_2E),
+];
+
+impl EcOp {
+    /// Returns the operation of an elliptic curve syscall, or `None` for any other syscall.
+    pub fn from_syscall_code(code: u32) -> Option<EcOp> {
+        EC_SYSCALL_CODES.iter().find(|&&(_, _, c)| c == code).map(|&(_, op, _)| op)
+    }
+}
+
+impl CurveType {
+    /// Returns the curve of an elliptic curve syscall, or `None` for any other syscall.
+    pub fn from_syscall_code(code: u32) -> Option<CurveType> {
+        EC_SYSCALL_CODES.iter().find(|&&(_, _, c)| c == code).map(|&(curve, _, _)| curve)
+    }
+
+    /// Returns the syscall code of an operation on the curve, the inverse of
+    /// [`CurveType::from_syscall_code`] and [`EcOp::from_syscall_code`]. Returns `None` if the
+    /// operation has no syscall on this curve.
+    pub fn syscall_code(self, op: EcOp) -> Option<u32> {
+        EC_SYSCALL_CODES.iter().find(|&&(curve, o, _)| curve == self && o == op).map(|&(_, _, c)| c)
+    }
+
+    /// Returns the order of the prime-order group of the curve, the modulus of its scalars.
+    ///
+    /// # Panics
+    ///
+    /// Panics for a custom curve, whose order is given by its [`registry::CustomCurve`].
+    pub fn prime_group_order(self) -> BigUint {
+        use edwards::EdwardsParameters;
+        use weierstrass::WeierstrassParameters;
+
+        match self {
+            CurveType::Secp256k1 => {
+                weierstrass::secp256k1::Secp256k1Parameters::prime_group_order()
+            }
+            CurveType::Secp256r1 => {
+                weierstrass::secp256r1::Secp256r1Parameters::prime_group_order()
+            }
+            CurveType::Bn254 => weierstrass::bn254::Bn254Parameters::prime_group_order(),
+            CurveType::Ed25519 => edwards::ed25519::Ed25519Parameters::prime_group_order(),
+            CurveType::Bls12381 => weierstrass::bls12_381::Bls12381Parameters::prime_group_order(),
+            CurveType::Jubjub => edwards::jubjub::JubjubParameters::prime_group_order(),
+            CurveType::Pallas => weierstrass::pasta::PallasParameters::prime_group_order(),
+            CurveType::Vesta => weierstrass::pasta::VestaParameters::prime_group_order(),
+            CurveType::Custom(_) => panic!("the order of {self} is given by its implementation"),
+        }
+    }
+
+    /// Returns the number of words of an affine point of the curve in memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics for a custom curve, whose point size is given by its [`EllipticCurve`] type.
+    pub fn words_per_point(self) -> usize {
+        match self {
+            CurveType::Secp256k1 => {
+                <weierstrass::secp256k1::Secp256k1BaseField as NumWords>::WordsCurvePoint::USIZE
+            }
+            CurveType::Secp256r1 => {
+                <weierstrass::secp256r1::Secp256r1BaseField as NumWords>::WordsCurvePoint::USIZE
+            }
+            CurveType::Bn254 => {
+                <weierstrass::bn254::Bn254BaseField as NumWords>::WordsCurvePoint::USIZE
+            }
+            CurveType::Ed25519 => {
+                <edwards::ed25519::Ed25519BaseField as NumWords>::WordsCurvePoint::USIZE
+            }
+            CurveType::Bls12381 => {
+                <weierstrass::bls12_381::Bls12381BaseField as NumWords>::WordsCurvePoint::USIZE
+            }
+            CurveType::Jubjub => {
+                <edwards::jubjub::JubjubBaseField as NumWords>::WordsCurvePoint::USIZE
+            }
+            CurveType::Pallas => {
+                <weierstrass::pasta::PallasBaseField as NumWords>::WordsCurvePoint::USIZE
+            }
+            CurveType::Vesta => {
+                <weierstrass::pasta::VestaBaseField as NumWords>::WordsCurvePoint::USIZE
+            }
+            CurveType::Custom(_) => panic!("the point size of {self} is given by its type"),
         }
     }
 }
@@ -99,6 +219,23 @@ impl<E: EllipticCurveParameters> AffinePoint<E> {
     }
 
     pub fn to_sec1_uncompressed(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(E::BaseField::NB_BYTES * 2 + 1);
+        out.push(0x04);
+        out.extend(self.to_bytes_be());
+        out
+    }
+
+    /// Decodes a point from `x || y`, each coordinate a fixed-width big-endian integer.
+    pub fn from_bytes_be(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), E::BaseField::NB_BYTES * 2);
+        let (x_bytes, y_bytes) = bytes.split_at(bytes.len() / 2);
+        let x = BigUint::from_bytes_be(x_bytes);
+        let y = BigUint::from_bytes_be(y_bytes);
+        Self { x, y, _marker: std::marker::PhantomData }
+    }
+
+    /// Encodes the point as `x || y`, each coordinate a fixed-width big-endian integer.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
         fn le_to_fixed_be<E: EllipticCurveParameters>(n: &BigUint) -> Vec<u8> {
             let le = n.to_bytes_le();
 
@@ -109,10 +246,8 @@ impl<E: EllipticCurveParameters> AffinePoint<E> {
             buf
         }
 
-        let mut out = vec![0u8; E::BaseField::NB_BYTES * 2 + 1];
-        out[0] = 0x04;
-        out[1..E::BaseField::NB_BYTES + 1].copy_from_slice(&le_to_fixed_be::<E>(&self.x));
-        out[E::BaseField::NB_BYTES + 1..].copy_from_slice(&le_to_fixed_be::<E>(&self.y));
+        let mut out = le_to_fixed_be::<E>(&self.x);
+        out.extend(le_to_fixed_be::<E>(&self.y));
         out
     }
 
@@ -166,6 +301,66 @@ impl<E: EllipticCurveParameters> AffinePoint<E> {
 
         words
     }
+
+    /// Decodes a point from its words in memory, each coordinate stored with the given byte order.
+    pub fn from_words(words: &[u64], endianness: Endianness) -> Self {
+        match endianness {
+            Endianness::Little => Self::from_words_le(words),
+            Endianness::Big => {
+                let bytes = words.iter().flat_map(|n| n.to_le_bytes()).collect::<Vec<_>>();
+                Self::from_bytes_be(&bytes)
+            }
+        }
+    }
+
+    /// Encodes the point as its words in memory, each coordinate stored with the given byte order.
+    pub fn to_words(&self, endianness: Endianness) -> Vec<u64> {
+        match endianness {
+            Endianness::Little => self.to_words_le(),
+            Endianness::Big => self
+                .to_bytes_be()
+                .chunks_exact(8)
+                .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+                .collect(),
+        }
+    }
+}
+
+impl<E: EllipticCurve> AffinePoint<E> {
+    /// The number of words of a point in memory, two coordinates of
+    /// [`EllipticCurve::COORD_STRIDE_WORDS`] words each.
+    pub fn num_memory_words() -> usize {
+        2 * E::COORD_STRIDE_WORDS
+    }
+
+    /// Decodes a point from memory, in the byte order given by [`EllipticCurve::MEMORY_ENDIANNESS`]
+    /// and with the coordinates [`EllipticCurve::COORD_STRIDE_WORDS`] words apart. The padding
+    /// after each coordinate is ignored.
+    pub fn from_memory_words(words: &[u64]) -> Self {
+        let n = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+        let stride = E::COORD_STRIDE_WORDS;
+        if stride == n {
+            return Self::from_words(words, E::MEMORY_ENDIANNESS);
+        }
+        let packed = [&words[..n], &words[stride..stride + n]].concat();
+        Self::from_words(&packed, E::MEMORY_ENDIANNESS)
+    }
+
+    /// Encodes a point for memory, in the byte order given by [`EllipticCurve::MEMORY_ENDIANNESS`]
+    /// and with the coordinates [`EllipticCurve::COORD_STRIDE_WORDS`] words apart. The padding
+    /// after each coordinate is zero.
+    pub fn to_memory_words(&self) -> Vec<u64> {
+        let n = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+        let stride = E::COORD_STRIDE_WORDS;
+        let packed = self.to_words(E::MEMORY_ENDIANNESS);
+        if stride == n {
+            return packed;
+        }
+        let mut words = vec![0; 2 * stride];
+        words[..n].copy_from_slice(&packed[..n]);
+        words[stride..stride + n].copy_from_slice(&packed[n..]);
+        words
+    }
 }
 
 pub trait EllipticCurveParameters:
@@ -176,11 +371,49 @@ pub trait EllipticCurveParameters:
     const CURVE_TYPE: CurveType;
 }
 
+/// How the identity of a curve group is encoded as an affine point in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentityEncoding {
+    /// The point at infinity, which has no affine coordinates, encoded as all-zero words.
+    Zero,
+    /// The affine neutral element returned by [`EllipticCurve::ec_neutral`], such as `(0, 1)` on
+    /// twisted Edwards curves.
+    Neutral,
+}
+
+/// The byte order of each coordinate of a point stored in memory.
+///
+/// Either way, a point takes the same words, `x` followed by `y`. With [`Endianness::Big`], the
+/// bytes of each coordinate in memory are its fixed-width big-endian encoding, as many guest
+/// libraries store them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endianness {
+    /// The least significant byte of each coordinate comes first.
+    #[default]
+    Little,
+    /// The most significant byte of each coordinate comes first.
+    Big,
+}
+
 /// An interface for elliptic curve groups.
 pub trait EllipticCurve: EllipticCurveParameters {
     const NB_LIMBS: usize = Self::BaseField::NB_LIMBS;
 
     const NB_WITNESS_LIMBS: usize = Self::BaseField::NB_WITNESS_LIMBS;
+
+    /// The encoding of the identity.
+    const IDENTITY_ENCODING: IdentityEncoding;
+
+    /// The byte order of the coordinates of a point in memory.
+    const MEMORY_ENDIANNESS: Endianness = Endianness::Little;
+
+    /// The number of words from the start of the `x` coordinate of a point in memory to the start
+    /// of its `y` coordinate. A stride longer than a field element zero-pads each coordinate, as
+    /// libraries that align coordinates to a power-of-two boundary do.
+    ///
+    /// Defaults to the words of a field element, so that the coordinates are contiguous.
+    const COORD_STRIDE_WORDS: usize = <Self::BaseField as NumWords>::WordsFieldElement::USIZE;
+
     /// Adds two different points on the curve.
     ///
     /// Warning: This method assumes that the two points are different.
@@ -199,6 +432,9 @@ pub trait EllipticCurve: EllipticCurveParameters {
     /// Returns the negative of a point on the curve.
     fn ec_neg(p: &AffinePoint<Self>) -> AffinePoint<Self>;
 
+    /// Returns whether the point has reduced coordinates that satisfy the curve equation.
+    fn ec_is_on_curve(p: &AffinePoint<Self>) -> bool;
+
     /// Returns the number of bits needed to represent a scalar in the group.
     fn nb_scalar_bits() -> usize {
         Self::BaseField::NB_LIMBS * Self::BaseField::NB_BITS_PER_LIMB
@@ -244,3 +480,142 @@ impl<E: EllipticCurve> Neg for AffinePoint<E> {
         -&self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use sp1_primitives::consts::{bytes_be_to_words_le_vec, words_to_bytes_be_vec};
+
+    use super::*;
+    use crate::{
+        edwards::{ed25519::Ed25519, jubjub::Jubjub},
+        weierstrass::{
+            bls12_381::Bls12381,
+            bn254::Bn254,
+            pasta::{Pallas, Vesta},
+            secp256k1::Secp256k1,
+            secp256r1::Secp256r1,
+        },
+    };
+
+    fn check_be_le_round_trip<E: EllipticCurve>() {
+        let mut point = E::ec_generator();
+        for _ in 0..4 {
+            let words = point.to_words_le();
+            let bytes = point.to_bytes_be();
+            assert_eq!(bytes.len(), E::BaseField::NB_BYTES * 2);
+            assert_eq!(AffinePoint::<E>::from_bytes_be(&bytes), point);
+
+            // Each coordinate's big endian bytes are its little endian words reversed.
+            let (x_words, y_words) = words.split_at(words.len() / 2);
+            let (x_bytes, y_bytes) = bytes.split_at(bytes.len() / 2);
+            assert_eq!(words_to_bytes_be_vec(x_words), x_bytes);
+            assert_eq!(words_to_bytes_be_vec(y_words), y_bytes);
+            assert_eq!(bytes_be_to_words_le_vec(x_bytes), x_words);
+            assert_eq!(bytes_be_to_words_le_vec(y_bytes), y_words);
+
+            point = E::ec_double(&point);
+        }
+    }
+
+    fn check_memory_words_round_trip<E: EllipticCurve>() {
+        let point = E::ec_double(&E::ec_generator());
+        let num_words = <E::BaseField as NumWords>::WordsCurvePoint::USIZE;
+        for endianness in [Endianness::Little, Endianness::Big] {
+            let words = point.to_words(endianness);
+            assert_eq!(words.len(), num_words);
+            assert_eq!(AffinePoint::<E>::from_words(&words, endianness), point);
+        }
+
+        let big = point.to_words(Endianness::Big);
+        let memory_bytes = big.iter().flat_map(|n| n.to_le_bytes()).collect::<Vec<_>>();
+        assert_eq!(memory_bytes, point.to_bytes_be());
+        assert_eq!(point.to_memory_words(), point.to_words_le());
+        assert_eq!(AffinePoint::<E>::from_memory_words(&point.to_words_le()), point);
+    }
+
+    #[test]
+    fn test_memory_words_round_trip() {
+        check_memory_words_round_trip::<Secp256k1>();
+        check_memory_words_round_trip::<Bn254>();
+        check_memory_words_round_trip::<Bls12381>();
+        check_memory_words_round_trip::<Ed25519>();
+    }
+
+    /// BLS12-381 G1 with each 6-word coordinate padded to an 8-word slot.
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+    struct PaddedBls12381;
+
+    impl EllipticCurveParameters for PaddedBls12381 {
+        type BaseField = <Bls12381 as EllipticCurveParameters>::BaseField;
+
+        const CURVE_TYPE: CurveType = CurveType::Bls12381;
+    }
+
+    fn cast<E: EllipticCurve, F: EllipticCurve>(p: &AffinePoint<E>) -> AffinePoint<F> {
+        AffinePoint::new(p.x.clone(), p.y.clone())
+    }
+
+    impl EllipticCurve for PaddedBls12381 {
+        const IDENTITY_ENCODING: IdentityEncoding = IdentityEncoding::Zero;
+        const COORD_STRIDE_WORDS: usize = 8;
+
+        fn ec_add(p: &AffinePoint<Self>, q: &AffinePoint<Self>) -> AffinePoint<Self> {
+            cast(&Bls12381::ec_add(&cast(p), &cast(q)))
+        }
+
+        fn ec_double(p: &AffinePoint<Self>) -> AffinePoint<Self> {
+            cast(&Bls12381::ec_double(&cast(p)))
+        }
+
+        fn ec_generator() -> AffinePoint<Self> {
+            cast(&Bls12381::ec_generator())
+        }
+
+        fn ec_neutral() -> Option<AffinePoint<Self>> {
+            None
+        }
+
+        fn ec_neg(p: &AffinePoint<Self>) -> AffinePoint<Self> {
+            cast(&Bls12381::ec_neg(&cast(p)))
+        }
+
+        fn ec_is_on_curve(p: &AffinePoint<Self>) -> bool {
+            Bls12381::ec_is_on_curve(&cast(p))
+        }
+    }
+
+    #[test]
+    fn test_memory_words_padded_stride() {
+        let point = PaddedBls12381::ec_double(&PaddedBls12381::ec_generator());
+        let packed = point.to_words_le();
+        let mut words = point.to_memory_words();
+        assert_eq!(words.len(), AffinePoint::<PaddedBls12381>::num_memory_words());
+        assert_eq!(words.len(), 16);
+        assert_eq!(words[..6], packed[..6]);
+        assert_eq!(words[6..8], [0, 0]);
+        assert_eq!(words[8..14], packed[6..]);
+        assert_eq!(words[14..], [0, 0]);
+        assert_eq!(AffinePoint::<PaddedBls12381>::from_memory_words(&words), point);
+
+        // The padding is ignored when decoding.
+        words[7] = u64::MAX;
+        words[15] = 1;
+        assert_eq!(AffinePoint::<PaddedBls12381>::from_memory_words(&words), point);
+
+        // The default stride keeps the coordinates contiguous.
+        assert_eq!(AffinePoint::<Bls12381>::num_memory_words(), 12);
+    }
+
+    #[test]
+    fn test_bytes_be_round_trip() {
+        check_be_le_round_trip::<Secp256k1>();
+        check_be_le_round_trip::<Secp256r1>();
+        check_be_le_round_trip::<Bn254>();
+        check_be_le_round_trip::<Bls12381>();
+        check_be_le_round_trip::<Ed25519>();
+        check_be_le_round_trip::<Jubjub>();
+        check_be_le_round_trip::<Pallas>();
+        check_be_le_round_trip::<Vesta>();
+    }
+}
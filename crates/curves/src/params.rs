@@ -110,6 +110,16 @@ where
     type WordsCurvePoint = <N::Limbs as Div<U4>>::Output;
 }
 
+/// Returns whether the word counts [`NumWords`] derives for `N` cover its limbs exactly.
+///
+/// The type-level divisions of [`NumWords`] round down, so a field whose limbs are not a whole
+/// number of words would get points of fewer words than the bytes its limbs and decompression
+/// read.
+pub const fn words_match_limbs<N: NumLimbs + NumWords>() -> bool {
+    let limb_bytes = N::Limbs::USIZE * NB_BITS_PER_LIMB / 8;
+    N::WordsFieldElement::USIZE * 8 == limb_bytes && N::WordsCurvePoint::USIZE * 8 == 2 * limb_bytes
+}
+
 impl<T: Copy, N: ArrayLength> Copy for Limbs<T, N> where N::ArrayType<T>: Copy {}
 
 impl<T, N: ArrayLength> Default for Limbs<T, N>
@@ -176,3 +186,55 @@ impl<T, N: ArrayLength> FromIterator<T> for Limbs<T, N> {
         Limbs(GenericArray::from_iter(iter))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        edwards::{ed25519::Ed25519BaseField, jubjub::JubjubBaseField},
+        uint256::U256Field,
+        weierstrass::{
+            bls12_381::Bls12381BaseField,
+            bn254::Bn254BaseField,
+            pasta::{PallasBaseField, VestaBaseField},
+            secp256k1::Secp256k1BaseField,
+            secp256r1::Secp256r1BaseField,
+        },
+    };
+
+    /// Fails to compile unless the words of each field match its limbs.
+    macro_rules! assert_words_match_limbs {
+        ($($field:ty),* $(,)?) => {
+            $(const _: () = assert!(words_match_limbs::<$field>());)*
+        };
+    }
+
+    assert_words_match_limbs!(
+        Secp256k1BaseField,
+        Secp256r1BaseField,
+        Bn254BaseField,
+        Bls12381BaseField,
+        PallasBaseField,
+        VestaBaseField,
+        Ed25519BaseField,
+        JubjubBaseField,
+        U256Field,
+    );
+
+    #[derive(Debug, Clone)]
+    struct OddLimbs;
+
+    impl NumLimbs for OddLimbs {
+        type Limbs = typenum::U36;
+        type Witness = typenum::U70;
+    }
+
+    #[test]
+    fn test_words_match_limbs() {
+        assert!(words_match_limbs::<Bls12381BaseField>());
+        // 36 bytes are four and a half words, which the division rounds down.
+        assert_eq!(<OddLimbs as NumWords>::WordsFieldElement::USIZE, 4);
+        assert_eq!(<OddLimbs as NumWords>::WordsCurvePoint::USIZE, 9);
+        assert!(!words_match_limbs::<OddLimbs>());
+    }
+}
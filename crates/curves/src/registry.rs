@@ -0,0 +1,86 @@
+//! Elliptic curves implemented outside of this crate.
+//!
+//! A downstream crate plugs in a curve by implementing [`CustomCurve`], giving its
+//! [`EllipticCurveParameters::CURVE_TYPE`] the value [`CurveType::Custom`] with an id, and
+//! registering the implementation under that id in a [`CustomCurveRegistry`]. The executor is built
+//! with the registry, filled by `SP1ContextBuilder::custom_curve`, and resolves the implementation
+//! of the curve once per event to add, double and decompress its points. The other events operate
+//! on the curve with the [`EllipticCurve`] methods of its type.
+//!
+//! ```ignore
+//! impl EllipticCurveParameters for MyCurveParameters {
+//!     type BaseField = MyBaseField;
+//!     const CURVE_TYPE: CurveType = CurveType::Custom(7);
+//! }
+//!
+//! let context = SP1Context::builder().custom_curve(7, Arc::new(MyCurve)).build();
+//! ```
+//!
+//! An event on a curve with no registered implementation fails instead of being executed.
+//!
+//! [`EllipticCurve`]: crate::EllipticCurve
+//! [`EllipticCurveParameters::CURVE_TYPE`]: crate::EllipticCurveParameters::CURVE_TYPE
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{BigUint, CurveType};
+
+/// The affine coordinates `(x, y)` of a point of a [`CustomCurve`].
+pub type CustomPoint = (BigUint, BigUint);
+
+/// The operations of an elliptic curve registered in a [`CustomCurveRegistry`].
+///
+/// The methods mirror those of [`EllipticCurve`] on type-erased points. The identity is handled by
+/// the executor, so none of the methods is called with it.
+///
+/// [`EllipticCurve`]: crate::EllipticCurve
+pub trait CustomCurve: Send + Sync {
+    /// Returns the order of the prime-order group of the curve, the modulus of its scalars.
+    fn prime_group_order(&self) -> BigUint;
+
+    /// Adds two points with different `x` coordinates.
+    fn add(&self, p: &CustomPoint, q: &CustomPoint) -> CustomPoint;
+
+    /// Doubles a point.
+    fn double(&self, p: &CustomPoint) -> CustomPoint;
+
+    /// Recovers a point from its big-endian compressed coordinate and sign bit, returning `None` if
+    /// no point has that coordinate.
+    fn decompress(&self, bytes_be: &[u8], sign_bit: u32) -> Option<CustomPoint>;
+
+    /// Returns the square root of `square` in the base field that [`Self::decompress`] computes
+    /// before negating it to match the sign bit, or `None` if `square` is not a square.
+    fn sqrt(&self, square: &BigUint) -> Option<BigUint>;
+}
+
+/// The implementations of the [`CurveType::Custom`] curves, by id.
+#[derive(Clone, Default)]
+pub struct CustomCurveRegistry {
+    table: BTreeMap<u32, Arc<dyn CustomCurve>>,
+}
+
+impl CustomCurveRegistry {
+    /// Creates a registry with no curves.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { table: BTreeMap::new() }
+    }
+
+    /// Registers the implementation of the curve [`CurveType::Custom`] with id `id`, returning the
+    /// implementation it replaces, if any.
+    pub fn register(
+        &mut self,
+        id: u32,
+        curve: Arc<dyn CustomCurve>,
+    ) -> Option<Arc<dyn CustomCurve>> {
+        self.table.insert(id, curve)
+    }
+
+    /// Returns the implementation registered for `curve`, or `None` if it is not a custom curve or
+    /// none is registered for its id.
+    #[must_use]
+    pub fn get(&self, curve: CurveType) -> Option<&Arc<dyn CustomCurve>> {
+        let CurveType::Custom(id) = curve else { return None };
+        self.table.get(&id)
+    }
+}
@@ -27,6 +27,16 @@ pub fn biguint_from_limbs(limbs: &[u8]) -> BigUint {
     BigUint::from_bytes_le(limbs)
 }
 
+/// Computes a square root of `a` modulo a prime `modulus = 3 mod 4`, returning `None` if `a` is not
+/// a square.
+///
+/// For such a modulus, the root of a square is its `(modulus + 1) / 4`-th power.
+pub fn sqrt_3mod4(a: &BigUint, modulus: &BigUint) -> Option<BigUint> {
+    let a = a % modulus;
+    let root = a.modpow(&((modulus + 1u32) >> 2), modulus);
+    ((&root * &root) % modulus == a).then_some(root)
+}
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "bigint-rug")] {
         pub fn biguint_to_rug(integer: &BigUint) -> rug::Integer {
@@ -4,7 +4,10 @@ use num::{BigUint, Num, Zero};
 use serde::{Deserialize, Serialize};
 use typenum::{U48, U94};
 
-use super::{FieldType, FpOpField, SwCurve, WeierstrassParameters};
+use super::{
+    g2::{Fp2, G2AffinePoint, G2Parameters},
+    FieldType, FpOpField, SwCurve, WeierstrassParameters,
+};
 use crate::{
     params::{FieldParameters, NumLimbs},
     CurveType, EllipticCurveParameters,
@@ -13,9 +16,10 @@ use crate::{
 /// Bls12-381 curve parameter
 use crate::{AffinePoint, EllipticCurve};
 
-// Serialization flags
-const COMPRESSION_FLAG: u8 = 0b_1000_0000;
-const Y_IS_ODD_FLAG: u8 = 0b_0010_0000;
+// Serialization flags, in the most significant byte of a compressed G1 or G2 point.
+pub const COMPRESSION_FLAG: u8 = 0b_1000_0000;
+pub const INFINITY_FLAG: u8 = 0b_0100_0000;
+pub const Y_IS_ODD_FLAG: u8 = 0b_0010_0000;
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 /// Bls12381 curve parameter
@@ -102,6 +106,16 @@ impl WeierstrassParameters for Bls12381Parameters {
         .unwrap()
     }
 
+    /// The cofactor `(z - 1)^2 / 3`, where `z = -0xd201000000010000` is the curve parameter.
+    fn cofactor() -> BigUint {
+        BigUint::from(0x396c8c005555e1568c00aaab0000aaab_u128)
+    }
+
+    /// The effective cofactor `1 - z` of RFC 9380, which is half as long as the cofactor.
+    fn effective_cofactor() -> BigUint {
+        BigUint::from(0xd201000000010001_u64)
+    }
+
     fn a_int() -> BigUint {
         BigUint::zero()
     }
@@ -111,6 +125,33 @@ impl WeierstrassParameters for Bls12381Parameters {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Bls12381 G2 curve parameter, the twist `y^2 = x^3 + 4(1 + u)` over `Fp2`.
+pub struct Bls12381G2Parameters;
+
+impl G2Parameters for Bls12381G2Parameters {
+    type BaseField = Bls12381BaseField;
+
+    fn b() -> Fp2<Self::BaseField> {
+        Fp2::from_u32(4, 4)
+    }
+
+    // The generator has been taken from the IETF pairing-friendly curves draft.
+    // https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-pairing-friendly-curves-11#section-4.2.1
+    fn generator() -> G2AffinePoint<Self> {
+        let coeff = |s: &str| BigUint::from_str_radix(s, 16).unwrap();
+        let x = Fp2::new(
+            coeff("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+            coeff("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+        );
+        let y = Fp2::new(
+            coeff("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+            coeff("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+        );
+        G2AffinePoint::new(x, y)
+    }
+}
+
 pub fn bls12381_decompress<E: EllipticCurve>(bytes_be: &[u8], sign_bit: u32) -> AffinePoint<E> {
     let mut g1_bytes_be: [u8; 48] = bytes_be.try_into().unwrap();
     let mut flags = COMPRESSION_FLAG;
@@ -130,6 +171,100 @@ pub fn bls12381_decompress<E: EllipticCurve>(bytes_be: &[u8], sign_bit: u32) ->
     AffinePoint::new(x, y)
 }
 
+/// Compresses a point to its 48-byte big-endian serialization, the inverse of
+/// [`bls12381_decompress`].
+///
+/// The most significant byte carries the flags: the compression flag is always set, the sign flag
+/// is set if `y` is lexicographically largest, that is `y > -y`, and `None` encodes the point at
+/// infinity as the compression and infinity flags followed by zeros.
+pub fn bls12381_compress<E: EllipticCurve>(point: Option<&AffinePoint<E>>) -> [u8; 48] {
+    let mut bytes = [0u8; 48];
+    let Some(point) = point else {
+        bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+        return bytes;
+    };
+
+    let x = point.x.to_bytes_be();
+    bytes[48 - x.len()..].copy_from_slice(&x);
+    bytes[0] |= COMPRESSION_FLAG;
+    if point.y > Bls12381BaseField::modulus() - &point.y {
+        bytes[0] |= Y_IS_ODD_FLAG;
+    }
+    bytes
+}
+
+/// Decompresses a point of G2 from its `x` coordinate and whether `y` is lexicographically largest,
+/// the sign convention of the compressed serialization. Returns `None` if `x` is not the
+/// coordinate of a point.
+pub fn bls12381_g2_decompress(
+    x: &Fp2<Bls12381BaseField>,
+    y_is_largest: bool,
+) -> Option<G2AffinePoint<Bls12381G2Parameters>> {
+    let rhs = &(&(x * x) * x) + &Bls12381G2Parameters::b();
+    let mut y = rhs.sqrt()?;
+    if y.is_lexicographically_largest() != y_is_largest {
+        y = -&y;
+    }
+    Some(G2AffinePoint::new(x.clone(), y))
+}
+
+/// Compresses a point of G2 to its 96-byte big-endian serialization, `x.c1` then `x.c0`, the
+/// inverse of [`bls12381_g2_decompress`].
+///
+/// The flags are those of [`bls12381_compress`], with the sign flag set if `y` is
+/// lexicographically largest.
+pub fn bls12381_g2_compress(point: Option<&G2AffinePoint<Bls12381G2Parameters>>) -> [u8; 96] {
+    let mut bytes = [0u8; 96];
+    let Some(point) = point else {
+        bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+        return bytes;
+    };
+
+    let c1 = point.x.c1.to_bytes_be();
+    bytes[48 - c1.len()..48].copy_from_slice(&c1);
+    let c0 = point.x.c0.to_bytes_be();
+    bytes[96 - c0.len()..].copy_from_slice(&c0);
+    bytes[0] |= COMPRESSION_FLAG;
+    if point.y.is_lexicographically_largest() {
+        bytes[0] |= Y_IS_ODD_FLAG;
+    }
+    bytes
+}
+
+/// Returns the coefficient of `x` in [`bls12381_g2_psi`], `1 / (1 + u)^((p - 1) / 3)`, which is
+/// `u` times
+/// `0x1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaad`.
+pub fn bls12381_psi_coeff_x() -> Fp2<Bls12381BaseField> {
+    Fp2::new(
+        BigUint::zero(),
+        BigUint::from_str_radix("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaad", 16).unwrap(),
+    )
+}
+
+/// Returns the coefficient of `y` in [`bls12381_g2_psi`], `1 / (1 + u)^((p - 1) / 2)`, which is
+/// `0x135203e60180a68ee2e9c448d77a2cd91c3dedd930b1cf60ef396489f61eb45e304466cf3e67fa0af1ee7b04121bdea2`
+/// plus `u` times
+/// `0x06af0e0437ff400b6831e36d6bd17ffe48395dabc2d3435e77f76e17009241c5ee67992f72ec05f4c81084fbede3cc09`.
+pub fn bls12381_psi_coeff_y() -> Fp2<Bls12381BaseField> {
+    Fp2::new(
+        BigUint::from_str_radix("135203e60180a68ee2e9c448d77a2cd91c3dedd930b1cf60ef396489f61eb45e304466cf3e67fa0af1ee7b04121bdea2", 16).unwrap(),
+        BigUint::from_str_radix("06af0e0437ff400b6831e36d6bd17ffe48395dabc2d3435e77f76e17009241c5ee67992f72ec05f4c81084fbede3cc09", 16).unwrap(),
+    )
+}
+
+/// Applies the untwist-Frobenius-twist endomorphism `psi` of G2, which maps `(x, y)` to
+/// `(conj(x) * cx, conj(y) * cy)` with the coefficients [`bls12381_psi_coeff_x`] and
+/// [`bls12381_psi_coeff_y`]. On G2 it is the multiplication by the BLS parameter
+/// `z = -0xd201000000010000`.
+pub fn bls12381_g2_psi(
+    point: &G2AffinePoint<Bls12381G2Parameters>,
+) -> G2AffinePoint<Bls12381G2Parameters> {
+    G2AffinePoint::new(
+        &point.x.conjugate() * &bls12381_psi_coeff_x(),
+        &point.y.conjugate() * &bls12381_psi_coeff_y(),
+    )
+}
+
 pub fn bls12381_sqrt(a: &BigUint) -> BigUint {
     let a_big = Big::from_bytes(a.to_bytes_be().as_slice());
 
@@ -215,4 +350,71 @@ mod tests {
             BigUint::from_bytes_le(<Bls12381BaseField as FieldParameters>::MODULUS)
         );
     }
+
+    #[test]
+    fn test_bls12381_compress() {
+        let mut point = Bls12381::ec_generator();
+        for _ in 0..NUM_TEST_CASES {
+            let compressed = bls12381_compress(Some(&point));
+            assert_ne!(compressed[0] & COMPRESSION_FLAG, 0);
+            let sign_bit = u32::from(compressed[0] & Y_IS_ODD_FLAG != 0);
+            assert_eq!(bls12381_decompress::<Bls12381>(&compressed, sign_bit), point);
+            point = point.sw_double();
+        }
+
+        let infinity = bls12381_compress::<Bls12381>(None);
+        assert_eq!(infinity[0], 0xc0);
+        assert!(infinity[1..].iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn test_bls12381_g2_psi() {
+        // The coefficients are the inverses of powers of `xi = 1 + u`.
+        let p = Bls12381BaseField::modulus();
+        let xi_pow = |exp: &BigUint| {
+            let xi = Fp2::<Bls12381BaseField>::from_u32(1, 1);
+            let mut result = Fp2::from_u32(1, 0);
+            for i in (0..exp.bits()).rev() {
+                result = &result * &result;
+                if exp.bit(i) {
+                    result = &result * &xi;
+                }
+            }
+            result
+        };
+        assert_eq!(bls12381_psi_coeff_x(), xi_pow(&((&p - 1u32) / 3u32)).inv().unwrap());
+        assert_eq!(bls12381_psi_coeff_y(), xi_pow(&((&p - 1u32) / 2u32)).inv().unwrap());
+
+        // On G2, psi is the multiplication by `z` modulo the group order, and psi^2 by `z^2`.
+        let r = Bls12381Parameters::prime_group_order();
+        let z = &r - BigUint::from(0xd201000000010000u64);
+        let mut point = Bls12381G2Parameters::generator();
+        for _ in 0..NUM_TEST_CASES {
+            let psi = bls12381_g2_psi(&point);
+            assert!(psi.is_on_curve());
+            assert_eq!(Some(psi.clone()), point.g2_mul(&z));
+            assert_eq!(Some(bls12381_g2_psi(&psi)), point.g2_mul(&(&z * &z % &r)));
+            assert_eq!(bls12381_g2_psi(&point.g2_neg()), psi.g2_neg());
+            point = point.g2_add(&Bls12381G2Parameters::generator()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_bls12381_g2_compress() {
+        let generator = Bls12381G2Parameters::generator();
+        let mut point = generator.clone();
+        for _ in 0..NUM_TEST_CASES {
+            for point in [point.clone(), point.g2_neg()] {
+                let compressed = bls12381_g2_compress(Some(&point));
+                assert_ne!(compressed[0] & COMPRESSION_FLAG, 0);
+                let y_is_largest = compressed[0] & Y_IS_ODD_FLAG != 0;
+                assert_eq!(bls12381_g2_decompress(&point.x, y_is_largest), Some(point));
+            }
+            point = point.g2_add(&generator).unwrap();
+        }
+
+        let infinity = bls12381_g2_compress(None);
+        assert_eq!(infinity[0], 0xc0);
+        assert!(infinity[1..].iter().all(|&byte| byte == 0));
+    }
 }
@@ -6,7 +6,8 @@ use typenum::{U32, U62};
 use super::{FieldType, FpOpField, SwCurve, WeierstrassParameters};
 use crate::{
     params::{FieldParameters, NumLimbs},
-    CurveType, EllipticCurveParameters,
+    utils::sqrt_3mod4,
+    AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -89,14 +90,74 @@ impl WeierstrassParameters for Bn254Parameters {
     }
 }
 
+/// Decompresses a point on the Bn254 curve from its big-endian x coordinate.
+///
+/// The `sign_bit` selects the root whose y coordinate is odd. Returns `None` if `x^3 + 3` is not a
+/// quadratic residue, i.e. `x` is not the x coordinate of a point on the curve.
+pub fn bn254_decompress<E: EllipticCurve>(
+    bytes_be: &[u8],
+    sign_bit: u32,
+) -> Option<AffinePoint<E>> {
+    let modulus = Bn254BaseField::modulus();
+    let x = BigUint::from_bytes_be(bytes_be) % &modulus;
+    let y_squared = (&x * &x * &x + Bn254Parameters::b_int()) % &modulus;
+
+    let mut y = bn254_sqrt(&y_squared)?;
+    if y.bit(0) != (sign_bit == 1) {
+        y = (&modulus - &y) % &modulus;
+    }
+
+    Some(AffinePoint::new(x, y))
+}
+
+/// Computes a square root in the base field of Bn254, returning `None` for non-residues.
+pub fn bn254_sqrt(a: &BigUint) -> Option<BigUint> {
+    sqrt_3mod4(a, &Bn254BaseField::modulus())
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
     use crate::utils::biguint_from_limbs;
+    use num::bigint::RandBigInt;
+    use rand::thread_rng;
 
     #[test]
     fn test_weierstrass_biguint_scalar_mul() {
         assert_eq!(biguint_from_limbs(Bn254BaseField::MODULUS), Bn254BaseField::modulus());
     }
+
+    #[test]
+    fn test_bn254_sqrt() {
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            // Check that sqrt(x^2)^2 == x^2
+            // We use x^2 since not all field elements have a square root
+            let x = rng.gen_biguint(256) % Bn254BaseField::modulus();
+            let x_2 = (&x * &x) % Bn254BaseField::modulus();
+            let sqrt = bn254_sqrt(&x_2).unwrap();
+            let sqrt_2 = (&sqrt * &sqrt) % Bn254BaseField::modulus();
+            assert_eq!(sqrt_2, x_2);
+        }
+    }
+
+    #[test]
+    fn test_bn254_decompress() {
+        let (x, y) = Bn254Parameters::generator();
+        let neg_y = Bn254BaseField::modulus() - &y;
+        let x_bytes = x.to_bytes_be();
+
+        // The generator is (1, 2), so the even root is the generator itself.
+        let even = bn254_decompress::<Bn254>(&x_bytes, 0).unwrap();
+        assert_eq!(even, AffinePoint::new(x.clone(), y));
+        let odd = bn254_decompress::<Bn254>(&x_bytes, 1).unwrap();
+        assert_eq!(odd, AffinePoint::new(x, neg_y));
+    }
+
+    #[test]
+    fn test_bn254_decompress_non_residue() {
+        // 4^3 + 3 = 67 is not a quadratic residue modulo the Bn254 base field.
+        assert!(bn254_decompress::<Bn254>(&[4], 0).is_none());
+    }
 }
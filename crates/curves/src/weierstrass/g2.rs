@@ -0,0 +1,359 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use num::{BigUint, Zero};
+use typenum::Unsigned;
+
+use super::FpOpField;
+use crate::{
+    params::{FieldParameters, NumWords},
+    utils::sqrt_3mod4,
+};
+
+/// An element `c0 + c1 * u` of the quadratic extension `Fp2 = Fp[u] / (u^2 + 1)`.
+#[derive(Debug, Clone)]
+pub struct Fp2<F> {
+    pub c0: BigUint,
+    pub c1: BigUint,
+    _marker: PhantomData<F>,
+}
+
+impl<F: FieldParameters> Fp2<F> {
+    pub fn new(c0: BigUint, c1: BigUint) -> Self {
+        Self { c0, c1, _marker: PhantomData }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(BigUint::zero(), BigUint::zero())
+    }
+
+    pub fn from_u32(c0: u32, c1: u32) -> Self {
+        Self::new(BigUint::from(c0), BigUint::from(c1))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    /// Returns the inverse of the element, or `None` if it is zero.
+    ///
+    /// Since `u^2 = -1`, the inverse is `(c0 - c1 * u) / (c0^2 + c1^2)`.
+    pub fn inv(&self) -> Option<Self> {
+        let p = F::modulus();
+        let norm = (&self.c0 * &self.c0 + &self.c1 * &self.c1) % &p;
+        if norm.is_zero() {
+            return None;
+        }
+        let norm_inv = norm.modpow(&(&p - 2u32), &p);
+        let c0 = (&self.c0 * &norm_inv) % &p;
+        let c1 = ((&p - &self.c1 % &p) * &norm_inv) % &p;
+        Some(Self::new(c0, c1))
+    }
+
+    /// Returns a square root of the element, or `None` if it is not a square.
+    ///
+    /// The modulus must be `3 mod 4`, as for BLS12-381, so that `-1` is not a square in `Fp` and
+    /// square roots in `Fp` are powers. Writing the root `x0 + x1 * u`, `x0^2` is half the sum of
+    /// `c0` and a square root of the norm `c0^2 + c1^2`, and `x1 = c1 / (2 * x0)`.
+    pub fn sqrt(&self) -> Option<Self> {
+        let p = F::modulus();
+        let sqrt_fp = |a: &BigUint| sqrt_3mod4(a, &p);
+        let (c0, c1) = (&self.c0 % &p, &self.c1 % &p);
+        let root = if c1.is_zero() {
+            match sqrt_fp(&c0) {
+                Some(x0) => Self::new(x0, BigUint::zero()),
+                None => Self::new(BigUint::zero(), sqrt_fp(&(&p - &c0))?),
+            }
+        } else {
+            let norm_root = sqrt_fp(&((&c0 * &c0 + &c1 * &c1) % &p))?;
+            let half = (&p + 1u32) >> 1;
+            let x0 = sqrt_fp(&(((&c0 + &norm_root) * &half) % &p))
+                .or_else(|| sqrt_fp(&(((&c0 + &p - &norm_root) * &half) % &p)))?;
+            let x1 = (&c1 * (&x0 * 2u32).modpow(&(&p - 2u32), &p)) % &p;
+            Self::new(x0, x1)
+        };
+        (&root * &root == Self::new(c0, c1)).then_some(root)
+    }
+
+    /// Returns the conjugate `c0 - c1 * u`, the image of the element under the Frobenius map
+    /// `a -> a^p`.
+    pub fn conjugate(&self) -> Self {
+        let p = F::modulus();
+        Self::new(self.c0.clone(), (&p - &self.c1 % &p) % &p)
+    }
+
+    /// Returns whether the element is greater than its negation, comparing `c1` first and `c0` if
+    /// `c1` is zero. This is the sign of `y` in the compressed serialization of BLS12-381 G2 points.
+    pub fn is_lexicographically_largest(&self) -> bool {
+        let half = (F::modulus() - 1u32) >> 1;
+        if self.c1.is_zero() {
+            self.c0 > half
+        } else {
+            self.c1 > half
+        }
+    }
+}
+
+impl<F: FpOpField> Fp2<F> {
+    /// Returns the element as `c0` then `c1`, each taking as many little-endian words as an element
+    /// of the base field.
+    pub fn to_words_le(&self) -> Vec<u64> {
+        let n = <F as NumWords>::WordsFieldElement::USIZE;
+        let mut words = Vec::with_capacity(2 * n);
+        for coeff in [&self.c0, &self.c1] {
+            let mut bytes = coeff.to_bytes_le();
+            bytes.resize(n * 8, 0);
+            words.extend(bytes.chunks_exact(8).map(|c| u64::from_le_bytes(c.try_into().unwrap())));
+        }
+        words
+    }
+}
+
+impl<F> PartialEq for Fp2<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1
+    }
+}
+
+impl<F> Eq for Fp2<F> {}
+
+impl<F: FieldParameters> Add for &Fp2<F> {
+    type Output = Fp2<F>;
+
+    fn add(self, other: Self) -> Fp2<F> {
+        let p = F::modulus();
+        Fp2::new((&self.c0 + &other.c0) % &p, (&self.c1 + &other.c1) % &p)
+    }
+}
+
+impl<F: FieldParameters> Sub for &Fp2<F> {
+    type Output = Fp2<F>;
+
+    fn sub(self, other: Self) -> Fp2<F> {
+        let p = F::modulus();
+        Fp2::new((&self.c0 + &p - &other.c0 % &p) % &p, (&self.c1 + &p - &other.c1 % &p) % &p)
+    }
+}
+
+impl<F: FieldParameters> Mul for &Fp2<F> {
+    type Output = Fp2<F>;
+
+    fn mul(self, other: Self) -> Fp2<F> {
+        let p = F::modulus();
+        let ac = (&self.c0 * &other.c0) % &p;
+        let bd = (&self.c1 * &other.c1) % &p;
+        let c0 = (ac + &p - bd) % &p;
+        let c1 = (&self.c0 * &other.c1 + &self.c1 * &other.c0) % &p;
+        Fp2::new(c0, c1)
+    }
+}
+
+impl<F: FieldParameters> Neg for &Fp2<F> {
+    type Output = Fp2<F>;
+
+    fn neg(self) -> Fp2<F> {
+        &Fp2::zero() - self
+    }
+}
+
+/// Parameters of a short Weierstrass curve `y^2 = x^3 + b` over `Fp2`, such as the twist carrying
+/// the G2 group of a pairing-friendly curve.
+pub trait G2Parameters: Debug + Send + Sync + Copy + 'static {
+    type BaseField: FpOpField;
+
+    fn b() -> Fp2<Self::BaseField>;
+
+    fn generator() -> G2AffinePoint<Self>;
+}
+
+/// An affine point on a curve over `Fp2`.
+///
+/// In memory, a point is laid out as `x.c0, x.c1, y.c0, y.c1`, each coefficient taking as many
+/// little-endian words as an element of the base field.
+#[derive(Debug, Clone)]
+pub struct G2AffinePoint<G: G2Parameters> {
+    pub x: Fp2<G::BaseField>,
+    pub y: Fp2<G::BaseField>,
+}
+
+impl<G: G2Parameters> PartialEq for G2AffinePoint<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<G: G2Parameters> Eq for G2AffinePoint<G> {}
+
+impl<G: G2Parameters> G2AffinePoint<G> {
+    pub fn new(x: Fp2<G::BaseField>, y: Fp2<G::BaseField>) -> Self {
+        Self { x, y }
+    }
+
+    /// The number of words of a point, four base field elements.
+    pub fn num_words() -> usize {
+        2 * <G::BaseField as NumWords>::WordsCurvePoint::USIZE
+    }
+
+    pub fn from_words_le(words: &[u64]) -> Self {
+        let n = words.len() / 4;
+        let coeff = |i: usize| {
+            let bytes =
+                words[i * n..(i + 1) * n].iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<_>>();
+            BigUint::from_bytes_le(&bytes)
+        };
+        Self::new(Fp2::new(coeff(0), coeff(1)), Fp2::new(coeff(2), coeff(3)))
+    }
+
+    pub fn to_words_le(&self) -> Vec<u64> {
+        let mut words = self.x.to_words_le();
+        words.extend(self.y.to_words_le());
+        words
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        let p = G::BaseField::modulus();
+        if [&self.x.c0, &self.x.c1, &self.y.c0, &self.y.c1].iter().any(|c| **c >= p) {
+            return false;
+        }
+        let lhs = &self.y * &self.y;
+        let rhs = &(&(&self.x * &self.x) * &self.x) + &G::b();
+        lhs == rhs
+    }
+
+    pub fn g2_neg(&self) -> Self {
+        Self::new(self.x.clone(), -&self.y)
+    }
+
+    /// Adds two points, returning `None` if the sum is the point at infinity.
+    pub fn g2_add(&self, other: &Self) -> Option<Self> {
+        if self.x == other.x {
+            return if self.y == other.y { self.g2_double() } else { None };
+        }
+        let slope = &(&other.y - &self.y) * &(&other.x - &self.x).inv()?;
+        Some(self.chord(&slope, &other.x))
+    }
+
+    /// Doubles a point, returning `None` if the result is the point at infinity.
+    pub fn g2_double(&self) -> Option<Self> {
+        let slope = self.tangent_slope()?;
+        Some(self.chord(&slope, &self.x))
+    }
+
+    /// Multiplies a point by a scalar, returning `None` if the result is the point at infinity.
+    pub fn g2_mul(&self, scalar: &BigUint) -> Option<Self> {
+        let mut result: Option<Self> = None;
+        for i in (0..scalar.bits()).rev() {
+            result = result.and_then(|point| point.g2_double());
+            if scalar.bit(i) {
+                result = match result {
+                    Some(point) => point.g2_add(self),
+                    None => Some(self.clone()),
+                };
+            }
+        }
+        result
+    }
+
+    /// Doubles a point as in a Miller loop doubling step, also returning the tangent line at the
+    /// point evaluated at `(x_p, y_p)`, a point of the curve over `Fp`.
+    ///
+    /// The curve is taken to be an M-type twist, with `Fp12 = Fp2[w] / (w^6 - xi)`. The line is
+    /// returned as its coefficients `[l0, l2, l3]` of `l0 + l2 * w^2 + l3 * w^3`, the other
+    /// coefficients being zero. It is the untwisted tangent scaled by `w^3`, a factor in a proper
+    /// subfield that the final exponentiation removes. Returns `None` if the tangent is vertical.
+    pub fn g2_double_with_line(
+        &self,
+        x_p: &BigUint,
+        y_p: &BigUint,
+    ) -> Option<(Self, [Fp2<G::BaseField>; 3])> {
+        let p = G::BaseField::modulus();
+        let slope = self.tangent_slope()?;
+        let l0 = &(&slope * &self.x) - &self.y;
+        let l2 = -&(&slope * &Fp2::new(x_p % &p, BigUint::zero()));
+        let l3 = Fp2::new(y_p % &p, BigUint::zero());
+        Some((self.chord(&slope, &self.x), [l0, l2, l3]))
+    }
+
+    /// Returns the slope of the tangent at the point, or `None` if it is vertical.
+    fn tangent_slope(&self) -> Option<Fp2<G::BaseField>> {
+        let x_sq = &self.x * &self.x;
+        let numerator = &(&x_sq + &x_sq) + &x_sq;
+        Some(&numerator * &(&self.y + &self.y).inv()?)
+    }
+
+    /// Returns the third intersection of the line through `self` with the given slope, reflected.
+    fn chord(&self, slope: &Fp2<G::BaseField>, other_x: &Fp2<G::BaseField>) -> Self {
+        let x = &(&(slope * slope) - &self.x) - other_x;
+        let y = &(slope * &(&self.x - &x)) - &self.y;
+        Self::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weierstrass::{
+        bls12_381::{Bls12381BaseField, Bls12381G2Parameters, Bls12381Parameters},
+        WeierstrassParameters,
+    };
+
+    type G2 = G2AffinePoint<Bls12381G2Parameters>;
+
+    #[test]
+    fn test_fp2_inv() {
+        let a = Fp2::<Bls12381BaseField>::from_u32(3, 7);
+        assert_eq!(&a * &a.inv().unwrap(), Fp2::from_u32(1, 0));
+        assert!(Fp2::<Bls12381BaseField>::zero().inv().is_none());
+    }
+
+    #[test]
+    fn test_fp2_sqrt() {
+        for (c0, c1) in [(0, 0), (4, 0), (3, 0), (0, 5), (3, 7), (1, 1)] {
+            let a = Fp2::<Bls12381BaseField>::from_u32(c0, c1);
+            let square = &a * &a;
+            let root = square.sqrt().unwrap();
+            assert!(root == a || root == -&a);
+            assert!(
+                a.is_zero()
+                    || a.is_lexicographically_largest() != (-&a).is_lexicographically_largest()
+            );
+        }
+        // The twist coefficient `4(1 + u)` has no square root, since its norm 32 is not a square.
+        assert!(Bls12381G2Parameters::b().sqrt().is_none());
+    }
+
+    #[test]
+    fn test_g2_double_and_add() {
+        let generator = Bls12381G2Parameters::generator();
+        assert!(generator.is_on_curve());
+
+        let doubled = generator.g2_double().unwrap();
+        assert!(doubled.is_on_curve());
+        assert_eq!(generator.g2_add(&generator), Some(doubled.clone()));
+        let (x_p, y_p) = (BigUint::from(1u32), BigUint::from(2u32));
+        let (doubled_with_line, _) = generator.g2_double_with_line(&x_p, &y_p).unwrap();
+        assert_eq!(doubled_with_line, doubled);
+
+        let tripled = generator.g2_add(&doubled).unwrap();
+        assert!(tripled.is_on_curve());
+        assert_eq!(doubled.g2_add(&generator), Some(tripled));
+
+        assert_eq!(generator.g2_add(&generator.g2_neg()), None);
+        assert_eq!(G2::from_words_le(&generator.to_words_le()), generator);
+        assert_eq!(generator.to_words_le().len(), G2::num_words());
+    }
+
+    #[test]
+    fn test_g2_mul() {
+        let generator = Bls12381G2Parameters::generator();
+        let tripled = generator.g2_add(&generator.g2_double().unwrap());
+        assert_eq!(generator.g2_mul(&BigUint::from(3u32)), tripled);
+        assert_eq!(generator.g2_mul(&BigUint::from(1u32)), Some(generator.clone()));
+        assert_eq!(generator.g2_mul(&BigUint::zero()), None);
+        // G2 has the same prime order as G1.
+        assert_eq!(generator.g2_mul(&Bls12381Parameters::prime_group_order()), None);
+    }
+}
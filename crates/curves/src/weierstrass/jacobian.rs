@@ -0,0 +1,324 @@
+use std::marker::PhantomData;
+
+use num::{BigUint, One, Zero};
+use typenum::Unsigned;
+
+use super::WeierstrassParameters;
+use crate::{
+    params::{FieldParameters, NumWords},
+    AffinePoint, EllipticCurve, Endianness,
+};
+
+/// A point `(X, Y, Z)` of a short Weierstrass curve in Jacobian coordinates, representing the affine
+/// point `(X / Z^2, Y / Z^3)`. Any point with `Z = 0` is the point at infinity.
+///
+/// Additions and doublings need no inversion, so a sum of many points costs a single inversion
+/// when it is finally converted to affine coordinates.
+///
+/// In memory, a point is laid out as `X, Y, Z`, each in the byte order given by
+/// [`EllipticCurve::MEMORY_ENDIANNESS`] and [`EllipticCurve::COORD_STRIDE_WORDS`] words apart, as
+/// the coordinates of an [`AffinePoint`] are.
+#[derive(Debug, Clone)]
+pub struct JacobianPoint<E> {
+    pub x: BigUint,
+    pub y: BigUint,
+    pub z: BigUint,
+    _marker: PhantomData<E>,
+}
+
+fn sub_mod(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
+    (a + p - b % p) % p
+}
+
+impl<E: WeierstrassParameters> JacobianPoint<E> {
+    pub fn new(x: BigUint, y: BigUint, z: BigUint) -> Self {
+        Self { x, y, z, _marker: PhantomData }
+    }
+
+    /// The point at infinity, `(1, 1, 0)`.
+    pub fn identity() -> Self {
+        Self::new(BigUint::one(), BigUint::one(), BigUint::zero())
+    }
+
+    pub fn is_identity(&self) -> bool {
+        (&self.z % E::BaseField::modulus()).is_zero()
+    }
+
+    pub fn from_affine(point: &AffinePoint<E>) -> Self {
+        Self::new(point.x.clone(), point.y.clone(), BigUint::one())
+    }
+
+    /// Returns the affine point, or `None` if the point is at infinity. This is the only operation
+    /// that inverts a field element.
+    pub fn to_affine(&self) -> Option<AffinePoint<E>> {
+        if self.is_identity() {
+            return None;
+        }
+        let p = E::BaseField::modulus();
+        let z_inv = self.z.modpow(&(&p - 2u32), &p);
+        let z_inv_sq = (&z_inv * &z_inv) % &p;
+        let x = (&self.x * &z_inv_sq) % &p;
+        let y = (&self.y * z_inv_sq * z_inv) % &p;
+        Some(AffinePoint::new(x, y))
+    }
+
+    /// The number of words of a point, three base field elements.
+    pub fn num_words() -> usize {
+        3 * <E::BaseField as NumWords>::WordsFieldElement::USIZE
+    }
+
+    pub fn from_words_le(words: &[u64]) -> Self {
+        let n = words.len() / 3;
+        let coord = |i: usize| {
+            let bytes =
+                words[i * n..(i + 1) * n].iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<_>>();
+            BigUint::from_bytes_le(&bytes)
+        };
+        Self::new(coord(0), coord(1), coord(2))
+    }
+
+    pub fn to_words_le(&self) -> Vec<u64> {
+        let n = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+        let mut words = Vec::with_capacity(3 * n);
+        for coord in [&self.x, &self.y, &self.z] {
+            let mut bytes = coord.to_bytes_le();
+            bytes.resize(n * 8, 0);
+            words.extend(bytes.chunks_exact(8).map(|c| u64::from_le_bytes(c.try_into().unwrap())));
+        }
+        words
+    }
+
+    /// Doubles a point.
+    pub fn double(&self) -> Self {
+        let p = E::BaseField::modulus();
+        if self.is_identity() || (&self.y % &p).is_zero() {
+            return Self::identity();
+        }
+        let yy = (&self.y * &self.y) % &p;
+        let s = (&self.x * &yy * 4u32) % &p;
+        let zz = (&self.z * &self.z) % &p;
+        let m = (&self.x * &self.x * 3u32 + E::a_int() * &zz * &zz) % &p;
+        let x = sub_mod(&(&m * &m), &(&s * 2u32), &p);
+        let y = sub_mod(&(m * sub_mod(&s, &x, &p)), &(&yy * &yy * 8u32), &p);
+        let z = (&self.y * &self.z * 2u32) % &p;
+        Self::new(x, y, z)
+    }
+
+    /// Adds an affine point, using the mixed addition formulas.
+    pub fn add_affine(&self, other: &AffinePoint<E>) -> Self {
+        if self.is_identity() {
+            return Self::from_affine(other);
+        }
+        let p = E::BaseField::modulus();
+        let zz = (&self.z * &self.z) % &p;
+        let u = (&other.x * &zz) % &p;
+        let s = (&other.y * zz * &self.z) % &p;
+        let h = sub_mod(&u, &self.x, &p);
+        let r = sub_mod(&s, &self.y, &p);
+        if h.is_zero() {
+            return if r.is_zero() { self.double() } else { Self::identity() };
+        }
+        let hh = (&h * &h) % &p;
+        let hhh = (&hh * &h) % &p;
+        let v = (&self.x * hh) % &p;
+        let x = sub_mod(&sub_mod(&(&r * &r), &hhh, &p), &(&v * 2u32), &p);
+        let y = sub_mod(&(r * sub_mod(&v, &x, &p)), &(&self.y * hhh), &p);
+        let z = (&self.z * h) % &p;
+        Self::new(x, y, z)
+    }
+
+    /// Adds a point with the same `Z` coordinate, using the co-Z formulas of Meloni (`ZADDU`).
+    ///
+    /// Returns `self + other` and `self` scaled to the `Z` coordinate of the sum, so that the two
+    /// points again share a `Z` coordinate. The points must have distinct `x` coordinates.
+    pub fn co_z_add(&self, other: &Self) -> (Self, Self) {
+        let p = E::BaseField::modulus();
+        let dx = sub_mod(&self.x, &other.x, &p);
+        let dy = sub_mod(&self.y, &other.y, &p);
+        let c = (&dx * &dx) % &p;
+        let w1 = (&self.x * &c) % &p;
+        let w2 = (&other.x * c) % &p;
+        let a1 = (&self.y * sub_mod(&w1, &w2, &p)) % &p;
+        let x = sub_mod(&sub_mod(&(&dy * &dy), &w1, &p), &w2, &p);
+        let y = sub_mod(&(dy * sub_mod(&w1, &x, &p)), &a1, &p);
+        let z = (&self.z * dx) % &p;
+        (Self::new(x, y, z.clone()), Self::new(w1, a1, z))
+    }
+
+    /// Adds and subtracts a point with the same `Z` coordinate, using the conjugate co-Z formulas
+    /// (`ZADDC`).
+    ///
+    /// Returns `self + other` and `self - other`, which share a `Z` coordinate. The points must have
+    /// distinct `x` coordinates.
+    pub fn co_z_add_conjugate(&self, other: &Self) -> (Self, Self) {
+        let p = E::BaseField::modulus();
+        let dx = sub_mod(&self.x, &other.x, &p);
+        let c = (&dx * &dx) % &p;
+        let w1 = (&self.x * &c) % &p;
+        let w2 = (&other.x * c) % &p;
+        let a1 = (&self.y * sub_mod(&w1, &w2, &p)) % &p;
+        let z = (&self.z * dx) % &p;
+        let sum_dy = sub_mod(&self.y, &other.y, &p);
+        let diff_dy = (&self.y + &other.y) % &p;
+        let coords = |dy: BigUint| {
+            let x = sub_mod(&sub_mod(&(&dy * &dy), &w1, &p), &w2, &p);
+            let y = sub_mod(&(dy * sub_mod(&w1, &x, &p)), &a1, &p);
+            (x, y)
+        };
+        let (sum_x, sum_y) = coords(sum_dy);
+        let (diff_x, diff_y) = coords(diff_dy);
+        (Self::new(sum_x, sum_y, z.clone()), Self::new(diff_x, diff_y, z))
+    }
+}
+
+impl<E: EllipticCurve + WeierstrassParameters> JacobianPoint<E> {
+    /// The number of words of a point in memory, three coordinates of
+    /// [`EllipticCurve::COORD_STRIDE_WORDS`] words each.
+    pub fn num_memory_words() -> usize {
+        3 * E::COORD_STRIDE_WORDS
+    }
+
+    /// Decodes a point from memory, laid out as [`AffinePoint::from_memory_words`] expects with a
+    /// third coordinate. The padding after each coordinate is ignored.
+    pub fn from_memory_words(words: &[u64]) -> Self {
+        let n = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+        let stride = E::COORD_STRIDE_WORDS;
+        let coord = |i: usize| {
+            let bytes = words[i * stride..i * stride + n]
+                .iter()
+                .flat_map(|w| w.to_le_bytes())
+                .collect::<Vec<_>>();
+            match E::MEMORY_ENDIANNESS {
+                Endianness::Little => BigUint::from_bytes_le(&bytes),
+                Endianness::Big => BigUint::from_bytes_be(&bytes),
+            }
+        };
+        Self::new(coord(0), coord(1), coord(2))
+    }
+
+    /// Encodes a point for memory, laid out as [`AffinePoint::to_memory_words`] does with a third
+    /// coordinate. The padding after each coordinate is zero.
+    pub fn to_memory_words(&self) -> Vec<u64> {
+        let n = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+        let stride = E::COORD_STRIDE_WORDS;
+        let mut words = vec![0; 3 * stride];
+        for (i, coord) in [&self.x, &self.y, &self.z].into_iter().enumerate() {
+            let mut bytes = coord.to_bytes_le();
+            bytes.resize(n * 8, 0);
+            if E::MEMORY_ENDIANNESS == Endianness::Big {
+                bytes.reverse();
+            }
+            for (word, chunk) in words[i * stride..].iter_mut().zip(bytes.chunks_exact(8)) {
+                *word = u64::from_le_bytes(chunk.try_into().unwrap());
+            }
+        }
+        words
+    }
+}
+
+/// Performs a step of the Montgomery ladder on the affine points `r0` and `r1` with the co-Z
+/// formulas of Goundar, Joye and Miyaji: for `bit` 0 the pair becomes `(2 r0, r0 + r1)`, and for
+/// `bit` 1 it becomes `(r0 + r1, 2 r1)`.
+///
+/// With `r` the point selected by `bit` and `s` the other one, `ZADDC` gives `r + s` and `r - s`
+/// with a shared `Z` coordinate, and `ZADDU` of those gives `2 r` and `r + s` again sharing it, so
+/// both results are converted back to affine with a single inversion.
+///
+/// Returns `None` where the co-Z formulas do not apply: if `r0 = ±r1`, or if `2 r0`, `2 r1` or
+/// their sum is the point at infinity.
+pub fn co_z_ladder_step<E: WeierstrassParameters>(
+    r0: &AffinePoint<E>,
+    r1: &AffinePoint<E>,
+    bit: bool,
+) -> Option<(AffinePoint<E>, AffinePoint<E>)> {
+    let p = E::BaseField::modulus();
+    let (r, s) = if bit { (r1, r0) } else { (r0, r1) };
+    if &r.x % &p == &s.x % &p {
+        return None;
+    }
+    let (sum, diff) =
+        JacobianPoint::from_affine(r).co_z_add_conjugate(&JacobianPoint::from_affine(s));
+    if sum.x == diff.x {
+        return None;
+    }
+    let (double, sum) = sum.co_z_add(&diff);
+
+    let z_inv = double.z.modpow(&(&p - 2u32), &p);
+    let z_inv_sq = (&z_inv * &z_inv) % &p;
+    let z_inv_cu = (&z_inv_sq * z_inv) % &p;
+    let to_affine = |point: &JacobianPoint<E>| {
+        AffinePoint::new((&point.x * &z_inv_sq) % &p, (&point.y * &z_inv_cu) % &p)
+    };
+    let (double, sum) = (to_affine(&double), to_affine(&sum));
+    Some(if bit { (sum, double) } else { (double, sum) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        weierstrass::{bls12_381::Bls12381, secp256k1::Secp256k1},
+        EllipticCurve,
+    };
+
+    fn check_against_affine<E: EllipticCurve + WeierstrassParameters>() {
+        let generator = E::ec_generator();
+        let mut affine = generator.clone();
+        let mut jacobian = JacobianPoint::<E>::from_affine(&generator);
+        for _ in 0..10 {
+            affine = E::ec_add(&affine, &generator);
+            jacobian = jacobian.add_affine(&generator);
+            assert_eq!(jacobian.to_affine(), Some(affine.clone()));
+        }
+        assert_eq!(jacobian.double().to_affine(), Some(E::ec_double(&affine)));
+        assert_eq!(jacobian.add_affine(&affine).to_affine(), Some(E::ec_double(&affine)));
+        assert!(jacobian.add_affine(&E::ec_neg(&affine)).is_identity());
+
+        let identity = JacobianPoint::<E>::identity();
+        assert_eq!(identity.to_affine(), None);
+        assert!(identity.double().is_identity());
+        assert_eq!(identity.add_affine(&generator).to_affine(), Some(generator));
+
+        let words = jacobian.to_words_le();
+        assert_eq!(words.len(), JacobianPoint::<E>::num_words());
+        assert_eq!(JacobianPoint::<E>::from_words_le(&words).to_affine(), Some(affine.clone()));
+
+        // The curves of this crate lay points out in memory as the packed little-endian words.
+        let memory_words = jacobian.to_memory_words();
+        assert_eq!(memory_words, words);
+        assert_eq!(memory_words.len(), JacobianPoint::<E>::num_memory_words());
+        assert_eq!(JacobianPoint::<E>::from_memory_words(&memory_words).to_affine(), Some(affine));
+    }
+
+    fn check_co_z_ladder_step<E: EllipticCurve + WeierstrassParameters>() {
+        // The ladder invariant `r1 - r0 = G` holds for either bit.
+        let generator = E::ec_generator();
+        let (mut r0, mut r1) = (generator.clone(), E::ec_double(&generator));
+        for bit in [false, true, true, false, true, false, false, true] {
+            let expected = if bit {
+                (E::ec_add(&r0, &r1), E::ec_double(&r1))
+            } else {
+                (E::ec_double(&r0), E::ec_add(&r0, &r1))
+            };
+            let step = co_z_ladder_step(&r0, &r1, bit).unwrap();
+            assert_eq!(step, expected);
+            (r0, r1) = step;
+            assert_eq!(E::ec_add(&r0, &generator), r1);
+        }
+
+        assert_eq!(co_z_ladder_step(&r0, &r0, false), None);
+        assert_eq!(co_z_ladder_step(&r0, &E::ec_neg(&r0), true), None);
+    }
+
+    #[test]
+    fn test_co_z_ladder_step() {
+        check_co_z_ladder_step::<Secp256k1>();
+        check_co_z_ladder_step::<Bls12381>();
+    }
+
+    #[test]
+    fn test_jacobian_matches_affine() {
+        check_against_affine::<Secp256k1>();
+        check_against_affine::<Bls12381>();
+    }
+}
@@ -8,7 +8,7 @@ use super::CurveType;
 use crate::{
     params::{FieldParameters, NumLimbs, NumWords},
     utils::biguint_to_bits_le,
-    AffinePoint, EllipticCurve, EllipticCurveParameters,
+    AffinePoint, EllipticCurve, EllipticCurveParameters, IdentityEncoding,
 };
 
 #[cfg(feature = "bigint-rug")]
@@ -16,8 +16,12 @@ use crate::utils::{biguint_to_rug, rug_to_biguint};
 
 pub mod bls12_381;
 pub mod bn254;
+pub mod g2;
+pub mod jacobian;
+pub mod pasta;
 pub mod secp256k1;
 pub mod secp256r1;
+pub mod sswu;
 
 use k256::{
     elliptic_curve::sec1::ToEncodedPoint, AffinePoint as K256AffinePoint, EncodedPoint,
@@ -52,6 +56,17 @@ pub trait WeierstrassParameters: EllipticCurveParameters {
     fn nb_scalar_bits() -> usize {
         Self::BaseField::NB_LIMBS * 16
     }
+
+    /// The cofactor `h`, the index of the prime-order subgroup in the group of points.
+    fn cofactor() -> BigUint {
+        BigUint::from(1u32)
+    }
+
+    /// A multiple of the cofactor by a scalar coprime to the prime group order, which also maps
+    /// every point into the prime-order subgroup and may be cheaper to multiply by.
+    fn effective_cofactor() -> BigUint {
+        Self::cofactor()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -80,6 +95,14 @@ impl<E: WeierstrassParameters> WeierstrassParameters for SwCurve<E> {
     fn prime_group_order() -> BigUint {
         E::prime_group_order()
     }
+
+    fn cofactor() -> BigUint {
+        E::cofactor()
+    }
+
+    fn effective_cofactor() -> BigUint {
+        E::effective_cofactor()
+    }
 }
 
 impl<E: WeierstrassParameters> EllipticCurveParameters for SwCurve<E> {
@@ -93,6 +116,7 @@ macro_rules! impl_generic_ec_ops {
         impl EllipticCurve for SwCurve<$curve> {
             const NB_LIMBS: usize = Self::BaseField::NB_LIMBS;
             const NB_WITNESS_LIMBS: usize = Self::BaseField::NB_WITNESS_LIMBS;
+            const IDENTITY_ENCODING: IdentityEncoding = IdentityEncoding::Zero;
 
             fn ec_add(p: &AffinePoint<Self>, q: &AffinePoint<Self>) -> AffinePoint<Self> {
                 p.sw_add(q)
@@ -115,6 +139,10 @@ macro_rules! impl_generic_ec_ops {
                 let modulus = <$curve as EllipticCurveParameters>::BaseField::modulus();
                 AffinePoint::new(p.x.clone(), modulus - &p.y)
             }
+
+            fn ec_is_on_curve(p: &AffinePoint<Self>) -> bool {
+                p.sw_is_on_curve()
+            }
         }
     };
 }
@@ -122,6 +150,8 @@ macro_rules! impl_generic_ec_ops {
 impl_generic_ec_ops!(bn254::Bn254Parameters);
 impl_generic_ec_ops!(secp256r1::Secp256r1Parameters);
 impl_generic_ec_ops!(bls12_381::Bls12381Parameters);
+impl_generic_ec_ops!(pasta::PallasParameters);
+impl_generic_ec_ops!(pasta::VestaParameters);
 
 impl<E: WeierstrassParameters> SwCurve<E> {
     pub fn generator() -> AffinePoint<SwCurve<E>> {
@@ -152,6 +182,17 @@ impl<E: WeierstrassParameters> AffinePoint<SwCurve<E>> {
         }
         result.unwrap()
     }
+
+    /// Returns whether the point has reduced coordinates satisfying `y^2 = x^3 + ax + b`.
+    pub fn sw_is_on_curve(&self) -> bool {
+        let p = E::BaseField::modulus();
+        if self.x >= p || self.y >= p {
+            return false;
+        }
+        let lhs = (&self.y * &self.y) % &p;
+        let rhs = (&self.x * &self.x * &self.x + E::a_int() * &self.x + E::b_int()) % &p;
+        lhs == rhs
+    }
 }
 
 pub fn biguint_to_dashu(integer: &BigUint) -> dashu::integer::UBig {
@@ -187,6 +228,8 @@ pub fn dashu_modpow(
 }
 
 impl EllipticCurve for SwCurve<Secp256k1Parameters> {
+    const IDENTITY_ENCODING: IdentityEncoding = IdentityEncoding::Zero;
+
     fn ec_add(p: &AffinePoint<Self>, q: &AffinePoint<Self>) -> AffinePoint<Self> {
         p.sw_add_k256(q)
     }
@@ -208,6 +251,10 @@ impl EllipticCurve for SwCurve<Secp256k1Parameters> {
         let modulus = <Secp256k1Parameters as EllipticCurveParameters>::BaseField::modulus();
         AffinePoint::new(p.x.clone(), modulus - &p.y)
     }
+
+    fn ec_is_on_curve(p: &AffinePoint<Self>) -> bool {
+        p.sw_is_on_curve()
+    }
 }
 
 impl AffinePoint<SwCurve<Secp256k1Parameters>> {
@@ -385,7 +432,23 @@ mod tests {
     use num::bigint::RandBigInt;
     use rand::thread_rng;
 
-    use super::bn254;
+    use super::{bn254, secp256k1::Secp256k1};
+    use crate::{params::FieldParameters, AffinePoint, EllipticCurve};
+
+    #[test]
+    fn test_sw_is_on_curve() {
+        type E = bn254::Bn254;
+        let base = E::generator();
+        assert!(E::ec_is_on_curve(&base));
+        assert!(E::ec_is_on_curve(&base.sw_double()));
+        assert!(Secp256k1::ec_is_on_curve(&Secp256k1::ec_generator()));
+
+        let off_curve = AffinePoint::<E>::new(base.x.clone(), &base.y + 1u32);
+        assert!(!E::ec_is_on_curve(&off_curve));
+
+        let unreduced = AffinePoint::<E>::new(&base.x + bn254::Bn254BaseField::modulus(), base.y);
+        assert!(!E::ec_is_on_curve(&unreduced));
+    }
 
     #[test]
     fn test_weierstrass_biguint_scalar_mul() {
@@ -0,0 +1,192 @@
+//! The Pasta cycle of curves, Pallas and Vesta. Both are `y^2 = x^3 + 5`, and the base field of
+//! each is the scalar field of the other. The constants are taken from
+//! https://github.com/zcash/pasta_curves
+
+use generic_array::GenericArray;
+use num::{BigUint, Num, Zero};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use super::{SwCurve, WeierstrassParameters};
+use crate::{
+    params::{FieldParameters, NumLimbs},
+    CurveType, EllipticCurveParameters,
+};
+
+const B: GenericArray<u8, U32> = GenericArray::from_array([
+    5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Pallas curve parameter
+pub struct PallasParameters;
+
+pub type Pallas = SwCurve<PallasParameters>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Pallas base field parameter, which is the scalar field of Vesta.
+pub struct PallasBaseField;
+
+impl FieldParameters for PallasBaseField {
+    const MODULUS: &'static [u8] = &[
+        1, 0, 0, 0, 237, 48, 45, 153, 27, 249, 76, 9, 252, 152, 70, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 64,
+    ];
+
+    // A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "40000000000000000000000000000000224698fc094cf91b992d30ed00000001",
+            16,
+        )
+        .unwrap()
+    }
+}
+
+impl NumLimbs for PallasBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for PallasParameters {
+    type BaseField = PallasBaseField;
+
+    const CURVE_TYPE: CurveType = CurveType::Pallas;
+}
+
+impl WeierstrassParameters for PallasParameters {
+    const A: GenericArray<u8, U32> = GenericArray::from_array([0; 32]);
+
+    const B: GenericArray<u8, U32> = B;
+
+    fn generator() -> (BigUint, BigUint) {
+        // The generator is (-1, 2).
+        let x = PallasBaseField::modulus() - 1u32;
+        let y = BigUint::from(2u32);
+        (x, y)
+    }
+
+    fn prime_group_order() -> BigUint {
+        VestaBaseField::modulus()
+    }
+
+    fn a_int() -> BigUint {
+        BigUint::zero()
+    }
+
+    fn b_int() -> BigUint {
+        BigUint::from(5u32)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Vesta curve parameter
+pub struct VestaParameters;
+
+pub type Vesta = SwCurve<VestaParameters>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Vesta base field parameter, which is the scalar field of Pallas.
+pub struct VestaBaseField;
+
+impl FieldParameters for VestaBaseField {
+    const MODULUS: &'static [u8] = &[
+        1, 0, 0, 0, 33, 235, 70, 140, 221, 168, 148, 9, 252, 152, 70, 34, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 64,
+    ];
+
+    // A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001",
+            16,
+        )
+        .unwrap()
+    }
+}
+
+impl NumLimbs for VestaBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for VestaParameters {
+    type BaseField = VestaBaseField;
+
+    const CURVE_TYPE: CurveType = CurveType::Vesta;
+}
+
+impl WeierstrassParameters for VestaParameters {
+    const A: GenericArray<u8, U32> = GenericArray::from_array([0; 32]);
+
+    const B: GenericArray<u8, U32> = B;
+
+    fn generator() -> (BigUint, BigUint) {
+        // The generator is (-1, 2).
+        let x = VestaBaseField::modulus() - 1u32;
+        let y = BigUint::from(2u32);
+        (x, y)
+    }
+
+    fn prime_group_order() -> BigUint {
+        PallasBaseField::modulus()
+    }
+
+    fn a_int() -> BigUint {
+        BigUint::zero()
+    }
+
+    fn b_int() -> BigUint {
+        BigUint::from(5u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::Unsigned;
+
+    use super::*;
+    use crate::{params::NumWords, utils::biguint_from_limbs, AffinePoint, EllipticCurve};
+
+    fn check_generator<E: EllipticCurve>() {
+        let generator = E::ec_generator();
+        assert!(E::ec_is_on_curve(&generator));
+        assert_eq!(AffinePoint::<E>::from_words_le(&generator.to_words_le()), generator);
+
+        let doubled = E::ec_double(&generator);
+        assert!(E::ec_is_on_curve(&doubled));
+        assert_eq!(E::ec_add(&doubled, &generator), E::ec_add(&generator, &doubled));
+    }
+
+    #[test]
+    fn test_pasta_generators() {
+        check_generator::<Pallas>();
+        check_generator::<Vesta>();
+    }
+
+    #[test]
+    fn test_pasta_moduli() {
+        assert_eq!(biguint_from_limbs(PallasBaseField::MODULUS), PallasBaseField::modulus());
+        assert_eq!(biguint_from_limbs(VestaBaseField::MODULUS), VestaBaseField::modulus());
+    }
+
+    #[test]
+    fn test_pasta_cycle() {
+        // Each curve's scalar field is the other's base field.
+        assert_eq!(PallasParameters::prime_group_order(), VestaBaseField::modulus());
+        assert_eq!(VestaParameters::prime_group_order(), PallasBaseField::modulus());
+
+        // Both fields are 255 bits, so scalars of either curve fit in a field element of the other.
+        for modulus in [PallasBaseField::modulus(), VestaBaseField::modulus()] {
+            assert_eq!(modulus.bits(), 255);
+        }
+        assert_eq!(<PallasBaseField as NumWords>::WordsFieldElement::USIZE, 4);
+        assert_eq!(<VestaBaseField as NumWords>::WordsFieldElement::USIZE, 4);
+        assert_eq!(<PallasBaseField as NumWords>::WordsCurvePoint::USIZE, 8);
+        assert_eq!(<VestaBaseField as NumWords>::WordsCurvePoint::USIZE, 8);
+    }
+}
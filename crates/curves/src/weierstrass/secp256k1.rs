@@ -102,6 +102,49 @@ pub fn secp256k1_decompress<E: EllipticCurve>(bytes_be: &[u8], sign: u32) -> Aff
     AffinePoint::<E>::new(x, y)
 }
 
+/// The scalar `lambda` by which the endomorphism `(x, y) -> (beta * x, y)` acts on the group.
+pub fn secp256k1_glv_lambda() -> BigUint {
+    BigUint::parse_bytes(b"5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72", 16)
+        .unwrap()
+}
+
+/// The cube root of unity `beta` in the base field defining the endomorphism
+/// `(x, y) -> (beta * x, y)`.
+pub fn secp256k1_glv_beta() -> BigUint {
+    BigUint::parse_bytes(b"7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee", 16)
+        .unwrap()
+}
+
+/// Splits a scalar `k` into `k1 + k2 * lambda = k mod n`, where `k1` and `k2` have about 128 bits.
+///
+/// Each half is returned as its magnitude and whether it is negative. The lattice basis
+/// `(a1, b1), (a2, b2)` is the one used by libsecp256k1.
+pub fn secp256k1_glv_decompose(k: &BigUint) -> [(BigUint, bool); 2] {
+    let n = Secp256k1Parameters::prime_group_order();
+    let a1 = BigUint::parse_bytes(b"3086d221a7d46bcde86c90e49284eb15", 16).unwrap();
+    // b1 is negative, so only its magnitude is kept.
+    let minus_b1 = BigUint::parse_bytes(b"e4437ed6010e88286f547fa90abfe4c3", 16).unwrap();
+    let a2 = BigUint::parse_bytes(b"114ca50f7a8e2f3f657c1108d9d44cfd8", 16).unwrap();
+    let b2 = &a1;
+
+    let half_n = &n >> 1;
+    let c1 = (b2 * k + &half_n) / &n;
+    let c2 = (&minus_b1 * k + &half_n) / &n;
+
+    let k1 = signed_sub(k.clone(), &c1 * &a1 + &c2 * &a2);
+    let k2 = signed_sub(&c1 * &minus_b1, &c2 * b2);
+    [k1, k2]
+}
+
+/// Returns `|a - b|` and whether `a - b` is negative.
+fn signed_sub(a: BigUint, b: BigUint) -> (BigUint, bool) {
+    if a >= b {
+        (a - b, false)
+    } else {
+        (b - a, true)
+    }
+}
+
 pub fn secp256k1_sqrt(n: &BigUint) -> BigUint {
     let be_bytes = n.to_be_bytes();
     let mut bytes = [0_u8; 32];
@@ -143,4 +186,25 @@ mod tests {
             assert_eq!(sqrt_2, x_2);
         }
     }
+
+    #[test]
+    fn test_secp256k1_glv_decompose() {
+        let n = Secp256k1Parameters::prime_group_order();
+        let lambda = secp256k1_glv_lambda();
+        assert_eq!(lambda.modpow(&BigUint::from(3u32), &n), BigUint::from(1u32));
+        let beta = secp256k1_glv_beta();
+        let p = Secp256k1BaseField::modulus();
+        assert_eq!(beta.modpow(&BigUint::from(3u32), &p), BigUint::from(1u32));
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let k = rng.gen_biguint(256);
+            let [(k1, k1_neg), (k2, k2_neg)] = secp256k1_glv_decompose(&k);
+            assert!(k1.bits() <= 129 && k2.bits() <= 129);
+
+            let signed = |v: &BigUint, neg: bool| if neg { &n - v % &n } else { v % &n };
+            let recombined = (signed(&k1, k1_neg) + signed(&k2, k2_neg) * &lambda) % &n;
+            assert_eq!(recombined, &k % &n);
+        }
+    }
 }
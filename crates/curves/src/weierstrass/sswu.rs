@@ -0,0 +1,187 @@
+//! The simplified SWU map of [RFC 9380] for BLS12-381 G1, the `map_to_curve` step of the
+//! `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite.
+//!
+//! A field element is first mapped to the curve `E': y^2 = x^3 + A'x + B'`, which is 11-isogenous
+//! to BLS12-381 G1, and then sent to G1 by the isogeny of [RFC 9380, Appendix E.2]. The result still
+//! has to be multiplied by the effective cofactor to land in the prime-order subgroup.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+//! [RFC 9380, Appendix E.2]: https://www.rfc-editor.org/rfc/rfc9380#appendix-E.2
+
+use num::{BigUint, Num, One, Zero};
+
+use crate::{
+    params::FieldParameters, utils::sqrt_3mod4, weierstrass::bls12_381::Bls12381BaseField,
+    AffinePoint, EllipticCurve,
+};
+
+/// The coefficient `A'` of the isogenous curve.
+const ISO_A: &str = "00144698a3b8e9433d693a02c96d4982b0ea985383ee66a8d8e8981aefd881ac98936f8da0e0f97f5cf428082d584c1d";
+
+/// The coefficient `B'` of the isogenous curve.
+const ISO_B: &str = "12e2908d11688030018b12e8753eee3b2016c1f0f24f4070a0b9c14fcef35ef55a23215a316ceaa5d1cc48e98e172be0";
+
+/// The non-square `Z` of the map.
+const Z: u32 = 11;
+
+/// The coefficients of the numerator of the `x` coordinate of the isogeny, constant term first.
+const X_NUM: [&str; 12] = [
+    "11a05f2b1e833340b809101dd99815856b303e88a2d7005ff2627b56cdb4e2c85610c2d5f2e62d6eaeac1662734649b7",
+    "17294ed3e943ab2f0588bab22147a81c7c17e75b2f6a8417f565e33c70d1e86b4838f2a6f318c356e834eef1b3cb83bb",
+    "0d54005db97678ec1d1048c5d10a9a1bce032473295983e56878e501ec68e25c958c3e3d2a09729fe0179f9dac9edcb0",
+    "1778e7166fcc6db74e0609d307e55412d7f5e4656a8dbf25f1b33289f1b330835336e25ce3107193c5b388641d9b6861",
+    "0e99726a3199f4436642b4b3e4118e5499db995a1257fb3f086eeb65982fac18985a286f301e77c451154ce9ac8895d9",
+    "1630c3250d7313ff01d1201bf7a74ab5db3cb17dd952799b9ed3ab9097e68f90a0870d2dcae73d19cd13c1c66f652983",
+    "0d6ed6553fe44d296a3726c38ae652bfb11586264f0f8ce19008e218f9c86b2a8da25128c1052ecaddd7f225a139ed84",
+    "17b81e7701abdbe2e8743884d1117e53356de5ab275b4db1a682c62ef0f2753339b7c8f8c8f475af9ccb5618e3f0c88e",
+    "080d3cf1f9a78fc47b90b33563be990dc43b756ce79f5574a2c596c928c5d1de4fa295f296b74e956d71986a8497e317",
+    "169b1f8e1bcfa7c42e0c37515d138f22dd2ecb803a0c5c99676314baf4bb1b7fa3190b2edc0327797f241067be390c9e",
+    "10321da079ce07e272d8ec09d2565b0dfa7dccdde6787f96d50af36003b14866f69b771f8c285decca67df3f1605fb7b",
+    "06e08c248e260e70bd1e962381edee3d31d79d7e22c837bc23c0bf1bc24c6b68c24b1b80b64d391fa9c8ba2e8ba2d229",
+];
+
+/// The coefficients of the monic denominator of the `x` coordinate of the isogeny, constant term
+/// first and without the leading 1.
+const X_DEN: [&str; 10] = [
+    "08ca8d548cff19ae18b2e62f4bd3fa6f01d5ef4ba35b48ba9c9588617fc8ac62b558d681be343df8993cf9fa40d21b1c",
+    "12561a5deb559c4348b4711298e536367041e8ca0cf0800c0126c2588c48bf5713daa8846cb026e9e5c8276ec82b3bff",
+    "0b2962fe57a3225e8137e629bff2991f6f89416f5a718cd1fca64e00b11aceacd6a3d0967c94fedcfcc239ba5cb83e19",
+    "03425581a58ae2fec83aafef7c40eb545b08243f16b1655154cca8abc28d6fd04976d5243eecf5c4130de8938dc62cd8",
+    "13a8e162022914a80a6f1d5f43e7a07dffdfc759a12062bb8d6b44e833b306da9bd29ba81f35781d539d395b3532a21e",
+    "0e7355f8e4e667b955390f7f0506c6e9395735e9ce9cad4d0a43bcef24b8982f7400d24bc4228f11c02df9a29f6304a5",
+    "0772caacf16936190f3e0c63e0596721570f5799af53a1894e2e073062aede9cea73b3538f0de06cec2574496ee84a3a",
+    "14a7ac2a9d64a8b230b3f5b074cf01996e7f63c21bca68a81996e1cdf9822c580fa5b9489d11e2d311f7d99bbdcc5a5e",
+    "0a10ecf6ada54f825e920b3dafc7a3cce07f8d1d7161366b74100da67f39883503826692abba43704776ec3a79a1d641",
+    "095fc13ab9e92ad4476d6e3eb3a56680f682b4ee96f7d03776df533978f31c1593174e4b4b7865002d6384d168ecdd0a",
+];
+
+/// The coefficients of the numerator of the `y` coordinate of the isogeny, constant term first.
+const Y_NUM: [&str; 16] = [
+    "090d97c81ba24ee0259d1f094980dcfa11ad138e48a869522b52af6c956543d3cd0c7aee9b3ba3c2be9845719707bb33",
+    "134996a104ee5811d51036d776fb46831223e96c254f383d0f906343eb67ad34d6c56711962fa8bfe097e75a2e41c696",
+    "00cc786baa966e66f4a384c86a3b49942552e2d658a31ce2c344be4b91400da7d26d521628b00523b8dfe240c72de1f6",
+    "01f86376e8981c217898751ad8746757d42aa7b90eeb791c09e4a3ec03251cf9de405aba9ec61deca6355c77b0e5f4cb",
+    "08cc03fdefe0ff135caf4fe2a21529c4195536fbe3ce50b879833fd221351adc2ee7f8dc099040a841b6daecf2e8fedb",
+    "16603fca40634b6a2211e11db8f0a6a074a7d0d4afadb7bd76505c3d3ad5544e203f6326c95a807299b23ab13633a5f0",
+    "04ab0b9bcfac1bbcb2c977d027796b3ce75bb8ca2be184cb5231413c4d634f3747a87ac2460f415ec961f8855fe9d6f2",
+    "0987c8d5333ab86fde9926bd2ca6c674170a05bfe3bdd81ffd038da6c26c842642f64550fedfe935a15e4ca31870fb29",
+    "09fc4018bd96684be88c9e221e4da1bb8f3abd16679dc26c1e8b6e6a1f20cabe69d65201c78607a360370e577bdba587",
+    "0e1bba7a1186bdb5223abde7ada14a23c42a0ca7915af6fe06985e7ed1e4d43b9b3f7055dd4eba6f2bafaaebca731c30",
+    "19713e47937cd1be0dfd0b8f1d43fb93cd2fcbcb6caf493fd1183e416389e61031bf3a5cce3fbafce813711ad011c132",
+    "18b46a908f36f6deb918c143fed2edcc523559b8aaf0c2462e6bfe7f911f643249d9cdf41b44d606ce07c8a4d0074d8e",
+    "0b182cac101b9399d155096004f53f447aa7b12a3426b08ec02710e807b4633f06c851c1919211f20d4c04f00b971ef8",
+    "0245a394ad1eca9b72fc00ae7be315dc757b3b080d4c158013e6632d3c40659cc6cf90ad1c232a6442d9d3f5db980133",
+    "05c129645e44cf1102a159f748c4a3fc5e673d81d7e86568d9ab0f5d396a7ce46ba1049b6579afb7866b1e715475224b",
+    "15e6be4e990f03ce4ea50b3b42df2eb5cb181d8f84965a3957add4fa95af01b2b665027efec01c7704b456be69c8b604",
+];
+
+/// The coefficients of the monic denominator of the `y` coordinate of the isogeny, constant term
+/// first and without the leading 1.
+const Y_DEN: [&str; 15] = [
+    "16112c4c3a9c98b252181140fad0eae9601a6de578980be6eec3232b5be72e7a07f3688ef60c206d01479253b03663c1",
+    "1962d75c2381201e1a0cbd6c43c348b885c84ff731c4d59ca4a10356f453e01f78a4260763529e3532f6102c2e49a03d",
+    "058df3306640da276faaae7d6e8eb15778c4855551ae7f310c35a5dd279cd2eca6757cd636f96f891e2538b53dbf67f2",
+    "16b7d288798e5395f20d23bf89edb4d1d115c5dbddbcd30e123da489e726af41727364f2c28297ada8d26d98445f5416",
+    "0be0e079545f43e4b00cc912f8228ddcc6d19c9f0f69bbb0542eda0fc9dec916a20b15dc0fd2ededda39142311a5001d",
+    "08d9e5297186db2d9fb266eaac783182b70152c65550d881c5ecd87b6f0f5a6449f38db9dfa9cce202c6477faaf9b7ac",
+    "166007c08a99db2fc3ba8734ace9824b5eecfdfa8d0cf8ef5dd365bc400a0051d5fa9c01a58b1fb93d1a1399126a775c",
+    "16a3ef08be3ea7ea03bcddfabba6ff6ee5a4375efa1f4fd7feb34fd206357132b920f5b00801dee460ee415a15812ed9",
+    "1866c8ed336c61231a1be54fd1d74cc4f9fb0ce4c6af5920abc5750c4bf39b4852cfe2f7bb9248836b233d9d55535d4a",
+    "167a55cda70a6e1cea820597d94a84903216f763e13d87bb5308592e7ea7d4fbc7385ea3d529b35e346ef48bb8913f55",
+    "04d2f259eea405bd48f010a01ad2911d9c6dd039bb61a6290e591b36e636a5c871a5c29f4f83060400f8b49cba8f6aa8",
+    "0accbb67481d033ff5852c1e48c50c477f94ff8aefce42d28c0f9a88cea7913516f968986f7ebbea9684b529e2561092",
+    "0ad6b9514c767fe3c3613144b45f1496543346d98adf02267d5ceef9a00d9b8693000763e3b90ac11e99b138573345cc",
+    "02660400eb2e4f3b628bdd0d53cd76f2bf565b94e72927c1cb748df27942480e420517bd8714cc80d1fadc1326ed06f7",
+    "0e0fa1d816ddc03e6b24255e0d7819c171c40f65e273b853324efcd6356caa205ca2f570f13497804415473a1d634b8f",
+];
+
+fn coeff(hex: &str) -> BigUint {
+    BigUint::from_str_radix(hex, 16).unwrap()
+}
+
+/// Evaluates the polynomial with the given coefficients at `x`, appending a leading 1 if `monic`.
+fn eval(coeffs: &[&str], monic: bool, x: &BigUint, p: &BigUint) -> BigUint {
+    let leading = if monic { BigUint::one() } else { BigUint::zero() };
+    coeffs.iter().rev().fold(leading, |acc, c| (acc * x + coeff(c)) % p)
+}
+
+/// Maps an element of the base field of BLS12-381 to a point of G1, with the simplified SWU map to
+/// the isogenous curve followed by the isogeny. Inputs that are not reduced are reduced modulo the
+/// field modulus first.
+///
+/// Returns `None` for the point at infinity, which the isogeny only reaches at the roots of its
+/// denominators.
+pub fn bls12381_map_to_curve<E: EllipticCurve>(u: &BigUint) -> Option<AffinePoint<E>> {
+    let p = Bls12381BaseField::modulus();
+    let (a, b) = (coeff(ISO_A), coeff(ISO_B));
+    let u = u % &p;
+    let inv = |x: &BigUint| x.modpow(&(&p - 2u32), &p);
+    let g = |x: &BigUint| (x * x * x + &a * x + &b) % &p;
+    let sqrt = |x: &BigUint| sqrt_3mod4(x, &p);
+
+    // Simplified SWU, as in section 6.6.2 of RFC 9380.
+    let uu = (&u * &u) % &p;
+    let tv = (uu.pow(2) * Z * Z + &uu * Z) % &p;
+    let x1 = if tv.is_zero() {
+        (&b * inv(&((&a * Z) % &p))) % &p
+    } else {
+        (&p - (&b * inv(&a)) % &p) * (inv(&tv) + 1u32) % &p
+    };
+    let (x, mut y) = match sqrt(&g(&x1)) {
+        Some(y) => (x1, y),
+        None => {
+            let x2 = (&uu * Z * &x1) % &p;
+            let y = sqrt(&g(&x2)).expect("one of g(x1) and g(x2) is a square");
+            (x2, y)
+        }
+    };
+    // `sgn0` is the parity of the canonical representative.
+    if y.bit(0) != u.bit(0) {
+        y = (&p - y) % &p;
+    }
+
+    // The isogeny to G1.
+    let x_den = eval(&X_DEN, true, &x, &p);
+    let y_den = eval(&Y_DEN, true, &x, &p);
+    if x_den.is_zero() || y_den.is_zero() {
+        return None;
+    }
+    let x_out = (eval(&X_NUM, false, &x, &p) * inv(&x_den)) % &p;
+    let y_out = (y * eval(&Y_NUM, false, &x, &p) * inv(&y_den)) % &p;
+    Some(AffinePoint::new(x_out, y_out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weierstrass::bls12_381::Bls12381;
+
+    #[test]
+    fn test_bls12381_map_to_curve() {
+        // The field elements and their images `Q0` and `Q1` for the empty message of the
+        // `BLS12381G1_XMD:SHA-256_SSWU_RO_` test vectors of RFC 9380, Appendix J.9.1.
+        let vectors = [
+            (
+                "0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f",
+                "11a3cce7e1d90975990066b2f2643b9540fa40d6137780df4e753a8054d07580db3b7f1f03396333d4a359d1fe3766fe",
+                "0eeaf6d794e479e270da10fdaf768db4c96b650a74518fc67b04b03927754bac66f3ac720404f339ecdcc028afa091b7",
+            ),
+            (
+                "019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9",
+                "160003aaf1632b13396dbad518effa00fff532f604de1a7fc2082ff4cb0afa2d63b2c32da1bef2bf6c5ca62dc6b72f9c",
+                "0d8bb2d14e20cf9f6036152ed386d79189415b6d015a20133acb4e019139b94e9c146aaad5817f866c95d609a361735e",
+            ),
+        ];
+        for (u, x, y) in vectors {
+            let point = bls12381_map_to_curve::<Bls12381>(&coeff(u)).unwrap();
+            assert_eq!(point, AffinePoint::new(coeff(x), coeff(y)));
+            assert!(Bls12381::ec_is_on_curve(&point));
+        }
+
+        // The map is defined on the whole field, including the exceptional input 0.
+        let zero = bls12381_map_to_curve::<Bls12381>(&BigUint::zero()).unwrap();
+        assert!(Bls12381::ec_is_on_curve(&zero));
+        let p = Bls12381BaseField::modulus();
+        assert_eq!(bls12381_map_to_curve::<Bls12381>(&p), Some(zero));
+    }
+}
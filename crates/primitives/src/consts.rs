@@ -118,6 +118,23 @@ pub fn bytes_to_words_le_vec(bytes: &[u8]) -> Vec<u64> {
         .collect::<Vec<_>>()
 }
 
+/// Converts a slice of little endian words to the big endian bytes of the integer they represent.
+///
+/// This is the byte-reversal of [`words_to_bytes_le_vec`], with the most significant byte first.
+pub fn words_to_bytes_be_vec(words: &[u64]) -> Vec<u8> {
+    words.iter().rev().flat_map(|word| word.to_be_bytes().into_iter()).collect::<Vec<_>>()
+}
+
+/// Converts the big endian bytes of an integer to a vector of little endian words.
+///
+/// This is the inverse of [`words_to_bytes_be_vec`].
+pub fn bytes_be_to_words_le_vec(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .rchunks_exact(8)
+        .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+        .collect::<Vec<_>>()
+}
+
 // Converts a num to a string with commas every 3 digits.
 pub fn num_to_comma_separated<T: ToString>(value: T) -> String {
     value